@@ -11,12 +11,15 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::time::Duration;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, EngineState, Stack},
-    LabeledError, Value,
+    LabeledError, Spanned, Value,
 };
 use zenoh::{
     bytes::Encoding,
@@ -26,7 +29,7 @@ use zenoh::{
     time::Timestamp,
 };
 
-use crate::State;
+use crate::{conv::PayloadFormat, serialization::Schema, State};
 
 pub(crate) trait CallExt2 {
     fn allowed_origin(
@@ -112,6 +115,26 @@ pub(crate) trait CallExt2 {
         engine_state: &EngineState,
         stack: &mut Stack,
     ) -> Result<Option<Duration>, LabeledError>;
+
+    fn payload_format(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<PayloadFormat, LabeledError>;
+
+    fn duration(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        name: &str,
+    ) -> Result<Option<Duration>, LabeledError>;
+
+    fn expand_path(
+        &self,
+        engine_state: &EngineState,
+        stack: &Stack,
+        path: &Path,
+    ) -> Result<PathBuf, LabeledError>;
 }
 
 impl CallExt2 for Call<'_> {
@@ -315,11 +338,63 @@ impl CallExt2 for Call<'_> {
         engine_state: &EngineState,
         stack: &mut Stack,
     ) -> Result<Option<Duration>, LabeledError> {
-        match self.get_flag::<Value>(engine_state, stack, "timeout")? {
+        self.duration(engine_state, stack, "timeout")
+    }
+
+    fn payload_format(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<PayloadFormat, LabeledError> {
+        let schema = self
+            .get_flag::<Spanned<String>>(engine_state, stack, "schema")?
+            .map(|schema| {
+                Schema::parse(&schema.item)
+                    .map_err(|err| LabeledError::new("Invalid schema").with_label(err, schema.span))
+            })
+            .transpose()?;
+
+        match (
+            self.has_flag(engine_state, stack, "decode")?,
+            self.has_flag(engine_state, stack, "raw")?,
+            schema,
+        ) {
+            (false, false, None) => Ok(PayloadFormat::Auto),
+            (true, false, None) => Ok(PayloadFormat::Decode),
+            (false, true, None) => Ok(PayloadFormat::Raw),
+            (false, false, Some(schema)) => Ok(PayloadFormat::Schema(schema)),
+            _ => Err(LabeledError::new("Conflicting arguments").with_label(
+                "Only one of --decode, --raw or --schema can be specified",
+                self.head,
+            )),
+        }
+    }
+
+    fn duration(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        name: &str,
+    ) -> Result<Option<Duration>, LabeledError> {
+        match self.get_flag::<Value>(engine_state, stack, name)? {
             Some(v) => Ok(Some(Duration::from_nanos(v.as_duration()? as u64))),
             None => Ok(None),
         }
     }
+
+    /// Resolves a path against the current directory of the caller (i.e. `$env.PWD`) rather
+    /// than the one of the process, which differs when running as a plugin
+    fn expand_path(
+        &self,
+        engine_state: &EngineState,
+        stack: &Stack,
+        path: &Path,
+    ) -> Result<PathBuf, LabeledError> {
+        let cwd = engine_state
+            .cwd(Some(stack))
+            .map_err(|err| LabeledError::from_diagnostic(&err))?;
+        Ok(nu_path::expand_path_with(path, cwd, true))
+    }
 }
 
 /// Helper function to parse locality values
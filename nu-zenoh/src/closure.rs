@@ -0,0 +1,80 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{cell::RefCell, sync::Arc};
+
+use nu_engine::ClosureEval;
+use nu_protocol::{
+    engine::{Closure, EngineState, Stack},
+    PipelineData, ShellError, Spanned, Value,
+};
+
+/// Evaluates closures owned by another engine, e.g. the shell which called a plugin
+pub trait ClosureEvaluator: Send + Sync {
+    /// Runs a closure with a value as both its argument and its input
+    #[allow(clippy::result_large_err)]
+    fn run_with_value(
+        &self,
+        closure: &Spanned<Closure>,
+        value: Value,
+    ) -> Result<PipelineData, ShellError>;
+}
+
+thread_local! {
+    static CLOSURE_EVALUATOR: RefCell<Option<Arc<dyn ClosureEvaluator>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with the closure arguments of commands evaluated by `evaluator`
+///
+/// This is needed when commands are run with an [`EngineState`] which doesn't own the closures
+/// passed to them.
+pub fn with_closure_evaluator<T>(evaluator: Arc<dyn ClosureEvaluator>, f: impl FnOnce() -> T) -> T {
+    let previous = CLOSURE_EVALUATOR.replace(Some(evaluator));
+    let result = f();
+    CLOSURE_EVALUATOR.set(previous);
+    result
+}
+
+/// Closure argument of a command, see [`with_closure_evaluator`]
+pub(crate) enum Handler {
+    Engine(Box<ClosureEval>),
+    External {
+        evaluator: Arc<dyn ClosureEvaluator>,
+        closure: Spanned<Closure>,
+    },
+}
+
+impl Handler {
+    pub(crate) fn new(
+        engine_state: &EngineState,
+        stack: &Stack,
+        closure: Spanned<Closure>,
+    ) -> Self {
+        match CLOSURE_EVALUATOR.with_borrow(Clone::clone) {
+            Some(evaluator) => Self::External { evaluator, closure },
+            None => Self::Engine(Box::new(ClosureEval::new(
+                engine_state,
+                stack,
+                closure.item,
+            ))),
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn run_with_value(&mut self, value: Value) -> Result<PipelineData, ShellError> {
+        match self {
+            Self::Engine(closure) => closure.run_with_value(value),
+            Self::External { evaluator, closure } => evaluator.run_with_value(closure, value),
+        }
+    }
+}
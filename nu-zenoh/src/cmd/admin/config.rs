@@ -0,0 +1,86 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, Record, ShellError, Signature, Type, Value,
+};
+
+use crate::{call_ext2::CallExt2, cmd::config::session_config, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Config {
+    state: State,
+}
+
+impl Config {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Config {
+    fn name(&self) -> &str {
+        "zenoh admin config"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::record())
+    }
+
+    fn description(&self) -> &str {
+        "Configuration of the session's node, by configuration path"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns a record of configuration paths (e.g. 'connect/endpoints') to values. \
+        Unlike other admin commands, this can't query remote nodes: \
+        the Zenoh admin space doesn't expose their configuration."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let config = session_config(&self.state, &call.session(engine_state, stack)?, span)?;
+
+        let mut paths = Record::new();
+        flatten(&mut paths, "", config);
+
+        Ok(PipelineData::Value(Value::record(paths, span), None))
+    }
+}
+
+/// Helper function to flatten nested records into configuration paths
+fn flatten(paths: &mut Record, prefix: &str, value: Value) {
+    match value {
+        Value::Record { val, .. } if !val.is_empty() => {
+            for (key, value) in val.into_owned() {
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}/{key}")
+                };
+                flatten(paths, &path, value);
+            }
+        }
+        value => paths.push(prefix, value),
+    }
+}
@@ -0,0 +1,107 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{record, shell_error::generic::GenericError, IntoValue, ShellError, Span, Value};
+use zenoh::{Session, Wait};
+
+use crate::conv::{self, PayloadFormat};
+
+pub(crate) mod config;
+pub(crate) mod resources;
+pub(crate) mod routers;
+pub(crate) mod sessions;
+
+/// Admin space reply, i.e. a sample on `@/<zid>/<whatami>[/<path>]`
+pub(crate) struct AdminReply {
+    pub(crate) zid: String,
+    pub(crate) whatami: String,
+    pub(crate) path: String,
+    pub(crate) payload: Value,
+}
+
+/// Queries the admin space and decodes the payloads of the replies
+#[allow(clippy::result_large_err)]
+pub(crate) fn query(
+    session: &Session,
+    selector: &str,
+    span: Span,
+) -> Result<Vec<AdminReply>, ShellError> {
+    let error =
+        |msg: String| ShellError::Generic(GenericError::new("Admin space query failed", msg, span));
+
+    let replies = session
+        .get(selector)
+        .wait()
+        .map_err(|e| error(format!("Zenoh get failed: {e}")))?;
+
+    let mut admin_replies = Vec::new();
+    while let Ok(reply) = replies.recv() {
+        let sample = match reply.into_result() {
+            Ok(sample) => sample,
+            Err(reply_error) => {
+                return Err(error(format!(
+                    "Admin space replied with an error: {}",
+                    reply_error.payload().try_to_string().unwrap_or_default()
+                )))
+            }
+        };
+
+        let mut chunks = sample.key_expr().as_str().splitn(4, '/').skip(1);
+        let (Some(zid), Some(whatami)) = (chunks.next(), chunks.next()) else {
+            continue;
+        };
+
+        admin_replies.push(AdminReply {
+            zid: zid.to_string(),
+            whatami: whatami.to_string(),
+            path: chunks.next().unwrap_or_default().to_string(),
+            payload: conv::payload_to_value(
+                sample.payload(),
+                sample.encoding(),
+                &PayloadFormat::Decode,
+                span,
+            ),
+        });
+    }
+
+    Ok(admin_replies)
+}
+
+/// Normalizes the `sessions` of a node's admin space root
+fn node_sessions(node: &Value, span: Span) -> Vec<Value> {
+    let sessions = node
+        .get_data_by_key("sessions")
+        .and_then(|sessions| sessions.into_list().ok())
+        .unwrap_or_default();
+
+    let column = |session: &Value, name: &str| {
+        session
+            .get_data_by_key(name)
+            .unwrap_or_else(|| Value::nothing(span))
+    };
+
+    sessions
+        .into_iter()
+        .map(|session| {
+            record!(
+                "zid" => column(&session, "peer"),
+                "whatami" => column(&session, "whatami"),
+                "links" => column(&session, "links"),
+                "weight" => column(&session, "weight"),
+                "shm" => column(&session, "shm"),
+                "region" => column(&session, "region"),
+            )
+            .into_value(span)
+        })
+        .collect()
+}
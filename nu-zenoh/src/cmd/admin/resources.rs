@@ -0,0 +1,137 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{call_ext2::CallExt2, cmd::admin, signature_ext::SignatureExt, State};
+
+/// Declarations listed in the admin space, along with the nodes they originate from
+#[derive(Clone)]
+pub(crate) struct Resources {
+    state: State,
+    name: &'static str,
+    description: &'static str,
+    /// Admin space segment, e.g. `subscriber`
+    segment: &'static str,
+}
+
+impl Resources {
+    pub(crate) fn subscribers(state: State) -> Self {
+        Self {
+            state,
+            name: "zenoh admin subscribers",
+            description: "List the subscribers known to a node",
+            segment: "subscriber",
+        }
+    }
+
+    pub(crate) fn queryables(state: State) -> Self {
+        Self {
+            state,
+            name: "zenoh admin queryables",
+            description: "List the queryables known to a node",
+            segment: "queryable",
+        }
+    }
+
+    pub(crate) fn tokens(state: State) -> Self {
+        Self {
+            state,
+            name: "zenoh admin tokens",
+            description: "List the liveliness tokens known to a node",
+            segment: "token",
+        }
+    }
+}
+
+impl Command for Resources {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .optional(
+                "keyexpr",
+                SyntaxShape::String,
+                "Only list declarations intersecting this key expression",
+            )
+            .remote()
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        self.description
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each row lists the routers, peers and clients a declaration originates from."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let keyexpr = call
+            .opt::<String>(engine_state, stack, 0)?
+            .unwrap_or_else(|| "**".to_string());
+        let remote = call.get_flag::<String>(engine_state, stack, "remote")?;
+
+        let sess = self
+            .state
+            .with_session(&call.session(engine_state, stack)?, |sess| sess.clone())?;
+        let zid = remote.unwrap_or_else(|| sess.zid().to_string());
+        let replies = admin::query(
+            &sess,
+            &format!("@/{zid}/*/{}/{keyexpr}", self.segment),
+            span,
+        )?;
+
+        let prefix = format!("{}/", self.segment);
+        let resources = replies
+            .into_iter()
+            .filter_map(|reply| {
+                let keyexpr = reply.path.strip_prefix(&prefix)?.to_string();
+                let column = |name: &str| {
+                    reply
+                        .payload
+                        .get_data_by_key(name)
+                        .unwrap_or_else(|| Value::list(vec![], span))
+                };
+
+                Some(
+                    record!(
+                        "keyexpr" => keyexpr.into_value(span),
+                        "node" => reply.zid.clone().into_value(span),
+                        "routers" => column("routers"),
+                        "peers" => column("peers"),
+                        "clients" => column("clients"),
+                    )
+                    .into_value(span),
+                )
+            })
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(resources, span), None))
+    }
+}
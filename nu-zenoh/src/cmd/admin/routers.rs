@@ -0,0 +1,91 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use crate::{
+    call_ext2::CallExt2,
+    cmd::admin::{self, node_sessions},
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Routers {
+    state: State,
+}
+
+impl Routers {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Routers {
+    fn name(&self) -> &str {
+        "zenoh admin routers"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        "List the routers reachable through the admin space"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let sess = self
+            .state
+            .with_session(&call.session(engine_state, stack)?, |sess| sess.clone())?;
+        let replies = admin::query(&sess, "@/*/router", span)?;
+
+        let routers = replies
+            .into_iter()
+            .map(|reply| {
+                let column = |name: &str| {
+                    reply
+                        .payload
+                        .get_data_by_key(name)
+                        .unwrap_or_else(|| Value::nothing(span))
+                };
+
+                record!(
+                    "zid" => reply.zid.clone().into_value(span),
+                    "version" => column("version"),
+                    "locators" => column("locators"),
+                    "sessions" => Value::list(node_sessions(&reply.payload, span), span),
+                    "plugins" => column("plugins"),
+                    "metadata" => column("metadata"),
+                )
+                .into_value(span)
+            })
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(routers, span), None))
+    }
+}
@@ -0,0 +1,78 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+
+use crate::{
+    call_ext2::CallExt2,
+    cmd::admin::{self, node_sessions},
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Sessions {
+    state: State,
+}
+
+impl Sessions {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Sessions {
+    fn name(&self) -> &str {
+        "zenoh admin sessions"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .remote()
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        "List the sessions (i.e. transports) of a node"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let remote = call.get_flag::<String>(engine_state, stack, "remote")?;
+
+        let sess = self
+            .state
+            .with_session(&call.session(engine_state, stack)?, |sess| sess.clone())?;
+        let zid = remote.unwrap_or_else(|| sess.zid().to_string());
+        let replies = admin::query(&sess, &format!("@/{zid}/*"), span)?;
+
+        let transports = replies
+            .iter()
+            .flat_map(|reply| node_sessions(&reply.payload, span))
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(transports, span), None))
+    }
+}
@@ -13,7 +13,7 @@
 //
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    PipelineData, ShellError, Signature, Type,
+    LabeledError, PipelineData, ShellError, Signature, Span, Type, Value,
 };
 
 use crate::{call_ext2::CallExt2, conv, signature_ext::SignatureExt, State};
@@ -52,20 +52,24 @@ impl Command for Config {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let config = self
-            .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
-                sess.config().to_string()
-            })?;
+        let config = session_config(&self.state, &call.session(engine_state, stack)?, call.head)?;
 
-        let nujson = nu_json::from_str::<nu_json::Value>(config.trim()).map_err(|e| {
-            nu_protocol::LabeledError::new("Config deserialization failed")
-                .with_label(format!("Config deserialization failed: {e}"), call.head)
-        })?;
-
-        Ok(PipelineData::Value(
-            conv::nujson_to_value(nujson, call.head),
-            None,
-        ))
+        Ok(PipelineData::Value(config, None))
     }
 }
+
+/// Returns the configuration of a session as a record
+pub(crate) fn session_config(
+    state: &State,
+    session: &str,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let config = state.with_session(session, |sess| sess.config().to_string())?;
+
+    let nujson = nu_json::from_str::<nu_json::Value>(config.trim()).map_err(|e| {
+        LabeledError::new("Config deserialization failed")
+            .with_label(format!("Config deserialization failed: {e}"), span)
+    })?;
+
+    Ok(conv::nujson_to_value(nujson, span))
+}
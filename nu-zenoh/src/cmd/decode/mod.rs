@@ -1,2 +1,3 @@
 pub(crate) mod scouting_msg;
+pub(crate) mod transport_batch;
 pub(crate) mod transport_msg;
@@ -73,7 +73,7 @@ impl Command for ScoutingMsg {
     }
 }
 
-fn scouting_message_to_value(msg: &ScoutingMessage, span: Span) -> Value {
+pub(crate) fn scouting_message_to_value(msg: &ScoutingMessage, span: Span) -> Value {
     match &msg.body {
         ScoutingBody::Scout(scout) => scout_to_value(scout, span),
         ScoutingBody::Hello(hello) => hello_to_value(hello, span),
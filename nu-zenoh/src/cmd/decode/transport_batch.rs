@@ -0,0 +1,251 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record,
+    shell_error::generic::GenericError,
+    IntoValue, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use zenoh_codec::{RCodec, Zenoh080};
+use zenoh_protocol::transport::TransportMessage;
+
+use super::transport_msg::transport_message_to_value;
+use crate::signature_ext::SignatureExt;
+
+#[derive(Clone)]
+pub(crate) struct TransportBatch;
+
+impl Command for TransportBatch {
+    fn name(&self) -> &str {
+        "zenoh decode transport-batch"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Binary, Type::list(Type::record())),
+                (Type::list(Type::Binary), Type::list(Type::record())),
+            ])
+            .switch(
+                "datagram",
+                "Batches are not length-prefixed, each binary input being a single batch (e.g. UDP)",
+                Some('d'),
+            )
+            .switch(
+                "compression",
+                "Batches start with a header byte, as is the case once compression is negotiated",
+                Some('c'),
+            )
+            .switch(
+                "full",
+                "Decode the zenoh message bodies and extensions of network messages",
+                Some('f'),
+            )
+            .zenoh_category()
+    }
+
+    fn description(&self) -> &str {
+        "Decode a sequence of Zenoh transport batches from binary data"
+    }
+
+    fn extra_description(&self) -> &str {
+        "By default the input is a stream link capture (e.g. TCP), where each batch is prefixed \
+        by its 16-bit little-endian length; a list of binaries is then treated as consecutive chunks of the stream. \
+        Returns one {batch, batch_offset, offset, message} record per message, \
+        where 'batch_offset' is the byte offset of the batch in the input \
+        and 'offset' is the byte offset of the message in its (decompressed) batch payload."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let datagram = call.has_flag(engine_state, stack, "datagram")?;
+        let compression = call.has_flag(engine_state, stack, "compression")?;
+        let full = call.has_flag(engine_state, stack, "full")?;
+
+        let chunks = match input.into_value(span)? {
+            Value::Binary { val, .. } => vec![val],
+            Value::List { vals, .. } => vals
+                .into_iter()
+                .map(Value::into_binary)
+                .collect::<Result<_, _>>()?,
+            _ => {
+                return Err(ShellError::Generic(
+                    GenericError::new("Expected binary input", "Input must be binary data", span)
+                        .with_help("Pipe binary data or a list of binaries to this command"),
+                ));
+            }
+        };
+
+        let stream = if datagram { vec![] } else { chunks.concat() };
+
+        // NOTE: batches are (offset, bytes) pairs, where bytes exclude the length prefix
+        let mut batches = Vec::new();
+        let mut errors = Vec::new();
+        if datagram {
+            let mut offset = 0;
+            for chunk in &chunks {
+                batches.push((offset, chunk.as_slice()));
+                offset += chunk.len();
+            }
+        } else {
+            let mut offset = 0;
+            while offset < stream.len() {
+                let Some(len) = stream.get(offset..offset + 2) else {
+                    errors.push((offset, "Truncated batch length".to_string()));
+                    break;
+                };
+                let len = u16::from_le_bytes([len[0], len[1]]) as usize;
+
+                let Some(batch) = stream.get(offset + 2..offset + 2 + len) else {
+                    errors.push((offset, format!("Truncated batch of {len} bytes")));
+                    break;
+                };
+                batches.push((offset, batch));
+                offset += 2 + len;
+            }
+        }
+
+        Ok(PipelineData::Value(
+            self.decode_batches(&batches, errors, compression, full, span),
+            None,
+        ))
+    }
+}
+
+impl TransportBatch {
+    fn decode_batches(
+        &self,
+        batches: &[(usize, &[u8])],
+        errors: Vec<(usize, String)>,
+        compression: bool,
+        full: bool,
+        span: Span,
+    ) -> Value {
+        let row = |batch: usize, batch_offset: usize, offset: Option<usize>, message: Value| {
+            record!(
+                "batch"        => (batch as i64).into_value(span),
+                "batch_offset" => (batch_offset as i64).into_value(span),
+                "offset"       => offset.map(|offset| offset as i64).into_value(span),
+                "message"      => message,
+            )
+            .into_value(span)
+        };
+
+        let mut rows = Vec::new();
+        for (index, &(batch_offset, batch)) in batches.iter().enumerate() {
+            for (offset, message) in decode_batch(batch, compression, full, span) {
+                rows.push(row(index, batch_offset, offset, message));
+            }
+        }
+
+        for (batch_offset, msg) in errors {
+            rows.push(row(
+                batches.len(),
+                batch_offset,
+                None,
+                batch_error(msg, span),
+            ));
+        }
+
+        rows.into_value(span)
+    }
+}
+
+/// Decodes the messages of a batch (without its length prefix) along with their byte offsets in
+/// the batch payload, reporting errors as error values
+pub(crate) fn decode_batch(
+    batch: &[u8],
+    compression: bool,
+    full: bool,
+    span: Span,
+) -> Vec<(Option<usize>, Value)> {
+    let payload = match batch_payload(batch, compression) {
+        Ok(payload) => payload,
+        Err(msg) => return vec![(None, batch_error(msg, span))],
+    };
+
+    let codec = Zenoh080::new();
+    let mut reader = payload.as_slice();
+    let mut messages = Vec::new();
+    while !reader.is_empty() {
+        let offset = payload.len() - reader.len();
+        let msg: Result<TransportMessage, _> = codec.read(&mut reader);
+        match msg {
+            Ok(msg) => messages.push((Some(offset), transport_message_to_value(&msg, full, span))),
+            Err(_) => {
+                messages.push((
+                    Some(offset),
+                    batch_error("Zenoh080 Codec error".to_string(), span),
+                ));
+                break;
+            }
+        }
+    }
+
+    messages
+}
+
+/// Removes the complete length-prefixed batches from the buffer of a stream link
+pub(crate) fn split_batches(buffer: &mut Vec<u8>) -> Vec<Vec<u8>> {
+    let mut batches = Vec::new();
+    let mut offset = 0;
+    while let Some(len) = buffer.get(offset..offset + 2) {
+        let len = u16::from_le_bytes([len[0], len[1]]) as usize;
+        let Some(batch) = buffer.get(offset + 2..offset + 2 + len) else {
+            break;
+        };
+        batches.push(batch.to_vec());
+        offset += 2 + len;
+    }
+    buffer.drain(..offset);
+    batches
+}
+
+pub(crate) fn batch_error(msg: String, span: Span) -> Value {
+    Value::error(
+        ShellError::Generic(GenericError::new("Batch decode error", msg, span)),
+        span,
+    )
+}
+
+/// Returns the messages of a batch, stripping its header and decompressing it if needed
+fn batch_payload(batch: &[u8], compression: bool) -> Result<Vec<u8>, String> {
+    /// Flag of the batch header signaling an LZ4-compressed payload
+    const COMPRESSION: u8 = 1;
+
+    if !compression {
+        return Ok(batch.to_vec());
+    }
+
+    let (&header, payload) = batch
+        .split_first()
+        .ok_or_else(|| "Missing batch header".to_string())?;
+
+    if header & COMPRESSION == 0 {
+        return Ok(payload.to_vec());
+    }
+
+    let mut buffer = vec![0; u16::MAX as usize];
+    let len = lz4_flex::block::decompress_into(payload, &mut buffer)
+        .map_err(|err| format!("Decompression error: {err}"))?;
+    buffer.truncate(len);
+    Ok(buffer)
+}
@@ -13,21 +13,33 @@
 //
 use std::convert::TryFrom;
 
+use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
     record,
     shell_error::generic::GenericError,
     IntoValue, PipelineData, ShellError, Signature, Span, Type, Value,
 };
-use zenoh_codec::{RCodec, Zenoh080};
+use zenoh::internal::buffers::ZBuf;
+use zenoh_codec::{RCodec, Zenoh080, Zenoh080Reliability};
 use zenoh_protocol::{
-    common::ZExtBody,
-    core::{Field, Locator, Resolution, WhatAmI, WireExpr},
-    network::{declare::DeclareBody, interest::InterestMode, oam::id::OAM_LINKSTATE, NetworkBody},
+    common::{ZExtBody, ZExtUnknown},
+    core::{
+        Encoding, EntityGlobalIdProto, Field, Locator, Reliability, Resolution, WhatAmI, WireExpr,
+    },
+    network::{
+        declare::DeclareBody,
+        ext::{NodeIdType, QoSType, TimestampType},
+        interest::InterestMode,
+        oam::id::OAM_LINKSTATE,
+        request::ext::QueryTarget,
+        NetworkBody, NetworkMessage,
+    },
     transport::{
         close::reason_to_str, Fragment, Frame, InitAck, InitSyn, Join, OpenAck, OpenSyn,
         TransportBody, TransportMessage,
     },
+    zenoh::{ConsolidationMode, PushBody, RequestBody, ResponseBody},
 };
 
 use crate::signature_ext::SignatureExt;
@@ -42,7 +54,15 @@ impl Command for TransportMsg {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .input_output_type(Type::Binary, Type::record())
+            .input_output_types(vec![
+                (Type::Binary, Type::record()),
+                (Type::list(Type::Any), Type::record()),
+            ])
+            .switch(
+                "full",
+                "Decode the zenoh message bodies and extensions of network messages",
+                Some('f'),
+            )
             .zenoh_category()
     }
 
@@ -50,41 +70,130 @@ impl Command for TransportMsg {
         "Decode a Zenoh transport message from binary data"
     }
 
+    fn extra_description(&self) -> &str {
+        "Without --full, network messages only report the size of their payload. \
+        A list of fragments (either binary messages or records as output by this command) \
+        is reassembled into the network message they carry."
+    }
+
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
+        let full = call.has_flag(engine_state, stack, "full")?;
 
-        let bytes = match input {
-            PipelineData::Value(Value::Binary { val, .. }, ..) => val,
+        let value = match input.into_value(span)? {
+            Value::Binary { val, .. } => {
+                transport_message_to_value(&decode_transport_message(&val, span)?, full, span)
+            }
+            Value::List { vals, .. } => {
+                network_message_to_value(&defragment(&vals, span)?, full, span)
+            }
             _ => {
                 return Err(ShellError::Generic(
                     GenericError::new("Expected binary input", "Input must be binary data", span)
-                        .with_help("Pipe binary data to this command"),
+                        .with_help("Pipe binary data or a list of fragments to this command"),
                 ));
             }
         };
 
-        let codec = Zenoh080::new();
-        let mut reader = bytes.as_slice();
+        Ok(PipelineData::Value(value, None))
+    }
+}
+
+#[allow(clippy::result_large_err)]
+pub(super) fn decode_transport_message(
+    bytes: &[u8],
+    span: Span,
+) -> Result<TransportMessage, ShellError> {
+    let codec = Zenoh080::new();
+    let mut reader = bytes;
 
-        let msg: TransportMessage = codec.read(&mut reader).map_err(|_| {
-            nu_protocol::LabeledError::new("Failed to decode transport message")
-                .with_label("Zenoh080 Codec error", span)
-        })?;
+    Ok(codec.read(&mut reader).map_err(|_| {
+        nu_protocol::LabeledError::new("Failed to decode transport message")
+            .with_label("Zenoh080 Codec error", span)
+    })?)
+}
 
-        Ok(PipelineData::Value(
-            transport_message_to_value(&msg, span),
-            None,
+/// Reassembles the network message carried by a sequence of fragments
+#[allow(clippy::result_large_err)]
+fn defragment(fragments: &[Value], span: Span) -> Result<NetworkMessage, ShellError> {
+    let invalid = |msg: String| {
+        ShellError::Generic(GenericError::new("Invalid fragments", msg, span).with_help(
+            "Fragments are binary messages or records as output by 'zenoh decode transport-msg'",
         ))
+    };
+
+    let mut reliability = None;
+    let mut more = true;
+    let mut buffer = Vec::new();
+    for fragment in fragments {
+        if !more {
+            return Err(invalid("Fragments follow the last one".to_string()));
+        }
+
+        let fragment = match fragment {
+            Value::Binary { val, .. } => match decode_transport_message(val, span)?.body {
+                TransportBody::Fragment(fragment) => fragment_to_value(&fragment, span),
+                _ => return Err(invalid("Binary message is not a fragment".to_string())),
+            },
+            fragment => fragment.clone(),
+        };
+
+        let column = |name: &str| {
+            fragment
+                .get_data_by_key(name)
+                .ok_or_else(|| invalid(format!("Missing '{name}' column")))
+        };
+
+        if column("type")?.as_str()? != "fragment" {
+            return Err(invalid("Record is not a fragment".to_string()));
+        }
+
+        let fragment_reliability = column("reliability")?.as_str()?.to_string();
+        if reliability.get_or_insert_with(|| fragment_reliability.clone()) != &fragment_reliability
+        {
+            return Err(invalid("Fragments have distinct reliabilities".to_string()));
+        }
+
+        more = column("more")?.as_bool()?;
+
+        let payload = column("payload")?;
+        let payload = payload.as_str()?;
+        for i in (0..payload.len()).step_by(2) {
+            buffer.push(
+                payload
+                    .get(i..i + 2)
+                    .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                    .ok_or_else(|| invalid("Fragment payload is not a hex string".to_string()))?,
+            );
+        }
+    }
+
+    if more {
+        return Err(invalid("The last fragment is missing".to_string()));
     }
+
+    let reliability = reliability
+        .as_deref()
+        .unwrap_or_default()
+        .parse::<Reliability>()
+        .map_err(|_| invalid("Invalid fragment reliability".to_string()))?;
+
+    let codec = Zenoh080Reliability::new(reliability);
+    let mut reader = buffer.as_slice();
+
+    Ok(codec.read(&mut reader).map_err(|_| {
+        nu_protocol::LabeledError::new("Failed to decode network message")
+            .with_label("Zenoh080 Codec error", span)
+    })?)
 }
 
-fn transport_message_to_value(msg: &TransportMessage, span: Span) -> Value {
+pub(super) fn transport_message_to_value(msg: &TransportMessage, full: bool, span: Span) -> Value {
     match &msg.body {
         TransportBody::InitSyn(m) => init_syn_to_value(m, span),
         TransportBody::InitAck(m) => init_ack_to_value(m, span),
@@ -97,7 +206,7 @@ fn transport_message_to_value(msg: &TransportMessage, span: Span) -> Value {
         )
         .into_value(span),
         TransportBody::KeepAlive(_) => keep_alive_to_value(span),
-        TransportBody::Frame(m) => frame_to_value(m, span),
+        TransportBody::Frame(m) => frame_to_value(m, full, span),
         TransportBody::Fragment(m) => fragment_to_value(m, span),
         TransportBody::OAM(m) => transport_oam_to_value(m, span),
         TransportBody::Join(m) => join_to_value(m, span),
@@ -178,11 +287,11 @@ fn keep_alive_to_value(span: Span) -> Value {
     record!("type" => "keep-alive".into_value(span)).into_value(span)
 }
 
-fn frame_to_value(m: &Frame, span: Span) -> Value {
+fn frame_to_value(m: &Frame, full: bool, span: Span) -> Value {
     let messages: Vec<Value> = m
         .payload
         .iter()
-        .map(|nm| network_message_to_value(nm, span))
+        .map(|nm| network_message_to_value(nm, full, span))
         .collect();
     record!(
         "type"        => "frame".into_value(span),
@@ -259,9 +368,9 @@ fn join_to_value(m: &Join, span: Span) -> Value {
     .into_value(span)
 }
 
-fn network_message_to_value(msg: &zenoh_protocol::network::NetworkMessage, span: Span) -> Value {
+fn network_message_to_value(msg: &NetworkMessage, full: bool, span: Span) -> Value {
     let reliability = msg.reliability.to_string().to_lowercase();
-    match &msg.body {
+    let mut value = match &msg.body {
         NetworkBody::Push(m) => push_to_value(m, reliability, span),
         NetworkBody::Request(m) => request_to_value(m, reliability, span),
         NetworkBody::Response(m) => response_to_value(m, reliability, span),
@@ -269,7 +378,228 @@ fn network_message_to_value(msg: &zenoh_protocol::network::NetworkMessage, span:
         NetworkBody::Interest(m) => interest_to_value(m, reliability, span),
         NetworkBody::Declare(m) => declare_to_value(m, reliability, span),
         NetworkBody::OAM(m) => network_oam_to_value(m, reliability, span),
+    };
+
+    if let (true, Value::Record { val, .. }) = (full, &mut value) {
+        val.to_mut().extend(network_message_full_columns(msg, span));
+    }
+
+    value
+}
+
+/// Returns the extensions and zenoh message body of a network message
+fn network_message_full_columns(msg: &NetworkMessage, span: Span) -> Vec<(String, Value)> {
+    let mut columns = vec![];
+    let mut push = |name: &str, value: Value| columns.push((name.to_string(), value));
+
+    match &msg.body {
+        NetworkBody::Push(m) => {
+            push("ext_qos", qos_to_value(&m.ext_qos, span));
+            push("ext_tstamp", tstamp_to_value(m.ext_tstamp.as_ref(), span));
+            push("ext_nodeid", nodeid_to_value(&m.ext_nodeid, span));
+            push("body", push_body_to_value(&m.payload, span));
+        }
+        NetworkBody::Request(m) => {
+            let target = match m.ext_target {
+                QueryTarget::BestMatching => "best-matching",
+                QueryTarget::All => "all",
+                QueryTarget::AllComplete => "all-complete",
+            };
+            push("ext_qos", qos_to_value(&m.ext_qos, span));
+            push("ext_tstamp", tstamp_to_value(m.ext_tstamp.as_ref(), span));
+            push("ext_nodeid", nodeid_to_value(&m.ext_nodeid, span));
+            push("ext_target", target.into_value(span));
+            push(
+                "ext_budget",
+                m.ext_budget.map(|b| b.get() as i64).into_value(span),
+            );
+            push(
+                "ext_timeout_ms",
+                m.ext_timeout.map(|t| t.as_millis() as i64).into_value(span),
+            );
+            push("body", request_body_to_value(&m.payload, span));
+        }
+        NetworkBody::Response(m) => {
+            push("ext_qos", qos_to_value(&m.ext_qos, span));
+            push("ext_tstamp", tstamp_to_value(m.ext_tstamp.as_ref(), span));
+            push(
+                "ext_respid",
+                m.ext_respid
+                    .as_ref()
+                    .map(|id| {
+                        entity_global_id_to_value(
+                            &EntityGlobalIdProto {
+                                zid: id.zid,
+                                eid: id.eid,
+                            },
+                            span,
+                        )
+                    })
+                    .into_value(span),
+            );
+            push("body", response_body_to_value(&m.payload, span));
+        }
+        NetworkBody::ResponseFinal(m) => {
+            push("ext_qos", qos_to_value(&m.ext_qos, span));
+            push("ext_tstamp", tstamp_to_value(m.ext_tstamp.as_ref(), span));
+        }
+        NetworkBody::Interest(m) => {
+            push("ext_qos", qos_to_value(&m.ext_qos, span));
+            push("ext_tstamp", tstamp_to_value(m.ext_tstamp.as_ref(), span));
+            push("ext_nodeid", nodeid_to_value(&m.ext_nodeid, span));
+        }
+        NetworkBody::Declare(m) => {
+            push("ext_qos", qos_to_value(&m.ext_qos, span));
+            push("ext_tstamp", tstamp_to_value(m.ext_tstamp.as_ref(), span));
+            push("ext_nodeid", nodeid_to_value(&m.ext_nodeid, span));
+        }
+        NetworkBody::OAM(m) => {
+            push("ext_qos", qos_to_value(&m.ext_qos, span));
+            push("ext_tstamp", tstamp_to_value(m.ext_tstamp.as_ref(), span));
+        }
+    }
+
+    columns
+}
+
+fn push_body_to_value(body: &PushBody, span: Span) -> Value {
+    match body {
+        PushBody::Put(put) => record!(
+            "type"           => "put".into_value(span),
+            "timestamp"      => put.timestamp.map(|ts| ts.to_string()).into_value(span),
+            "encoding"       => encoding_to_value(&put.encoding, span),
+            "ext_sinfo"      => sinfo_to_value(put.ext_sinfo.as_ref().map(|s| (&s.id, s.sn)), span),
+            "ext_attachment" => put.ext_attachment.as_ref().map(|a| zbuf_to_hex(&a.buffer)).into_value(span),
+            "ext_shm"        => put.ext_shm.is_some().into_value(span),
+            "ext_unknown"    => ext_unknown_to_value(&put.ext_unknown, span),
+            "payload"        => zbuf_to_hex(&put.payload).into_value(span),
+        )
+        .into_value(span),
+        PushBody::Del(del) => record!(
+            "type"           => "del".into_value(span),
+            "timestamp"      => del.timestamp.map(|ts| ts.to_string()).into_value(span),
+            "ext_sinfo"      => sinfo_to_value(del.ext_sinfo.as_ref().map(|s| (&s.id, s.sn)), span),
+            "ext_attachment" => del.ext_attachment.as_ref().map(|a| zbuf_to_hex(&a.buffer)).into_value(span),
+            "ext_unknown"    => ext_unknown_to_value(&del.ext_unknown, span),
+        )
+        .into_value(span),
+    }
+}
+
+fn request_body_to_value(body: &RequestBody, span: Span) -> Value {
+    match body {
+        RequestBody::Query(query) => {
+            let ext_body = query.ext_body.as_ref().map(|body| {
+                record!(
+                    "encoding" => encoding_to_value(&body.encoding, span),
+                    "ext_shm"  => body.ext_shm.is_some().into_value(span),
+                    "payload"  => zbuf_to_hex(&body.payload).into_value(span),
+                )
+                .into_value(span)
+            });
+            record!(
+                "type"           => "query".into_value(span),
+                "consolidation"  => consolidation_to_value(query.consolidation, span),
+                "parameters"     => query.parameters.clone().into_value(span),
+                "ext_sinfo"      => sinfo_to_value(query.ext_sinfo.as_ref().map(|s| (&s.id, s.sn)), span),
+                "ext_body"       => ext_body.into_value(span),
+                "ext_attachment" => query.ext_attachment.as_ref().map(|a| zbuf_to_hex(&a.buffer)).into_value(span),
+                "ext_unknown"    => ext_unknown_to_value(&query.ext_unknown, span),
+            )
+            .into_value(span)
+        }
+    }
+}
+
+fn response_body_to_value(body: &ResponseBody, span: Span) -> Value {
+    match body {
+        ResponseBody::Reply(reply) => record!(
+            "type"          => "reply".into_value(span),
+            "consolidation" => consolidation_to_value(reply.consolidation, span),
+            "ext_unknown"   => ext_unknown_to_value(&reply.ext_unknown, span),
+            "body"          => push_body_to_value(&reply.payload, span),
+        )
+        .into_value(span),
+        ResponseBody::Err(err) => record!(
+            "type"        => "err".into_value(span),
+            "encoding"    => encoding_to_value(&err.encoding, span),
+            "ext_sinfo"   => sinfo_to_value(err.ext_sinfo.as_ref().map(|s| (&s.id, s.sn)), span),
+            "ext_shm"     => err.ext_shm.is_some().into_value(span),
+            "ext_unknown" => ext_unknown_to_value(&err.ext_unknown, span),
+            "payload"     => zbuf_to_hex(&err.payload).into_value(span),
+        )
+        .into_value(span),
+    }
+}
+
+fn qos_to_value<const ID: u8>(qos: &QoSType<ID>, span: Span) -> Value {
+    record!(
+        "priority"           => (qos.get_priority() as i64).into_value(span),
+        "congestion_control" => (qos.get_congestion_control() as i64).into_value(span),
+        "express"            => qos.is_express().into_value(span),
+    )
+    .into_value(span)
+}
+
+fn tstamp_to_value<const ID: u8>(tstamp: Option<&TimestampType<ID>>, span: Span) -> Value {
+    tstamp
+        .map(|tstamp| tstamp.timestamp.to_string())
+        .into_value(span)
+}
+
+fn nodeid_to_value<const ID: u8>(nodeid: &NodeIdType<ID>, span: Span) -> Value {
+    (nodeid.node_id as i64).into_value(span)
+}
+
+fn entity_global_id_to_value(id: &EntityGlobalIdProto, span: Span) -> Value {
+    record!(
+        "zid" => id.zid.to_string().into_value(span),
+        "eid" => (id.eid as i64).into_value(span),
+    )
+    .into_value(span)
+}
+
+fn sinfo_to_value(sinfo: Option<(&EntityGlobalIdProto, u32)>, span: Span) -> Value {
+    match sinfo {
+        Some((id, sn)) => record!(
+            "zid" => id.zid.to_string().into_value(span),
+            "eid" => (id.eid as i64).into_value(span),
+            "sn"  => (sn as i64).into_value(span),
+        )
+        .into_value(span),
+        None => Value::nothing(span),
+    }
+}
+
+fn encoding_to_value(encoding: &Encoding, span: Span) -> Value {
+    zenoh::bytes::Encoding::from(encoding.clone())
+        .to_string()
+        .into_value(span)
+}
+
+fn consolidation_to_value(consolidation: ConsolidationMode, span: Span) -> Value {
+    match consolidation {
+        ConsolidationMode::Auto => "auto",
+        ConsolidationMode::None => "none",
+        ConsolidationMode::Monotonic => "monotonic",
+        ConsolidationMode::Latest => "latest",
     }
+    .into_value(span)
+}
+
+/// Returns the ids of unknown extensions
+fn ext_unknown_to_value(exts: &[ZExtUnknown], span: Span) -> Value {
+    exts.iter()
+        .map(|ext| (ext.id as i64).into_value(span))
+        .collect::<Vec<_>>()
+        .into_value(span)
+}
+
+fn zbuf_to_hex(zbuf: &ZBuf) -> String {
+    zbuf.zslices()
+        .flat_map(|s| s.as_slice().iter())
+        .map(|b| format!("{b:02x}"))
+        .collect()
 }
 
 fn push_to_value(push: &zenoh_protocol::network::Push, reliability: String, span: Span) -> Value {
@@ -0,0 +1,121 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::str::FromStr;
+
+use nu_protocol::{
+    shell_error::generic::GenericError, PipelineData, Record, ShellError, Span, Value,
+};
+
+pub(crate) mod scouting_msg;
+pub(crate) mod transport_msg;
+
+/// Returns the record piped to an encode command
+#[allow(clippy::result_large_err)]
+fn input_record(input: PipelineData, span: Span) -> Result<Value, ShellError> {
+    match input {
+        PipelineData::Value(value @ Value::Record { .. }, ..) => Ok(value),
+        _ => Err(ShellError::Generic(
+            GenericError::new("Expected record input", "Input must be a record", span)
+                .with_help("Pipe a record as output by 'zenoh decode' to this command"),
+        )),
+    }
+}
+
+/// Typed access to the columns of a message record, as output by `zenoh decode`
+struct Fields<'a> {
+    record: &'a Record,
+    span: Span,
+}
+
+#[allow(clippy::result_large_err)]
+impl<'a> Fields<'a> {
+    fn new(value: &'a Value, span: Span) -> Result<Self, ShellError> {
+        Ok(Self {
+            record: value.as_record()?,
+            span,
+        })
+    }
+
+    fn error(&self, msg: String) -> ShellError {
+        ShellError::Generic(GenericError::new("Invalid message record", msg, self.span))
+    }
+
+    /// Returns a column, treating `null` as missing
+    fn opt(&self, name: &str) -> Option<&'a Value> {
+        self.record
+            .get(name)
+            .filter(|value| !matches!(value, Value::Nothing { .. }))
+    }
+
+    fn get(&self, name: &str) -> Result<&'a Value, ShellError> {
+        self.opt(name)
+            .ok_or_else(|| self.error(format!("Missing column '{name}'")))
+    }
+
+    fn str(&self, name: &str) -> Result<&'a str, ShellError> {
+        self.get(name)?.as_str()
+    }
+
+    fn int<T: TryFrom<i64>>(&self, name: &str) -> Result<T, ShellError> {
+        let int = self.get(name)?.as_int()?;
+        T::try_from(int).map_err(|_| self.error(format!("Column '{name}' is out of range: {int}")))
+    }
+
+    fn opt_int<T: TryFrom<i64>>(&self, name: &str) -> Result<Option<T>, ShellError> {
+        self.opt(name).map(|_| self.int(name)).transpose()
+    }
+
+    /// Returns a boolean column, defaulting to `false` (e.g. for absent extensions)
+    fn flag(&self, name: &str) -> Result<bool, ShellError> {
+        self.opt(name).map_or(Ok(false), Value::as_bool)
+    }
+
+    fn parse<T: FromStr>(&self, name: &str) -> Result<T, ShellError> {
+        let s = self.str(name)?;
+        s.parse()
+            .map_err(|_| self.error(format!("Invalid value for column '{name}': '{s}'")))
+    }
+
+    fn opt_parse<T: FromStr>(&self, name: &str) -> Result<Option<T>, ShellError> {
+        self.opt(name).map(|_| self.parse(name)).transpose()
+    }
+
+    /// Returns bytes from either a binary or a hex string column
+    fn bytes(&self, name: &str) -> Result<Vec<u8>, ShellError> {
+        match self.get(name)? {
+            Value::Binary { val, .. } => Ok(val.clone()),
+            value => {
+                let hex = value.as_str()?;
+                (0..hex.len())
+                    .step_by(2)
+                    .map(|i| {
+                        hex.get(i..i + 2)
+                            .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                            .ok_or_else(|| {
+                                self.error(format!("Column '{name}' is not a hex string"))
+                            })
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn list(&self, name: &str) -> Result<&'a [Value], ShellError> {
+        self.get(name)?.as_list()
+    }
+
+    fn record(&self, name: &str) -> Result<Fields<'a>, ShellError> {
+        Fields::new(self.get(name)?, self.span)
+    }
+}
@@ -0,0 +1,100 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+use zenoh_codec::{WCodec, Zenoh080};
+use zenoh_protocol::{
+    core::Locator,
+    scouting::{HelloProto, Scout, ScoutingBody, ScoutingMessage},
+};
+
+use super::Fields;
+use crate::signature_ext::SignatureExt;
+
+#[derive(Clone)]
+pub(crate) struct ScoutingMsg;
+
+impl Command for ScoutingMsg {
+    fn name(&self) -> &str {
+        "zenoh encode scouting-msg"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::record(), Type::Binary)
+            .zenoh_category()
+    }
+
+    fn description(&self) -> &str {
+        "Encode a Zenoh scouting message to binary data"
+    }
+
+    fn extra_description(&self) -> &str {
+        "This is the inverse of 'zenoh decode scouting-msg': the input is a scout or hello record as output by it."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let value = super::input_record(input, span)?;
+        let msg = scouting_message_from_fields(&Fields::new(&value, span)?)?;
+
+        let mut bytes = Vec::new();
+        Zenoh080::new().write(&mut bytes, &msg).map_err(|_| {
+            nu_protocol::LabeledError::new("Failed to encode scouting message")
+                .with_label("Zenoh080 Codec error", span)
+        })?;
+
+        Ok(PipelineData::Value(Value::binary(bytes, span), None))
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn scouting_message_from_fields(fields: &Fields) -> Result<ScoutingMessage, ShellError> {
+    let body = match fields.str("type")? {
+        "scout" => ScoutingBody::Scout(Scout {
+            version: fields.int("version")?,
+            what: fields.parse("what")?,
+            zid: fields.opt_parse("zid")?,
+        }),
+        "hello" => ScoutingBody::Hello(HelloProto {
+            version: fields.int("version")?,
+            whatami: fields.parse("whatami")?,
+            zid: fields.parse("zid")?,
+            locators: fields
+                .list("locators")?
+                .iter()
+                .map(|locator| {
+                    let locator = locator.as_str()?;
+                    locator
+                        .parse::<Locator>()
+                        .map_err(|_| fields.error(format!("Invalid locator: '{locator}'")))
+                })
+                .collect::<Result<_, _>>()?,
+        }),
+        other => {
+            return Err(fields.error(format!("Unknown scouting message type '{other}'")));
+        }
+    };
+
+    Ok(ScoutingMessage { body })
+}
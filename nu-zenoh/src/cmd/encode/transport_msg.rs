@@ -0,0 +1,879 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{borrow::Cow, num::NonZeroU32, time::Duration};
+
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+use zenoh::internal::buffers::{Buffer, DidntWrite, ZBuf, ZSlice};
+use zenoh_codec::{WCodec, Zenoh080};
+use zenoh_protocol::{
+    common::{ZExtBody, ZExtUnit},
+    core::{
+        Bits, CongestionControl, Encoding, EntityGlobalIdProto, Field, Locator, Priority,
+        Reliability, Resolution, WireExpr,
+    },
+    network::{
+        declare::{self, common::ext::WireExprType, queryable::ext::QueryableInfoType},
+        ext::{NodeIdType, QoSType, TimestampType},
+        interest::{self, InterestMode, InterestOptions},
+        oam::{self, id::OAM_LINKSTATE},
+        push, request, response, Declare, DeclareBody, Interest, Mapping, NetworkBody,
+        NetworkMessage, Oam, Push, Request, Response, ResponseFinal,
+    },
+    transport::{
+        close::reason_to_str, fragment, frame, init, join, BatchSize, Close, Fragment, Frame,
+        InitAck, InitSyn, Join, KeepAlive, OpenAck, OpenSyn, PrioritySn, TransportBody,
+        TransportMessage,
+    },
+    zenoh::{
+        ext::{AttachmentType, SourceInfoType},
+        query, ConsolidationMode, Del, PushBody, Put, Query, Reply, RequestBody, ResponseBody,
+    },
+};
+
+use super::Fields;
+use crate::signature_ext::SignatureExt;
+
+#[derive(Clone)]
+pub(crate) struct TransportMsg;
+
+impl Command for TransportMsg {
+    fn name(&self) -> &str {
+        "zenoh encode transport-msg"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::record(), Type::Binary)
+            .zenoh_category()
+    }
+
+    fn description(&self) -> &str {
+        "Encode a Zenoh transport message to binary data"
+    }
+
+    fn extra_description(&self) -> &str {
+        "This is the inverse of 'zenoh decode transport-msg': the input is a record as output by it, \
+        such that decoding the output yields the input again. \
+        Since decoded push, request and response messages only report the size of their payload, \
+        they are encoded with a zero-filled payload of 'payload_size' bytes (at most 65535) unless they have a 'payload' column (binary or hex). \
+        With the extension and 'body' columns output by 'zenoh decode transport-msg --full', \
+        network messages are encoded with them instead (and 'payload_size' is ignored); \
+        shared-memory and unknown extensions can't be encoded and return an error. \
+        Extensions which are not part of the record are left unset."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let value = super::input_record(input, span)?;
+        let msg = transport_message_from_fields(&Fields::new(&value, span)?)?;
+
+        let mut bytes = Vec::new();
+        Zenoh080::new().write(&mut bytes, &msg).map_err(|_| {
+            nu_protocol::LabeledError::new("Failed to encode transport message")
+                .with_label("Zenoh080 Codec error", span)
+        })?;
+
+        Ok(PipelineData::Value(Value::binary(bytes, span), None))
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn transport_message_from_fields(fields: &Fields) -> Result<TransportMessage, ShellError> {
+    let body = match fields.str("type")? {
+        "init-syn" => TransportBody::InitSyn(init_syn_from_fields(fields)?),
+        "init-ack" => TransportBody::InitAck(init_ack_from_fields(fields)?),
+        "open-syn" => TransportBody::OpenSyn(open_syn_from_fields(fields)?),
+        "open-ack" => TransportBody::OpenAck(open_ack_from_fields(fields)?),
+        "close" => TransportBody::Close(Close {
+            reason: close_reason_from_fields(fields)?,
+            session: fields.flag("session")?,
+        }),
+        "keep-alive" => TransportBody::KeepAlive(KeepAlive),
+        "frame" => TransportBody::Frame(frame_from_fields(fields)?),
+        "fragment" => TransportBody::Fragment(fragment_from_fields(fields)?),
+        "oam" => TransportBody::OAM(zenoh_protocol::transport::Oam {
+            id: fields.int("id")?,
+            body: oam_body_from_fields(fields, None)?,
+            ext_qos: zenoh_protocol::transport::oam::ext::QoSType::DEFAULT,
+        }),
+        "join" => TransportBody::Join(join_from_fields(fields)?),
+        other => {
+            return Err(fields.error(format!("Unknown transport message type '{other}'")));
+        }
+    };
+
+    Ok(TransportMessage { body })
+}
+
+#[allow(clippy::result_large_err)]
+fn init_syn_from_fields(fields: &Fields) -> Result<InitSyn, ShellError> {
+    Ok(InitSyn {
+        version: fields.int("version")?,
+        whatami: fields.parse("whatami")?,
+        zid: fields.parse("zid")?,
+        resolution: resolution_from_fields(&fields.record("resolution")?)?,
+        batch_size: fields.int("batch_size")?,
+        ext_qos: unit_ext(fields, "ext_qos")?,
+        ext_qos_link: None,
+        ext_shm: None,
+        ext_auth: None,
+        ext_mlink: None,
+        ext_lowlatency: unit_ext(fields, "ext_lowlatency")?,
+        ext_compression: unit_ext(fields, "ext_compression")?,
+        ext_patch: init::ext::PatchType::new(fields.int("ext_patch")?),
+        ext_region_name: None,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn init_ack_from_fields(fields: &Fields) -> Result<InitAck, ShellError> {
+    Ok(InitAck {
+        version: fields.int("version")?,
+        whatami: fields.parse("whatami")?,
+        zid: fields.parse("zid")?,
+        resolution: resolution_from_fields(&fields.record("resolution")?)?,
+        batch_size: fields.int("batch_size")?,
+        cookie: ZSlice::from(fields.bytes("cookie")?),
+        ext_qos: unit_ext(fields, "ext_qos")?,
+        ext_qos_link: None,
+        ext_shm: None,
+        ext_auth: None,
+        ext_mlink: None,
+        ext_lowlatency: unit_ext(fields, "ext_lowlatency")?,
+        ext_compression: unit_ext(fields, "ext_compression")?,
+        ext_patch: init::ext::PatchType::new(fields.int("ext_patch")?),
+        ext_region_name: None,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn open_syn_from_fields(fields: &Fields) -> Result<OpenSyn, ShellError> {
+    Ok(OpenSyn {
+        lease: Duration::from_millis(fields.int("lease_ms")?),
+        initial_sn: fields.int("initial_sn")?,
+        cookie: ZSlice::from(fields.bytes("cookie")?),
+        ext_qos: unit_ext(fields, "ext_qos")?,
+        ext_shm: None,
+        ext_auth: None,
+        ext_mlink: None,
+        ext_lowlatency: unit_ext(fields, "ext_lowlatency")?,
+        ext_compression: unit_ext(fields, "ext_compression")?,
+        ext_south: None,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn open_ack_from_fields(fields: &Fields) -> Result<OpenAck, ShellError> {
+    Ok(OpenAck {
+        lease: Duration::from_millis(fields.int("lease_ms")?),
+        initial_sn: fields.int("initial_sn")?,
+        ext_qos: unit_ext(fields, "ext_qos")?,
+        ext_shm: None,
+        ext_auth: None,
+        ext_mlink: None,
+        ext_lowlatency: unit_ext(fields, "ext_lowlatency")?,
+        ext_compression: unit_ext(fields, "ext_compression")?,
+        ext_south: None,
+    })
+}
+
+/// Accepts both the reason names output by the decoder and raw reason codes
+#[allow(clippy::result_large_err)]
+fn close_reason_from_fields(fields: &Fields) -> Result<u8, ShellError> {
+    if let Ok(reason) = fields.int::<u8>("reason") {
+        return Ok(reason);
+    }
+
+    let reason = fields.str("reason")?;
+    (0..=u8::MAX)
+        .find(|code| reason_to_str(*code) == reason)
+        .ok_or_else(|| fields.error(format!("Unknown close reason '{reason}'")))
+}
+
+#[allow(clippy::result_large_err)]
+fn frame_from_fields(fields: &Fields) -> Result<Frame, ShellError> {
+    let reliability = fields.parse::<Reliability>("reliability")?;
+    let payload = fields
+        .list("messages")?
+        .iter()
+        .map(|msg| network_message_from_fields(&Fields::new(msg, fields.span)?, reliability))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Frame {
+        reliability,
+        sn: fields.int("sn")?,
+        ext_qos: frame::ext::QoSType::DEFAULT,
+        payload,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn fragment_from_fields(fields: &Fields) -> Result<Fragment, ShellError> {
+    Ok(Fragment {
+        reliability: fields.parse("reliability")?,
+        more: fields.flag("more")?,
+        sn: fields.int("sn")?,
+        payload: ZSlice::from(fields.bytes("payload")?),
+        ext_qos: fragment::ext::QoSType::DEFAULT,
+        ext_first: unit_ext(fields, "first")?,
+        ext_drop: unit_ext(fields, "drop")?,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn join_from_fields(fields: &Fields) -> Result<Join, ShellError> {
+    let next_sn = fields.record("next_sn")?;
+    let next_sn = PrioritySn {
+        reliable: next_sn.int("reliable")?,
+        best_effort: next_sn.int("best_effort")?,
+    };
+
+    // NOTE: the decoder only reports the presence of per-priority sequence numbers, the default
+    // ones are used for every priority
+    let ext_qos = fields
+        .flag("ext_qos")?
+        .then(|| Box::new([next_sn; Priority::NUM]));
+
+    Ok(Join {
+        version: fields.int("version")?,
+        whatami: fields.parse("whatami")?,
+        zid: fields.parse("zid")?,
+        resolution: resolution_from_fields(&fields.record("resolution")?)?,
+        batch_size: fields.int("batch_size")?,
+        lease: Duration::from_millis(fields.int("lease_ms")?),
+        next_sn,
+        ext_qos,
+        ext_shm: None,
+        ext_patch: join::ext::PatchType::new(fields.int("ext_patch")?),
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn network_message_from_fields(
+    fields: &Fields,
+    reliability: Reliability,
+) -> Result<NetworkMessage, ShellError> {
+    let reliability = fields
+        .opt_parse::<Reliability>("reliability")?
+        .unwrap_or(reliability);
+
+    let body = match fields.str("type")? {
+        "push" => NetworkBody::Push(Push {
+            wire_expr: wire_expr_from_fields(fields, "wire_expr")?,
+            ext_qos: qos_from_fields(fields, push::ext::QoSType::PUSH)?,
+            ext_tstamp: tstamp_from_fields(fields)?,
+            ext_nodeid: nodeid_from_fields(fields)?,
+            payload: match fields.opt("body") {
+                Some(_) => push_body_from_fields(&fields.record("body")?)?,
+                None => PushBody::Put(put(payload_from_fields(fields)?)),
+            },
+        }),
+        "request" => NetworkBody::Request(Request {
+            id: fields.int("id")?,
+            wire_expr: wire_expr_from_fields(fields, "wire_expr")?,
+            ext_qos: qos_from_fields(fields, request::ext::QoSType::REQUEST)?,
+            ext_tstamp: tstamp_from_fields(fields)?,
+            ext_nodeid: nodeid_from_fields(fields)?,
+            ext_target: match fields.opt("ext_target").map(Value::as_str).transpose()? {
+                None | Some("best-matching") => request::ext::QueryTarget::BestMatching,
+                Some("all") => request::ext::QueryTarget::All,
+                Some("all-complete") => request::ext::QueryTarget::AllComplete,
+                Some(other) => return Err(fields.error(format!("Unknown query target '{other}'"))),
+            },
+            ext_budget: fields
+                .opt_int::<u32>("ext_budget")?
+                .and_then(NonZeroU32::new),
+            ext_timeout: fields
+                .opt_int::<u64>("ext_timeout_ms")?
+                .map(Duration::from_millis),
+            payload: match fields.opt("body") {
+                Some(_) => request_body_from_fields(&fields.record("body")?)?,
+                None => {
+                    let payload = payload_from_fields(fields)?;
+                    RequestBody::Query(Query {
+                        consolidation: ConsolidationMode::DEFAULT,
+                        parameters: String::new(),
+                        ext_sinfo: None,
+                        ext_body: (!payload.is_empty()).then(|| query::ext::QueryBodyType {
+                            ext_shm: None,
+                            encoding: Encoding::empty(),
+                            payload,
+                        }),
+                        ext_attachment: None,
+                        ext_unknown: vec![],
+                    })
+                }
+            },
+        }),
+        "response" => NetworkBody::Response(Response {
+            rid: fields.int("rid")?,
+            wire_expr: wire_expr_from_fields(fields, "wire_expr")?,
+            payload: match fields.opt("body") {
+                Some(_) => response_body_from_fields(&fields.record("body")?)?,
+                None => ResponseBody::Reply(Reply {
+                    consolidation: ConsolidationMode::DEFAULT,
+                    ext_unknown: vec![],
+                    payload: PushBody::Put(put(payload_from_fields(fields)?)),
+                }),
+            },
+            ext_qos: qos_from_fields(fields, response::ext::QoSType::DEFAULT)?,
+            ext_tstamp: tstamp_from_fields(fields)?,
+            ext_respid: fields
+                .opt("ext_respid")
+                .map(|_| {
+                    let id = fields.record("ext_respid")?;
+                    Ok::<_, ShellError>(response::ext::ResponderIdType {
+                        zid: id.parse("zid")?,
+                        eid: id.int("eid")?,
+                    })
+                })
+                .transpose()?,
+        }),
+        "response-final" => NetworkBody::ResponseFinal(ResponseFinal {
+            rid: fields.int("rid")?,
+            ext_qos: qos_from_fields(fields, response::ext::QoSType::DEFAULT)?,
+            ext_tstamp: tstamp_from_fields(fields)?,
+        }),
+        "interest" => NetworkBody::Interest(interest_from_fields(fields)?),
+        "declare" => NetworkBody::Declare(Declare {
+            interest_id: fields.opt_int("interest_id")?,
+            ext_qos: qos_from_fields(fields, declare::ext::QoSType::DECLARE)?,
+            ext_tstamp: tstamp_from_fields(fields)?,
+            ext_nodeid: nodeid_from_fields(fields)?,
+            body: declare_body_from_fields(&fields.record("body")?)?,
+        }),
+        "oam" => {
+            let id = fields.int("id")?;
+            NetworkBody::OAM(Oam {
+                id,
+                body: oam_body_from_fields(fields, Some(id))?,
+                ext_qos: qos_from_fields(fields, oam::ext::QoSType::OAM)?,
+                ext_tstamp: tstamp_from_fields(fields)?,
+            })
+        }
+        other => {
+            return Err(fields.error(format!("Unknown network message type '{other}'")));
+        }
+    };
+
+    Ok(NetworkMessage { body, reliability })
+}
+
+/// Encodes the `body` column of a push message or reply, as output by `--full`
+#[allow(clippy::result_large_err)]
+fn push_body_from_fields(fields: &Fields) -> Result<PushBody, ShellError> {
+    unsupported_exts_from_fields(fields)?;
+
+    Ok(match fields.str("type")? {
+        "put" => PushBody::Put(Put {
+            timestamp: fields.opt_parse("timestamp")?,
+            encoding: encoding_from_fields(fields)?,
+            ext_sinfo: sinfo_from_fields(fields)?,
+            ext_attachment: attachment_from_fields(fields)?,
+            ext_shm: None,
+            ext_unknown: vec![],
+            payload: ZBuf::from(fields.bytes("payload")?),
+        }),
+        "del" => PushBody::Del(Del {
+            timestamp: fields.opt_parse("timestamp")?,
+            ext_sinfo: sinfo_from_fields(fields)?,
+            ext_attachment: attachment_from_fields(fields)?,
+            ext_unknown: vec![],
+        }),
+        other => return Err(fields.error(format!("Unknown push body type '{other}'"))),
+    })
+}
+
+/// Encodes the `body` column of a request message, as output by `--full`
+#[allow(clippy::result_large_err)]
+fn request_body_from_fields(fields: &Fields) -> Result<RequestBody, ShellError> {
+    unsupported_exts_from_fields(fields)?;
+
+    match fields.str("type")? {
+        "query" => Ok(RequestBody::Query(Query {
+            consolidation: consolidation_from_fields(fields)?,
+            parameters: fields.str("parameters")?.to_string(),
+            ext_sinfo: sinfo_from_fields(fields)?,
+            ext_body: fields
+                .opt("ext_body")
+                .map(|_| {
+                    let body = fields.record("ext_body")?;
+                    unsupported_exts_from_fields(&body)?;
+                    Ok::<_, ShellError>(query::ext::QueryBodyType {
+                        ext_shm: None,
+                        encoding: encoding_from_fields(&body)?,
+                        payload: ZBuf::from(body.bytes("payload")?),
+                    })
+                })
+                .transpose()?,
+            ext_attachment: attachment_from_fields(fields)?,
+            ext_unknown: vec![],
+        })),
+        other => Err(fields.error(format!("Unknown request body type '{other}'"))),
+    }
+}
+
+/// Encodes the `body` column of a response message, as output by `--full`
+#[allow(clippy::result_large_err)]
+fn response_body_from_fields(fields: &Fields) -> Result<ResponseBody, ShellError> {
+    unsupported_exts_from_fields(fields)?;
+
+    Ok(match fields.str("type")? {
+        "reply" => ResponseBody::Reply(Reply {
+            consolidation: consolidation_from_fields(fields)?,
+            ext_unknown: vec![],
+            payload: push_body_from_fields(&fields.record("body")?)?,
+        }),
+        "err" => ResponseBody::Err(zenoh_protocol::zenoh::Err {
+            encoding: encoding_from_fields(fields)?,
+            ext_sinfo: sinfo_from_fields(fields)?,
+            ext_shm: None,
+            ext_unknown: vec![],
+            payload: ZBuf::from(fields.bytes("payload")?),
+        }),
+        other => return Err(fields.error(format!("Unknown response body type '{other}'"))),
+    })
+}
+
+/// Returns the `ext_qos` column if any, or the message's default QoS otherwise
+#[allow(clippy::result_large_err)]
+fn qos_from_fields<const ID: u8>(
+    fields: &Fields,
+    default: QoSType<ID>,
+) -> Result<QoSType<ID>, ShellError> {
+    if fields.opt("ext_qos").is_none() {
+        return Ok(default);
+    }
+
+    let qos = fields.record("ext_qos")?;
+    let priority = qos.int::<u8>("priority")?;
+    let priority = Priority::try_from(priority)
+        .map_err(|_| qos.error(format!("Invalid priority: {priority}")))?;
+    let congestion_control = match qos.int::<u8>("congestion_control")? {
+        0 => CongestionControl::Drop,
+        1 => CongestionControl::Block,
+        2 => CongestionControl::BlockFirst,
+        other => return Err(qos.error(format!("Invalid congestion control: {other}"))),
+    };
+
+    Ok(QoSType::new(
+        priority,
+        congestion_control,
+        qos.flag("express")?,
+    ))
+}
+
+#[allow(clippy::result_large_err)]
+fn tstamp_from_fields<const ID: u8>(
+    fields: &Fields,
+) -> Result<Option<TimestampType<ID>>, ShellError> {
+    Ok(fields
+        .opt_parse("ext_tstamp")?
+        .map(|timestamp| TimestampType { timestamp }))
+}
+
+#[allow(clippy::result_large_err)]
+fn nodeid_from_fields<const ID: u8>(fields: &Fields) -> Result<NodeIdType<ID>, ShellError> {
+    Ok(NodeIdType {
+        node_id: fields.opt_int("ext_nodeid")?.unwrap_or_default(),
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn sinfo_from_fields<const ID: u8>(
+    fields: &Fields,
+) -> Result<Option<SourceInfoType<ID>>, ShellError> {
+    if fields.opt("ext_sinfo").is_none() {
+        return Ok(None);
+    }
+
+    let sinfo = fields.record("ext_sinfo")?;
+    Ok(Some(SourceInfoType {
+        id: EntityGlobalIdProto {
+            zid: sinfo.parse("zid")?,
+            eid: sinfo.int("eid")?,
+        },
+        sn: sinfo.int("sn")?,
+    }))
+}
+
+#[allow(clippy::result_large_err)]
+fn attachment_from_fields<const ID: u8>(
+    fields: &Fields,
+) -> Result<Option<AttachmentType<ID>>, ShellError> {
+    Ok(fields
+        .opt("ext_attachment")
+        .map(|_| fields.bytes("ext_attachment"))
+        .transpose()?
+        .map(|attachment| AttachmentType {
+            buffer: ZBuf::from(attachment),
+        }))
+}
+
+#[allow(clippy::result_large_err)]
+fn encoding_from_fields(fields: &Fields) -> Result<Encoding, ShellError> {
+    Ok(fields
+        .opt("encoding")
+        .map(Value::as_str)
+        .transpose()?
+        .map_or_else(Encoding::empty, |encoding| {
+            zenoh::bytes::Encoding::from(encoding).into()
+        }))
+}
+
+#[allow(clippy::result_large_err)]
+fn consolidation_from_fields(fields: &Fields) -> Result<ConsolidationMode, ShellError> {
+    Ok(match fields.str("consolidation")? {
+        "auto" => ConsolidationMode::Auto,
+        "none" => ConsolidationMode::None,
+        "monotonic" => ConsolidationMode::Monotonic,
+        "latest" => ConsolidationMode::Latest,
+        other => return Err(fields.error(format!("Unknown consolidation mode '{other}'"))),
+    })
+}
+
+/// Returns an error for extensions the decoder only reports the presence of
+#[allow(clippy::result_large_err)]
+fn unsupported_exts_from_fields(fields: &Fields) -> Result<(), ShellError> {
+    if fields.flag("ext_shm")? {
+        return Err(fields.error("Shared-memory payloads can't be encoded".to_string()));
+    }
+    if fields
+        .opt("ext_unknown")
+        .map(Value::as_list)
+        .transpose()?
+        .is_some_and(|exts| !exts.is_empty())
+    {
+        return Err(fields.error("Unknown extensions can't be encoded".to_string()));
+    }
+    Ok(())
+}
+
+fn put(payload: ZBuf) -> Put {
+    Put {
+        timestamp: None,
+        encoding: Encoding::empty(),
+        ext_sinfo: None,
+        ext_attachment: None,
+        ext_shm: None,
+        ext_unknown: vec![],
+        payload,
+    }
+}
+
+/// Returns the `payload` column if any, or `payload_size` zeros otherwise
+///
+/// Since a network message can't be larger than a batch, `payload_size` is bounded by the maximum
+/// batch size.
+#[allow(clippy::result_large_err)]
+fn payload_from_fields(fields: &Fields) -> Result<ZBuf, ShellError> {
+    let payload = match fields.opt("payload") {
+        Some(_) => fields.bytes("payload")?,
+        None => {
+            let size = fields.int::<usize>("payload_size")?;
+            if size > BatchSize::MAX as usize {
+                return Err(fields.error(format!(
+                    "Column 'payload_size' exceeds the maximum batch size ({}): {size}",
+                    BatchSize::MAX
+                )));
+            }
+            vec![0; size]
+        }
+    };
+
+    Ok(ZBuf::from(payload))
+}
+
+#[allow(clippy::result_large_err)]
+fn interest_from_fields(fields: &Fields) -> Result<Interest, ShellError> {
+    let mode = match fields.str("mode")? {
+        "final" => InterestMode::Final,
+        "current" => InterestMode::Current,
+        "future" => InterestMode::Future,
+        "current-future" => InterestMode::CurrentFuture,
+        other => return Err(fields.error(format!("Unknown interest mode '{other}'"))),
+    };
+
+    // NOTE: the restricted option follows from the presence of a wire expression
+    let options = fields.record("options")?;
+    let mut interest_options = InterestOptions::empty();
+    for (name, option) in [
+        ("key_exprs", InterestOptions::KEYEXPRS),
+        ("subscribers", InterestOptions::SUBSCRIBERS),
+        ("queryables", InterestOptions::QUERYABLES),
+        ("tokens", InterestOptions::TOKENS),
+        ("aggregate", InterestOptions::AGGREGATE),
+    ] {
+        if options.flag(name)? {
+            interest_options += option;
+        }
+    }
+
+    Ok(Interest {
+        id: fields.int("id")?,
+        mode,
+        options: interest_options,
+        wire_expr: fields
+            .opt("wire_expr")
+            .map(|_| wire_expr_from_fields(fields, "wire_expr"))
+            .transpose()?,
+        ext_qos: qos_from_fields(fields, interest::ext::QoSType::INTEREST)?,
+        ext_tstamp: tstamp_from_fields(fields)?,
+        ext_nodeid: nodeid_from_fields(fields)?,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn declare_body_from_fields(fields: &Fields) -> Result<DeclareBody, ShellError> {
+    Ok(match fields.str("type")? {
+        "declare-key-expr" => DeclareBody::DeclareKeyExpr(declare::DeclareKeyExpr {
+            id: fields.int("id")?,
+            wire_expr: wire_expr_from_fields(fields, "wire_expr")?,
+        }),
+        "undeclare-key-expr" => DeclareBody::UndeclareKeyExpr(declare::UndeclareKeyExpr {
+            id: fields.int("id")?,
+        }),
+        "declare-subscriber" => DeclareBody::DeclareSubscriber(declare::DeclareSubscriber {
+            id: fields.int("id")?,
+            wire_expr: wire_expr_from_fields(fields, "wire_expr")?,
+        }),
+        "undeclare-subscriber" => DeclareBody::UndeclareSubscriber(declare::UndeclareSubscriber {
+            id: fields.int("id")?,
+            ext_wire_expr: WireExprType::null(),
+        }),
+        "declare-queryable" => DeclareBody::DeclareQueryable(declare::DeclareQueryable {
+            id: fields.int("id")?,
+            wire_expr: wire_expr_from_fields(fields, "wire_expr")?,
+            ext_info: QueryableInfoType {
+                complete: fields.flag("complete")?,
+                distance: fields.int("distance")?,
+            },
+        }),
+        "undeclare-queryable" => DeclareBody::UndeclareQueryable(declare::UndeclareQueryable {
+            id: fields.int("id")?,
+            ext_wire_expr: WireExprType::null(),
+        }),
+        "declare-token" => DeclareBody::DeclareToken(declare::DeclareToken {
+            id: fields.int("id")?,
+            wire_expr: wire_expr_from_fields(fields, "wire_expr")?,
+        }),
+        "undeclare-token" => DeclareBody::UndeclareToken(declare::UndeclareToken {
+            id: fields.int("id")?,
+            ext_wire_expr: WireExprType::null(),
+        }),
+        "declare-final" => DeclareBody::DeclareFinal(declare::DeclareFinal),
+        other => return Err(fields.error(format!("Unknown declaration type '{other}'"))),
+    })
+}
+
+/// Encodes the body of a transport or network OAM message; network OAM linkstates are
+/// re-encoded from their `linkstate` column
+#[allow(clippy::result_large_err)]
+fn oam_body_from_fields(fields: &Fields, network_id: Option<u16>) -> Result<ZExtBody, ShellError> {
+    Ok(match fields.str("ext_body")? {
+        "unit" => ZExtBody::Unit,
+        "z64" => ZExtBody::Z64(fields.int::<i64>("value")? as u64),
+        "zbuf" if network_id == Some(OAM_LINKSTATE) && fields.opt("linkstate").is_some() => {
+            ZExtBody::ZBuf(ZBuf::from(encode_link_state_list(fields)?))
+        }
+        "zbuf" => ZExtBody::ZBuf(ZBuf::from(fields.bytes("payload")?)),
+        other => return Err(fields.error(format!("Unknown extension body '{other}'"))),
+    })
+}
+
+/// Encode a `LinkStateList` to raw OAM payload bytes using `Zenoh080`.
+///
+/// The wire format mirrors zenoh's internal `Zenoh080Routing` codec.
+#[allow(clippy::result_large_err)]
+fn encode_link_state_list(fields: &Fields) -> Result<Vec<u8>, ShellError> {
+    let codec = Zenoh080::new();
+    let mut writer = Vec::new();
+
+    let states = fields.list("linkstate")?;
+    write_field(fields, codec, &mut writer, states.len(), "len")?;
+    for state in states {
+        encode_link_state(&Fields::new(state, fields.span)?, codec, &mut writer)?;
+    }
+
+    Ok(writer)
+}
+
+#[allow(clippy::result_large_err)]
+fn encode_link_state(
+    fields: &Fields,
+    codec: Zenoh080,
+    writer: &mut Vec<u8>,
+) -> Result<(), ShellError> {
+    const LS_PID: u64 = 1; // zid is present
+    const LS_WAI: u64 = 1 << 1; // whatami is present
+    const LS_LOC: u64 = 1 << 2; // locators are present
+    const LS_WGT: u64 = 1 << 3; // link weights are present
+
+    let zid = fields.opt_parse::<zenoh_protocol::core::ZenohIdProto>("zid")?;
+    let whatami = fields.opt_parse::<zenoh_protocol::core::WhatAmI>("whatami")?;
+    let locators = fields
+        .opt("locators")
+        .map(|_| {
+            fields
+                .list("locators")?
+                .iter()
+                .map(|locator| {
+                    let locator = locator.as_str()?;
+                    locator
+                        .parse::<Locator>()
+                        .map_err(|_| fields.error(format!("Invalid locator: '{locator}'")))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+    let links = fields
+        .list("links")?
+        .iter()
+        .map(|link| Ok(link.as_int()? as u64))
+        .collect::<Result<Vec<_>, ShellError>>()?;
+    let link_weights = fields
+        .opt("link_weights")
+        .map(|_| {
+            fields
+                .list("link_weights")?
+                .iter()
+                .map(|weight| {
+                    u16::try_from(weight.as_int()?)
+                        .map_err(|_| fields.error("Link weights must fit in 16 bits".to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    if link_weights
+        .as_ref()
+        .is_some_and(|weights| weights.len() != links.len())
+    {
+        return Err(fields.error("There must be as many link weights as links".to_string()));
+    }
+
+    let mut options = 0;
+    if zid.is_some() {
+        options |= LS_PID;
+    }
+    if whatami.is_some() {
+        options |= LS_WAI;
+    }
+    if locators.is_some() {
+        options |= LS_LOC;
+    }
+    if link_weights.is_some() {
+        options |= LS_WGT;
+    }
+
+    write_field(fields, codec, writer, options, "options")?;
+    write_field(
+        fields,
+        codec,
+        writer,
+        fields.int::<i64>("psid")? as u64,
+        "psid",
+    )?;
+    write_field(fields, codec, writer, fields.int::<i64>("sn")? as u64, "sn")?;
+    if let Some(zid) = &zid {
+        write_field(fields, codec, writer, zid, "zid")?;
+    }
+    if let Some(whatami) = whatami {
+        write_field(fields, codec, writer, u8::from(whatami), "whatami")?;
+    }
+    if let Some(locators) = &locators {
+        write_field(fields, codec, writer, locators.as_slice(), "locators")?;
+    }
+    write_field(fields, codec, writer, links.len(), "links_len")?;
+    for link in links {
+        write_field(fields, codec, writer, link, "link")?;
+    }
+    for weight in link_weights.into_iter().flatten() {
+        write_field(fields, codec, writer, weight, "link_weight")?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::result_large_err)]
+fn write_field<T>(
+    fields: &Fields,
+    codec: Zenoh080,
+    writer: &mut Vec<u8>,
+    value: T,
+    label: &str,
+) -> Result<(), ShellError>
+where
+    Zenoh080: for<'w> WCodec<T, &'w mut Vec<u8>, Output = Result<(), DidntWrite>>,
+{
+    codec.write(writer, value).map_err(|_| {
+        fields.error(format!(
+            "LinkState encode error: could not write field: {label}"
+        ))
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn resolution_from_fields(fields: &Fields) -> Result<Resolution, ShellError> {
+    let mut resolution = Resolution::default();
+    resolution.set(Field::FrameSN, fields.parse::<Bits>("frame_sn")?);
+    resolution.set(Field::RequestID, fields.parse::<Bits>("request_id")?);
+    Ok(resolution)
+}
+
+/// Parses the `Display` representation of a wire expression, i.e. either a plain key expression
+/// or `<scope>:<mapping>:<suffix>`
+#[allow(clippy::result_large_err)]
+fn wire_expr_from_fields(fields: &Fields, name: &str) -> Result<WireExpr<'static>, ShellError> {
+    let wire_expr = fields.str(name)?;
+
+    let mut chunks = wire_expr.splitn(3, ':');
+    if let (Some(scope), Some(mapping), Some(suffix)) =
+        (chunks.next(), chunks.next(), chunks.next())
+    {
+        let mapping = match mapping {
+            "Receiver" => Some(Mapping::Receiver),
+            "Sender" => Some(Mapping::Sender),
+            _ => None,
+        };
+        if let (Ok(scope), Some(mapping)) = (scope.parse(), mapping) {
+            return Ok(WireExpr {
+                scope,
+                suffix: Cow::Owned(suffix.to_string()),
+                mapping,
+            });
+        }
+    }
+
+    Ok(WireExpr {
+        scope: 0,
+        suffix: Cow::Owned(wire_expr.to_string()),
+        mapping: Mapping::DEFAULT,
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn unit_ext<const ID: u8>(fields: &Fields, name: &str) -> Result<Option<ZExtUnit<ID>>, ShellError> {
+    Ok(fields.flag(name)?.then(ZExtUnit::new))
+}
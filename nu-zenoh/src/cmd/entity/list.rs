@@ -0,0 +1,67 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+
+use crate::{signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct List {
+    state: State,
+}
+
+impl List {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for List {
+    fn name(&self) -> &str {
+        "zenoh entity list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        "List entities declared in the background"
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let entities = self.state.entities.read().unwrap();
+
+        let mut ids = entities.keys().copied().collect::<Vec<_>>();
+        ids.sort_unstable();
+
+        let entity_list = ids
+            .into_iter()
+            .map(|id| entities[&id].to_value(id, span))
+            .collect::<Vec<_>>();
+
+        Ok(PipelineData::Value(Value::list(entity_list, span), None))
+    }
+}
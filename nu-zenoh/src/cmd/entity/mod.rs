@@ -0,0 +1,196 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::sync::Arc;
+
+use nu_protocol::{record, CustomValue, IntoValue, ShellError, Span, Value};
+use serde::Serialize;
+use zenoh::{bytes::Encoding, liveliness::LivelinessToken, query::Queryable, Wait};
+
+use crate::{
+    cmd::{
+        pub_::Publisher,
+        shm::ShmPool,
+        storage::Storage,
+        sub::{Subscriber, SubscriberEvent},
+    },
+    conv::PayloadFormat,
+};
+
+pub(crate) mod list;
+pub(crate) mod undeclare;
+
+/// A Zenoh entity declared in the background (i.e. with `--background`)
+pub(crate) struct Entity {
+    pub(crate) keyexpr: String,
+    pub(crate) session: String,
+    pub(crate) handle: EntityHandle,
+}
+
+pub(crate) enum EntityHandle {
+    Subscriber {
+        subscriber: Subscriber,
+        /// Buffered events, drained by `zenoh sub recv`
+        events: flume::Receiver<SubscriberEvent>,
+        format: PayloadFormat,
+    },
+    Queryable(Queryable<()>),
+    Publisher {
+        /// Shared with the `zenoh pub send` commands using it
+        publisher: Arc<Publisher>,
+        /// Encoding used to serialize structured values, see [`crate::conv::value_to_bytes`]
+        encoding: Option<Encoding>,
+        shm: Option<Arc<ShmPool>>,
+    },
+    Storage(Storage),
+    Token(LivelinessToken),
+}
+
+impl Entity {
+    /// Maximum number of samples buffered by a background subscriber
+    pub(crate) const SAMPLE_BUFFER_SIZE: usize = 256;
+
+    pub(crate) fn kind(&self) -> &'static str {
+        match self.handle {
+            EntityHandle::Subscriber { .. } => "subscriber",
+            EntityHandle::Queryable(_) => "queryable",
+            EntityHandle::Publisher { .. } => "publisher",
+            EntityHandle::Storage(_) => "storage",
+            EntityHandle::Token(_) => "token",
+        }
+    }
+
+    pub(crate) fn to_value(&self, id: u64, span: Span) -> Value {
+        record!(
+            "id" => Value::int(id as i64, span),
+            "kind" => self.kind().into_value(span),
+            "keyexpr" => self.keyexpr.clone().into_value(span),
+            "session" => self.session.clone().into_value(span),
+        )
+        .into_value(span)
+    }
+
+    pub(crate) fn undeclare(self) -> zenoh::Result<()> {
+        match self.handle {
+            EntityHandle::Subscriber { subscriber, .. } => subscriber.undeclare(),
+            EntityHandle::Queryable(queryable) => queryable.undeclare().wait(),
+            // NOTE: a publisher still used by `zenoh pub send` is undeclared when it is dropped
+            EntityHandle::Publisher { publisher, .. } => {
+                Arc::into_inner(publisher).map_or(Ok(()), Publisher::undeclare)
+            }
+            EntityHandle::Storage(storage) => storage.undeclare(),
+            EntityHandle::Token(token) => token.undeclare().wait(),
+        }
+    }
+}
+
+/// Handle to an [`Entity`] stored in [`crate::State`]
+#[derive(Debug, Clone)]
+pub(crate) struct EntityValue {
+    pub(crate) id: u64,
+    pub(crate) kind: &'static str,
+    pub(crate) keyexpr: String,
+}
+
+impl EntityValue {
+    pub(crate) fn handle(id: u64, entity: &Entity, span: Span) -> Value {
+        Value::custom(
+            Box::new(EntityValue {
+                id,
+                kind: entity.kind(),
+                keyexpr: entity.keyexpr.clone(),
+            }),
+            span,
+        )
+    }
+
+    /// Returns the entity id of either a handle, its base record or an integer
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn id(value: &Value) -> Result<u64, ShellError> {
+        match value {
+            Value::Custom { val, .. } => val
+                .as_any()
+                .downcast_ref::<EntityValue>()
+                .map(|entity| entity.id)
+                .ok_or_else(|| ShellError::CantConvert {
+                    to_type: "entity".into(),
+                    from_type: val.type_name(),
+                    span: value.span(),
+                    help: None,
+                }),
+            Value::Record { val, .. } => match val.get("id") {
+                Some(id) => Self::id(id),
+                None => Err(ShellError::CantConvert {
+                    to_type: "entity".into(),
+                    from_type: "record without an 'id' column".into(),
+                    span: value.span(),
+                    help: None,
+                }),
+            },
+            _ => u64::try_from(value.as_int()?).map_err(|_| ShellError::CantConvert {
+                to_type: "entity".into(),
+                from_type: "negative integer".into(),
+                span: value.span(),
+                help: None,
+            }),
+        }
+    }
+}
+
+impl CustomValue for EntityValue {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::custom(Box::new(self.clone()), span)
+    }
+
+    fn type_name(&self) -> String {
+        format!("{}#{}", self.kind, self.id)
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        Ok(Value::record(
+            record! {
+                "id" => Value::int(self.id as i64, span),
+                "kind" => self.kind.into_value(span),
+                "keyexpr" => Value::string(self.keyexpr.clone(), span)
+            },
+            span,
+        ))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[doc(hidden)]
+    fn typetag_name(&self) -> &'static str {
+        "EntityValue"
+    }
+
+    #[doc(hidden)]
+    fn typetag_deserialize(&self) {
+        unimplemented!()
+    }
+}
+
+impl Serialize for EntityValue {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        unimplemented!()
+    }
+}
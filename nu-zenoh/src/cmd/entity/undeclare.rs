@@ -0,0 +1,65 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{cmd::entity::EntityValue, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Undeclare {
+    state: State,
+}
+
+impl Undeclare {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Undeclare {
+    fn name(&self) -> &str {
+        "zenoh entity undeclare"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .required("entity", SyntaxShape::Any, "Entity handle or id")
+    }
+
+    fn description(&self) -> &str {
+        "Undeclare an entity declared in the background"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let id = EntityValue::id(&call.req::<Value>(engine_state, stack, 0)?)?;
+
+        self.state.remove_entity(id)?.undeclare().map_err(|e| {
+            nu_protocol::LabeledError::new("Undeclaration failed")
+                .with_label(format!("Could not undeclare entity '{id}': {e}"), call.head)
+        })?;
+
+        Ok(PipelineData::Value(Value::nothing(call.head), None))
+    }
+}
@@ -49,6 +49,7 @@ impl engine::Command for Get {
             .named("encoding", SyntaxShape::String, "Query encoding", None)
             .named("attachment", SyntaxShape::String, "Query attachment", None)
             .allowed_destination()
+            .payload_format()
     }
 
     fn description(&self) -> &str {
@@ -63,12 +64,15 @@ impl engine::Command for Get {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
+        let format = call.payload_format(engine_state, stack)?;
 
         const REPLY_CHANNEL_SIZE: usize = 256;
         let (tx, rx) = flume::bounded(REPLY_CHANNEL_SIZE);
 
+        let session = call.session(engine_state, stack)?;
+
         self.state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
+            .with_session(&session, |sess| {
                 let mut get = sess
                     .get(call.req::<String>(engine_state, stack, 0)?)
                     .callback(move |reply| {
@@ -111,12 +115,11 @@ impl engine::Command for Get {
                     .with_label(format!("Zenoh get failed: {e}"), call.head)
             })?;
 
-        let iter =
-            InterruptibleChannel::new(rx, engine_state.signals().clone()).map(move |reply| {
-                match reply.into_result() {
-                    Ok(sample) => conv::sample_to_record_value(sample, span),
-                    Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
-                }
+        let iter = InterruptibleChannel::new(rx, engine_state.signals().clone())
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |reply| match reply.into_result() {
+                Ok(sample) => conv::sample_to_record_value(sample, &format, span),
+                Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
             });
 
         Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
@@ -11,13 +11,22 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    record, IntoValue, PipelineData, ShellError, Signature, Type, Value,
+    record, IntoValue, ListStream, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use zenoh::{
+    qos::Reliability,
+    sample::SampleKind,
+    session::{Link, LinkEvent, Transport, TransportEvent},
+    Wait,
 };
-use zenoh::Wait;
 
-use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+use crate::{
+    call_ext2::CallExt2, interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt,
+    State,
+};
 
 #[derive(Clone)]
 pub(crate) struct Info {
@@ -89,3 +98,260 @@ impl Command for Info {
         ))
     }
 }
+
+fn transport_to_value(transport: &Transport, span: Span) -> Value {
+    record!(
+        "zid" => transport.zid().to_string().into_value(span),
+        "whatami" => transport.whatami().to_string().into_value(span),
+        "multicast" => transport.is_multicast().into_value(span),
+        "qos" => transport.is_qos().into_value(span),
+        "shm" => transport.is_shm().into_value(span),
+    )
+    .into_value(span)
+}
+
+fn link_to_value(link: &Link, span: Span) -> Value {
+    record!(
+        "zid" => link.zid().to_string().into_value(span),
+        "src" => link.src().to_string().into_value(span),
+        "dst" => link.dst().to_string().into_value(span),
+        "group" => link.group().map(|group| group.to_string()).into_value(span),
+        "mtu" => link.mtu().into_value(span),
+        "streamed" => link.is_streamed().into_value(span),
+        "reliability" => link
+            .reliability()
+            .map(|reliability| match reliability {
+                Reliability::Reliable => "reliable",
+                Reliability::BestEffort => "best_effort",
+            })
+            .into_value(span),
+        "priorities" => link
+            .priorities()
+            .map(|(min, max)| record!(
+                "min" => min.into_value(span),
+                "max" => max.into_value(span),
+            ))
+            .into_value(span),
+        "interfaces" => link.interfaces().to_vec().into_value(span),
+        "auth_identifier" => link.auth_identifier().map(str::to_string).into_value(span),
+    )
+    .into_value(span)
+}
+
+#[derive(Clone)]
+pub(crate) struct Transports {
+    state: State,
+}
+
+impl Transports {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Transports {
+    fn name(&self) -> &str {
+        "zenoh info transports"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        "List the open transports of a session"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns one {zid, whatami, multicast, qos, shm} record per transport. \
+        Whether a transport negotiated low latency or compression isn't exposed by the Zenoh API, \
+        see the 'transport/unicast/lowlatency' and 'transport/unicast/compression/enabled' paths of 'zenoh admin config' instead."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let transports = self
+            .state
+            .with_session(&call.session(engine_state, stack)?, |sess| {
+                sess.info().transports().wait()
+            })?
+            .map(|transport| transport_to_value(&transport, span))
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(transports, span), None))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Links {
+    state: State,
+}
+
+impl Links {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Links {
+    fn name(&self) -> &str {
+        "zenoh info links"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        "List the links of all open transports of a session"
+    }
+
+    fn extra_description(&self) -> &str {
+        "As with 'zenoh info transports', whether low latency or compression were negotiated isn't reported."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let links = self
+            .state
+            .with_session(&call.session(engine_state, stack)?, |sess| {
+                sess.info().links().wait()
+            })?
+            .map(|link| link_to_value(&link, span))
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(links, span), None))
+    }
+}
+
+/// Transport and link lifecycle events
+enum InfoEvent {
+    Transport(TransportEvent),
+    Link(LinkEvent),
+}
+
+#[derive(Clone)]
+pub(crate) struct Events {
+    state: State,
+}
+
+impl Events {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Events {
+    fn name(&self) -> &str {
+        "zenoh info events"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .switch(
+                "history",
+                "Start with events for the transports and links that are already open",
+                None,
+            )
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+    }
+
+    fn description(&self) -> &str {
+        "Returns a stream of transport and link events"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Events are {kind: opened|closed, transport, link} records; \
+        the 'link' column is null for transport events."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const EVENT_CHANNEL_SIZE: usize = 256;
+        let (tx, rx) = flume::bounded(EVENT_CHANNEL_SIZE);
+
+        let span = call.head;
+        let session = call.session(engine_state, stack)?;
+        let history = call.has_flag(engine_state, stack, "history")?;
+
+        let listeners = self
+            .state
+            .with_session(&session, move |sess| -> zenoh::Result<_> {
+                let transport_tx = tx.clone();
+                let transport_listener = sess
+                    .info()
+                    .transport_events_listener()
+                    .history(history)
+                    .callback(move |event| {
+                        let _ = transport_tx.send(InfoEvent::Transport(event));
+                    })
+                    .wait()?;
+
+                let link_listener = sess
+                    .info()
+                    .link_events_listener()
+                    .history(history)
+                    .callback(move |event| {
+                        let _ = tx.send(InfoEvent::Link(event));
+                    })
+                    .wait()?;
+
+                Ok((transport_listener, link_listener))
+            })?
+            .map_err(|e| {
+                nu_protocol::LabeledError::new("Failed to declare events listener")
+                    .with_label(format!("Failed to declare events listener: {e}"), call.head)
+            })?;
+
+        let kind_to_value = move |kind: SampleKind| match kind {
+            SampleKind::Put => "opened".into_value(span),
+            SampleKind::Delete => "closed".into_value(span),
+        };
+
+        let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), listeners)
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |event| match event {
+                InfoEvent::Transport(event) => record!(
+                    "kind" => kind_to_value(event.kind()),
+                    "transport" => transport_to_value(event.transport(), span),
+                    "link" => Value::nothing(span),
+                )
+                .into_value(span),
+                InfoEvent::Link(event) => record!(
+                    "kind" => kind_to_value(event.kind()),
+                    "transport" => Value::nothing(span),
+                    "link" => link_to_value(event.link(), span),
+                )
+                .into_value(span),
+            });
+
+        Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
+    }
+}
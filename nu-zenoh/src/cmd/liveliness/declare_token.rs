@@ -11,20 +11,18 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::{
-    str::FromStr,
-    sync::{Arc, Mutex},
-};
-
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    PipelineData, ShellError, Signature, SyntaxShape, Type,
 };
-use zenoh::{key_expr::OwnedKeyExpr, Wait};
+use zenoh::Wait;
 
 use crate::{
-    call_ext2::CallExt2, cmd::liveliness::LivelinessTokenValue, signature_ext::SignatureExt, State,
+    call_ext2::CallExt2,
+    cmd::entity::{Entity, EntityHandle},
+    signature_ext::SignatureExt,
+    State,
 };
 
 #[derive(Clone)]
@@ -47,7 +45,7 @@ impl Command for DeclareToken {
         Signature::build(self.name())
             .session()
             .zenoh_category()
-            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .input_output_type(Type::Nothing, Type::Any)
             .required("keyexpr", SyntaxShape::String, "key-expression")
             .allowed_origin()
     }
@@ -56,6 +54,10 @@ impl Command for DeclareToken {
         "Declare a liveliness token"
     }
 
+    fn extra_description(&self) -> &str {
+        "The token is kept alive until it's undeclared with 'zenoh liveliness undeclare-token' or 'zenoh entity undeclare'."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -64,10 +66,11 @@ impl Command for DeclareToken {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
 
         let token = self
             .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
+            .with_session(&session, |sess| {
                 sess.liveliness().declare_token(&keyexpr).wait()
             })?
             .map_err(|e| {
@@ -77,14 +80,14 @@ impl Command for DeclareToken {
                 )
             })?;
 
+        let entity = Entity {
+            keyexpr,
+            session,
+            handle: EntityHandle::Token(token),
+        };
+
         Ok(PipelineData::Value(
-            Value::custom(
-                Box::new(LivelinessTokenValue {
-                    handle: Arc::new(Mutex::new(Some(token))),
-                    keyexpr: OwnedKeyExpr::from_str(&keyexpr).unwrap(),
-                }),
-                call.head,
-            ),
+            self.state.insert_entity(entity, call.head),
             None,
         ))
     }
@@ -60,8 +60,10 @@ impl engine::Command for Get {
         const REPLY_CHANNEL_SIZE: usize = 256;
         let (tx, rx) = flume::bounded(REPLY_CHANNEL_SIZE);
 
+        let session = call.session(engine_state, stack)?;
+
         self.state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
+            .with_session(&session, |sess| {
                 let mut get = sess
                     .liveliness()
                     .get(call.req::<String>(engine_state, stack, 0)?)
@@ -82,12 +84,13 @@ impl engine::Command for Get {
                     .with_label(format!("Zenoh liveliness get failed: {e}"), call.head)
             })?;
 
-        let iter =
-            InterruptibleChannel::new(rx, engine_state.signals().clone()).map(move |reply| {
-                match reply.into_result() {
-                    Ok(sample) => conv::sample_to_record_value(sample, span),
-                    Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
+        let iter = InterruptibleChannel::new(rx, engine_state.signals().clone())
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |reply| match reply.into_result() {
+                Ok(sample) => {
+                    conv::sample_to_record_value(sample, &conv::PayloadFormat::Auto, span)
                 }
+                Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
             });
 
         Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
@@ -48,6 +48,7 @@ impl Command for Sub {
             .required("keyexpr", SyntaxShape::String, "key-expression")
             .switch("history", "GET liveliness history", None)
             .allowed_origin()
+            .payload_format()
     }
 
     fn description(&self) -> &str {
@@ -68,10 +69,12 @@ impl Command for Sub {
 
         let keyexpr = call.req::<String>(engine_state, stack, 0)?;
         let history = call.has_flag(engine_state, stack, "history")?;
+        let format = call.payload_format(engine_state, stack)?;
+        let session = call.session(engine_state, stack)?;
 
         let sub = self
             .state
-            .with_session(&call.session(engine_state, stack)?, move |sess| {
+            .with_session(&session, move |sess| {
                 let sub = sess
                     .liveliness()
                     .declare_subscriber(keyexpr)
@@ -91,7 +94,10 @@ impl Command for Sub {
             })?;
 
         let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), sub)
-            .map(move |sample| conv::sample_to_record_value(sample, span));
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |sample| {
+                conv::sample_to_record_value(sample, &format, span)
+            });
 
         Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
     }
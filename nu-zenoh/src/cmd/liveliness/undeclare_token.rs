@@ -16,18 +16,21 @@ use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
     PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
-use zenoh::Wait;
 
-use crate::{cmd::liveliness::LivelinessTokenValue, signature_ext::SignatureExt, State};
+use crate::{
+    cmd::entity::{EntityHandle, EntityValue},
+    signature_ext::SignatureExt,
+    State,
+};
 
 #[derive(Clone)]
 pub(crate) struct UndeclareToken {
-    _state: State,
+    state: State,
 }
 
 impl UndeclareToken {
     pub(crate) fn new(state: State) -> Self {
-        Self { _state: state }
+        Self { state }
     }
 }
 
@@ -40,8 +43,8 @@ impl Command for UndeclareToken {
         Signature::build(self.name())
             .session()
             .zenoh_category()
-            .input_output_type(Type::Nothing, Type::list(Type::record()))
-            .required("token", SyntaxShape::Any, "liveliness token")
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .required("token", SyntaxShape::Any, "Liveliness token handle or id")
             .allowed_origin()
     }
 
@@ -56,21 +59,21 @@ impl Command for UndeclareToken {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let token_value = call.req::<Value>(engine_state, stack, 0)?;
-        let mut custom_value = token_value.into_custom_value()?;
+        let id = EntityValue::id(&call.req::<Value>(engine_state, stack, 0)?)?;
 
-        let mut token_lock = custom_value
-            .as_mut_any()
-            .downcast_mut::<LivelinessTokenValue>()
-            .unwrap()
-            .handle
-            .lock()
-            .unwrap();
+        self.state.with_entity(id, |entity| match entity.handle {
+            EntityHandle::Token(_) => Ok(()),
+            _ => Err(nu_protocol::LabeledError::new("Invalid entity").with_label(
+                format!("Entity '{id}' is a {}, not a token", entity.kind()),
+                call.head,
+            )),
+        })??;
 
-        if let Some(token) = token_lock.take() {
-            token.undeclare().wait().unwrap();
-        }
+        self.state.remove_entity(id)?.undeclare().map_err(|e| {
+            nu_protocol::LabeledError::new("Undeclaration failed")
+                .with_label(format!("Could not undeclare token '{id}': {e}"), call.head)
+        })?;
 
-        Ok(PipelineData::Empty)
+        Ok(PipelineData::Value(Value::nothing(call.head), None))
     }
 }
@@ -11,7 +11,7 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::{fs::File, path::PathBuf};
+use std::{fs::File, path::PathBuf, sync::OnceLock};
 
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
@@ -31,32 +31,43 @@ pub(crate) struct LogPath {
 
 impl LogPath {
     pub(crate) fn new(state: State) -> Self {
-        let log_path = tempfile::tempdir()
-            .unwrap()
-            .keep()
-            .join("zenoh.log")
-            .to_path_buf();
+        Self {
+            _state: state,
+            log_path: Self::init().clone(),
+        }
+    }
 
-        const ENV_FILTER_NAME: &str = "ZENOH_NU_LOG";
-        const ENV_FILTER_DEFAULT: &str = "zenoh=trace";
+    /// Installs the global tracing subscriber on first use and returns the path it writes to
+    ///
+    /// The subscriber is process-wide, hence so is the log path of all contexts (e.g. of a plugin).
+    fn init() -> &'static PathBuf {
+        static LOG_PATH: OnceLock<PathBuf> = OnceLock::new();
 
-        let env_filter = EnvFilter::try_from_env(ENV_FILTER_NAME)
-            .unwrap_or_else(|_| EnvFilter::new(ENV_FILTER_DEFAULT));
+        LOG_PATH.get_or_init(|| {
+            let log_path = tempfile::tempdir()
+                .unwrap()
+                .keep()
+                .join("zenoh.log")
+                .to_path_buf();
 
-        let fmt = tracing_subscriber::fmt::layer()
-            .with_writer(File::create(&log_path).unwrap())
-            .with_ansi(false)
-            .with_span_events(FmtSpan::ACTIVE);
+            const ENV_FILTER_NAME: &str = "ZENOH_NU_LOG";
+            const ENV_FILTER_DEFAULT: &str = "zenoh=trace";
 
-        tracing_subscriber::registry()
-            .with(env_filter)
-            .with(fmt)
-            .init();
+            let env_filter = EnvFilter::try_from_env(ENV_FILTER_NAME)
+                .unwrap_or_else(|_| EnvFilter::new(ENV_FILTER_DEFAULT));
 
-        Self {
-            _state: state,
-            log_path,
-        }
+            let fmt = tracing_subscriber::fmt::layer()
+                .with_writer(File::create(&log_path).unwrap())
+                .with_ansi(false)
+                .with_span_events(FmtSpan::ACTIVE);
+
+            tracing_subscriber::registry()
+                .with(env_filter)
+                .with(fmt)
+                .init();
+
+            log_path
+        })
     }
 }
 
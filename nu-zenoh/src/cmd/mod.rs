@@ -11,20 +11,34 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+pub(crate) mod admin;
 pub(crate) mod config;
 pub(crate) mod decode;
 pub(crate) mod delete;
+pub(crate) mod encode;
+pub(crate) mod entity;
 pub(crate) mod get;
 pub(crate) mod info;
 pub(crate) mod keyexpr;
 pub(crate) mod liveliness;
 pub(crate) mod log_path;
+pub(crate) mod pcap;
+pub(crate) mod perf;
 pub(crate) mod pub_;
 pub(crate) mod put;
 pub(crate) mod querier;
 pub(crate) mod queryable;
+pub(crate) mod record;
+pub(crate) mod router;
 pub(crate) mod runtime;
 pub(crate) mod scout;
+pub(crate) mod serialization;
 pub(crate) mod session;
+pub(crate) mod shm;
+pub(crate) mod sniff;
+pub(crate) mod stats;
+pub(crate) mod storage;
 pub(crate) mod sub;
+pub(crate) mod testnet;
+pub(crate) mod topology;
 pub(crate) mod zid;
@@ -0,0 +1,431 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    time::Duration,
+};
+
+use chrono::DateTime;
+use etherparse::{NetSlice, SlicedPacket, TransportSlice};
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record,
+    shell_error::generic::GenericError,
+    IntoValue, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use pcap_file::{
+    pcap::PcapReader,
+    pcapng::{blocks::interface_description::InterfaceDescriptionOption, Block, PcapNgReader},
+    DataLink,
+};
+use zenoh_codec::{RCodec, Zenoh080};
+use zenoh_protocol::scouting::ScoutingMessage;
+
+use crate::{
+    call_ext2::CallExt2,
+    cmd::decode::{
+        scouting_msg::scouting_message_to_value,
+        transport_batch::{batch_error, decode_batch, split_batches},
+    },
+    signature_ext::SignatureExt,
+};
+
+#[derive(Clone)]
+pub(crate) struct Read;
+
+impl Read {
+    const DEFAULT_PORT: i64 = 7447;
+    const DEFAULT_SCOUTING_PORT: i64 = 7446;
+}
+
+impl Command for Read {
+    fn name(&self) -> &str {
+        "zenoh pcap read"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "Path to a pcap or pcapng file",
+            )
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .named(
+                "ports",
+                SyntaxShape::List(Box::new(SyntaxShape::Int)),
+                "TCP and UDP ports of Zenoh links (defaults to [7447])",
+                Some('p'),
+            )
+            .named(
+                "scouting-port",
+                SyntaxShape::Int,
+                "UDP port of Zenoh scouting (defaults to 7446)",
+                Some('s'),
+            )
+            .switch(
+                "compression",
+                "Batches start with a header byte, as is the case once compression is negotiated",
+                Some('c'),
+            )
+            .switch(
+                "full",
+                "Decode the zenoh message bodies and extensions of network messages",
+                Some('f'),
+            )
+            .zenoh_category()
+    }
+
+    fn description(&self) -> &str {
+        "Read Zenoh messages from a packet capture"
+    }
+
+    fn extra_description(&self) -> &str {
+        "TCP streams are reassembled and split into length-prefixed batches, UDP datagrams are batches of their own \
+        and datagrams on the scouting port are decoded as scouting messages. \
+        Returns one {timestamp, protocol, src, dst, direction, message} record per message, \
+        where 'direction' is 'in' for packets sent to a Zenoh port and 'out' otherwise, \
+        and 'timestamp' is the capture time of the packet which completed the batch. \
+        Messages which could not be decoded are error values."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let path = call.req::<PathBuf>(engine_state, stack, 0)?;
+        let path = call.expand_path(engine_state, stack, &path)?;
+        let ports = call
+            .get_flag::<Vec<i64>>(engine_state, stack, "ports")?
+            .unwrap_or_else(|| vec![Self::DEFAULT_PORT]);
+        let scouting_port = call
+            .get_flag::<i64>(engine_state, stack, "scouting-port")?
+            .unwrap_or(Self::DEFAULT_SCOUTING_PORT);
+        let compression = call.has_flag(engine_state, stack, "compression")?;
+        let full = call.has_flag(engine_state, stack, "full")?;
+
+        let bytes = std::fs::read(&path).map_err(|err| {
+            ShellError::Generic(GenericError::new(
+                "Failed to read capture",
+                format!("Could not read {}: {err}", path.display()),
+                span,
+            ))
+        })?;
+
+        let invalid_port = |port: i64| {
+            ShellError::Generic(GenericError::new(
+                "Invalid port",
+                format!("{port} is not a valid port number"),
+                span,
+            ))
+        };
+        let ports = ports
+            .into_iter()
+            .map(|port| u16::try_from(port).map_err(|_| port))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(invalid_port)?;
+        let scouting_port =
+            u16::try_from(scouting_port).map_err(|_| invalid_port(scouting_port))?;
+
+        let mut dissector = Dissector {
+            ports,
+            scouting_port,
+            compression,
+            full,
+            streams: HashMap::new(),
+            rows: Vec::new(),
+            span,
+        };
+
+        for_each_packet(&bytes, |timestamp, linktype, data| {
+            dissector.packet(timestamp, linktype, data)
+        })
+        .map_err(|msg| {
+            ShellError::Generic(GenericError::new(
+                "Invalid capture",
+                format!("Could not parse {}: {msg}", path.display()),
+                span,
+            ))
+        })?;
+
+        Ok(PipelineData::Value(dissector.rows.into_value(span), None))
+    }
+}
+
+/// Magic number of pcapng files, i.e. the type of their first (section header) block
+const PCAPNG_MAGIC: [u8; 4] = [0x0a, 0x0d, 0x0d, 0x0a];
+
+/// Calls `f` with the capture timestamp, link type and data of each packet of a pcap or pcapng file
+fn for_each_packet(
+    bytes: &[u8],
+    mut f: impl FnMut(Duration, DataLink, &[u8]),
+) -> Result<(), String> {
+    if !bytes.starts_with(&PCAPNG_MAGIC) {
+        let mut reader = PcapReader::new(bytes).map_err(|err| err.to_string())?;
+        let linktype = reader.header().datalink;
+        while let Some(packet) = reader.next_packet() {
+            let packet = packet.map_err(|err| err.to_string())?;
+            f(packet.timestamp, linktype, &packet.data);
+        }
+        return Ok(());
+    }
+
+    // NOTE: blocks borrow the reader, so interfaces are tracked as (link type, resolution) pairs
+    let mut interfaces = Vec::new();
+    let mut reader = PcapNgReader::new(bytes).map_err(|err| err.to_string())?;
+    while let Some(block) = reader.next_block() {
+        match block.map_err(|err| err.to_string())? {
+            Block::SectionHeader(_) => interfaces.clear(),
+            Block::InterfaceDescription(interface) => {
+                let resolution = interface
+                    .options
+                    .iter()
+                    .find_map(|option| match option {
+                        InterfaceDescriptionOption::IfTsResol(resolution) => Some(*resolution),
+                        _ => None,
+                    })
+                    .unwrap_or(6);
+                interfaces.push((interface.linktype, resolution));
+            }
+            Block::EnhancedPacket(packet) => {
+                let &(linktype, resolution) = interfaces
+                    .get(packet.interface_id as usize)
+                    .ok_or_else(|| format!("Unknown interface {}", packet.interface_id))?;
+
+                // NOTE: the timestamp is read as nanoseconds regardless of the interface resolution
+                let units = packet.timestamp.as_nanos();
+                let invalid =
+                    || format!("Invalid timestamp {units} at resolution {resolution:#04x}");
+                let nanos = |nanos: Option<u128>| {
+                    nanos
+                        .and_then(|nanos| u64::try_from(nanos).ok())
+                        .map(Duration::from_nanos)
+                        .ok_or_else(invalid)
+                };
+                let timestamp = match resolution {
+                    r if r & 0x80 != 0 => {
+                        Duration::try_from_secs_f64(units as f64 / 2f64.powi((r & 0x7f) as i32))
+                            .map_err(|_| invalid())?
+                    }
+                    r if r <= 9 => nanos(units.checked_mul(10u128.pow(9 - r as u32)))?,
+                    r => {
+                        let divisor = 10u128.checked_pow(r as u32 - 9).ok_or_else(|| {
+                            format!("Unsupported timestamp resolution: 10^-{r} seconds")
+                        })?;
+                        nanos(Some(units / divisor))?
+                    }
+                };
+
+                f(timestamp, linktype, &packet.data);
+            }
+            Block::SimplePacket(packet) => {
+                let &(linktype, _) = interfaces
+                    .first()
+                    .ok_or_else(|| "Missing interface description".to_string())?;
+                f(Duration::ZERO, linktype, &packet.data);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Reassembly state of one direction of a TCP connection
+#[derive(Default)]
+struct Stream {
+    /// Sequence number of the next expected byte, if known
+    next_seq: Option<u32>,
+    /// Out-of-order segments indexed by sequence number
+    pending: BTreeMap<u32, Vec<u8>>,
+    /// Bytes received in order which do not form a complete batch yet
+    buffer: Vec<u8>,
+}
+
+impl Stream {
+    /// Appends a segment, returning whether new bytes were received in order
+    fn segment(&mut self, seq: u32, payload: &[u8]) -> bool {
+        // NOTE: if the capture started mid-stream, assume it starts at a batch boundary
+        let next_seq = *self.next_seq.get_or_insert(seq);
+
+        // NOTE: sequence numbers wrap around, so compare them through their difference
+        let ahead = seq.wrapping_sub(next_seq) as i32;
+        if ahead > 0 {
+            self.pending.insert(seq, payload.to_vec());
+            return false;
+        }
+
+        // Skip retransmitted bytes
+        let Some(payload) = payload.get(ahead.unsigned_abs() as usize..) else {
+            return false;
+        };
+        self.buffer.extend_from_slice(payload);
+        let mut next_seq = next_seq.wrapping_add(payload.len() as u32);
+
+        while let Some(entry) = self.pending.first_entry() {
+            let ahead = entry.key().wrapping_sub(next_seq) as i32;
+            if ahead > 0 {
+                break;
+            }
+            let segment = entry.remove();
+            if let Some(segment) = segment.get(ahead.unsigned_abs() as usize..) {
+                self.buffer.extend_from_slice(segment);
+                next_seq = next_seq.wrapping_add(segment.len() as u32);
+            }
+        }
+
+        self.next_seq = Some(next_seq);
+        !payload.is_empty()
+    }
+}
+
+struct Dissector {
+    ports: Vec<u16>,
+    scouting_port: u16,
+    compression: bool,
+    full: bool,
+    /// TCP streams indexed by (source, destination) addresses
+    streams: HashMap<(SocketAddr, SocketAddr), Stream>,
+    rows: Vec<Value>,
+    span: Span,
+}
+
+impl Dissector {
+    fn packet(&mut self, timestamp: Duration, linktype: DataLink, data: &[u8]) {
+        let packet = match linktype {
+            DataLink::ETHERNET => SlicedPacket::from_ethernet(data),
+            DataLink::LINUX_SLL => SlicedPacket::from_linux_sll(data),
+            DataLink::RAW | DataLink::IPV4 | DataLink::IPV6 => SlicedPacket::from_ip(data),
+            // NOTE: loopback captures start with a 4-byte address family
+            DataLink::NULL | DataLink::LOOP => {
+                SlicedPacket::from_ip(data.get(4..).unwrap_or_default())
+            }
+            // NOTE: the Linux cooked capture v2 header is 20 bytes long
+            DataLink::LINUX_SLL2 => SlicedPacket::from_ip(data.get(20..).unwrap_or_default()),
+            _ => return,
+        };
+
+        let Ok(packet) = packet else {
+            return;
+        };
+
+        let (src, dst): (IpAddr, IpAddr) = match &packet.net {
+            Some(NetSlice::Ipv4(ip)) => (
+                ip.header().source_addr().into(),
+                ip.header().destination_addr().into(),
+            ),
+            Some(NetSlice::Ipv6(ip)) => (
+                ip.header().source_addr().into(),
+                ip.header().destination_addr().into(),
+            ),
+            _ => return,
+        };
+
+        match &packet.transport {
+            Some(TransportSlice::Udp(udp)) => {
+                let src = SocketAddr::new(src, udp.source_port());
+                let dst = SocketAddr::new(dst, udp.destination_port());
+
+                if dst.port() == self.scouting_port || src.port() == self.scouting_port {
+                    let mut reader = udp.payload();
+                    let message = match Zenoh080::new().read(&mut reader) {
+                        Ok(msg) => scouting_message_to_value(&msg as &ScoutingMessage, self.span),
+                        Err(_) => batch_error("Zenoh080 Codec error".to_string(), self.span),
+                    };
+                    self.row(timestamp, "udp", src, dst, message);
+                } else if self.is_zenoh(src, dst) {
+                    for (_, message) in
+                        decode_batch(udp.payload(), self.compression, self.full, self.span)
+                    {
+                        self.row(timestamp, "udp", src, dst, message);
+                    }
+                }
+            }
+            Some(TransportSlice::Tcp(tcp)) => {
+                let src = SocketAddr::new(src, tcp.source_port());
+                let dst = SocketAddr::new(dst, tcp.destination_port());
+                if !self.is_zenoh(src, dst) {
+                    return;
+                }
+
+                if tcp.syn() || tcp.rst() {
+                    // NOTE: the SYN flag consumes one sequence number
+                    let stream = Stream {
+                        next_seq: tcp.syn().then(|| tcp.sequence_number().wrapping_add(1)),
+                        ..Default::default()
+                    };
+                    self.streams.insert((src, dst), stream);
+                    return;
+                }
+
+                let stream = self.streams.entry((src, dst)).or_default();
+                if !stream.segment(tcp.sequence_number(), tcp.payload()) {
+                    return;
+                }
+
+                for batch in split_batches(&mut stream.buffer) {
+                    for (_, message) in decode_batch(&batch, self.compression, self.full, self.span)
+                    {
+                        self.row(timestamp, "tcp", src, dst, message);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_zenoh(&self, src: SocketAddr, dst: SocketAddr) -> bool {
+        self.ports.contains(&src.port()) || self.ports.contains(&dst.port())
+    }
+
+    fn row(
+        &mut self,
+        timestamp: Duration,
+        protocol: &str,
+        src: SocketAddr,
+        dst: SocketAddr,
+        message: Value,
+    ) {
+        let span = self.span;
+        let direction = if self.ports.contains(&dst.port()) || dst.port() == self.scouting_port {
+            "in"
+        } else {
+            "out"
+        };
+        let timestamp =
+            DateTime::from_timestamp(timestamp.as_secs() as i64, timestamp.subsec_nanos())
+                .unwrap_or_default()
+                .fixed_offset();
+
+        self.rows.push(
+            record!(
+                "timestamp" => Value::date(timestamp, span),
+                "protocol"  => protocol.into_value(span),
+                "src"       => src.to_string().into_value(span),
+                "dst"       => dst.to_string().into_value(span),
+                "direction" => direction.into_value(span),
+                "message"   => message,
+            )
+            .into_value(span),
+        );
+    }
+}
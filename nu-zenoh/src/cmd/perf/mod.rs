@@ -0,0 +1,135 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::Duration;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, EngineState, Stack},
+    LabeledError, Signature, SyntaxShape,
+};
+use zenoh::{
+    bytes::ZBytes,
+    pubsub::Publisher,
+    qos::{CongestionControl, Priority, Reliability},
+    sample::Locality,
+    time::Timestamp,
+    Session, Wait,
+};
+
+use crate::call_ext2::CallExt2;
+
+pub(crate) mod ping;
+pub(crate) mod pong;
+pub(crate) mod pub_thr;
+pub(crate) mod sub_thr;
+
+/// Payload size used by default, as in the zenoh performance examples
+const DEFAULT_PAYLOAD_SIZE: usize = 8;
+
+/// Adds the `--payload-size` flag
+fn payload_size(signature: Signature) -> Signature {
+    signature.named(
+        "payload-size",
+        SyntaxShape::Int,
+        format!("Payload size in bytes (defaults to {DEFAULT_PAYLOAD_SIZE})"),
+        Some('p'),
+    )
+}
+
+/// Returns a payload of `--payload-size` bytes
+fn payload(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<ZBytes, LabeledError> {
+    let size = call
+        .get_flag::<usize>(engine_state, stack, "payload-size")?
+        .unwrap_or(DEFAULT_PAYLOAD_SIZE);
+
+    Ok((0..size).map(|i| (i % 10) as u8).collect::<Vec<_>>().into())
+}
+
+/// Returns the number of messages per second
+fn msgs_per_sec(messages: usize, duration: Duration) -> f64 {
+    messages as f64 / duration.as_secs_f64()
+}
+
+/// Publication options of [`crate::signature_ext::SignatureExt::publication`]
+struct Publication {
+    priority: Option<Priority>,
+    congestion_control: Option<CongestionControl>,
+    reliability: Option<Reliability>,
+    express: Option<bool>,
+    destination: Option<Locality>,
+    attachment: Option<String>,
+    timestamp: Option<Timestamp>,
+}
+
+impl Publication {
+    fn new(
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+    ) -> Result<Self, LabeledError> {
+        Ok(Self {
+            priority: call.priority(engine_state, stack)?,
+            congestion_control: call.congestion_control(engine_state, stack)?,
+            reliability: call.reliable(engine_state, stack)?,
+            express: call.express(engine_state, stack)?,
+            destination: call.allowed_destination(engine_state, stack)?,
+            attachment: call.attachment(engine_state, stack)?,
+            timestamp: call.timestamp(engine_state, stack)?,
+        })
+    }
+
+    fn declare(&self, session: &Session, keyexpr: String) -> zenoh::Result<Publisher<'static>> {
+        let mut publisher = session.declare_publisher(keyexpr);
+
+        if let Some(priority) = self.priority {
+            publisher = publisher.priority(priority);
+        }
+
+        if let Some(congestion_control) = self.congestion_control {
+            publisher = publisher.congestion_control(congestion_control);
+        }
+
+        if let Some(reliability) = self.reliability {
+            publisher = publisher.reliability(reliability);
+        }
+
+        if let Some(express) = self.express {
+            publisher = publisher.express(express);
+        }
+
+        if let Some(destination) = self.destination {
+            publisher = publisher.allowed_destination(destination);
+        }
+
+        publisher.wait()
+    }
+
+    fn put(&self, publisher: &Publisher, payload: ZBytes) -> zenoh::Result<()> {
+        let mut put = publisher.put(payload);
+
+        if let Some(attachment) = &self.attachment {
+            put = put.attachment(attachment.as_bytes());
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            put = put.timestamp(timestamp);
+        }
+
+        put.wait()
+    }
+}
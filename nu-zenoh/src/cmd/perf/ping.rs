@@ -0,0 +1,193 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::{Duration, Instant};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+use zenoh::Wait;
+
+use super::{payload, payload_size, Publication};
+use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Ping {
+    state: State,
+}
+
+impl Ping {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Ping {
+    fn name(&self) -> &str {
+        "zenoh perf ping"
+    }
+
+    fn signature(&self) -> Signature {
+        payload_size(
+            Signature::build(self.name())
+                .session()
+                .zenoh_category()
+                .publication()
+                .input_output_type(Type::Nothing, Type::table()),
+        )
+        .named(
+            "count",
+            SyntaxShape::Int,
+            "Number of measured pings (defaults to 100)",
+            Some('n'),
+        )
+        .named(
+            "warmup",
+            SyntaxShape::Duration,
+            "Duration of the unmeasured warmup (defaults to 1sec)",
+            Some('w'),
+        )
+        .named(
+            "timeout",
+            SyntaxShape::Duration,
+            "Maximum round-trip time of a ping (defaults to 1sec)",
+            None,
+        )
+    }
+
+    fn description(&self) -> &str {
+        "Measure the latency of a 'zenoh perf pong' peer"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Pings are published on '<keyexpr>/ping' and expected back on '<keyexpr>/pong'. \
+        Returns the min, p50, p90, p99, max and mean round-trip times, the latency being half of it."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const DEFAULT_COUNT: usize = 100;
+        const DEFAULT_WARMUP: Duration = Duration::from_secs(1);
+        const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+        let span = call.head;
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let publication = Publication::new(engine_state, stack, call)?;
+        let payload = payload(engine_state, stack, call)?;
+        let count = call
+            .get_flag::<usize>(engine_state, stack, "count")?
+            .unwrap_or(DEFAULT_COUNT);
+        let warmup = call
+            .duration(engine_state, stack, "warmup")?
+            .unwrap_or(DEFAULT_WARMUP);
+        let timeout = call
+            .timeout(engine_state, stack)?
+            .unwrap_or(DEFAULT_TIMEOUT);
+
+        if count == 0 {
+            return Err(LabeledError::new("Invalid count")
+                .with_label("At least one ping must be measured", span)
+                .into());
+        }
+
+        let (tx, rx) = flume::unbounded();
+
+        let (publisher, subscriber) = self
+            .state
+            .with_session(&session, |sess| -> zenoh::Result<_> {
+                let publisher = publication.declare(sess, format!("{keyexpr}/ping"))?;
+                let subscriber = sess
+                    .declare_subscriber(format!("{keyexpr}/pong"))
+                    .callback(move |_| {
+                        let _ = tx.send(());
+                    })
+                    .wait()?;
+
+                Ok((publisher, subscriber))
+            })?
+            .map_err(|e| {
+                LabeledError::new("Ping declaration failed")
+                    .with_label(format!("Zenoh ping failed: {e}"), span)
+            })?;
+
+        let session_signals = self.state.session_signals(&session);
+        let put = || {
+            publication.put(&publisher, payload.clone()).map_err(|e| {
+                LabeledError::new("Put operation failed")
+                    .with_label(format!("Zenoh put failed: {e}"), span)
+            })
+        };
+
+        // NOTE: late pongs are ignored during the warmup and drained before measuring
+        let start = Instant::now();
+        while start.elapsed() < warmup {
+            engine_state.signals().check(&span)?;
+            session_signals.check(span)?;
+            put()?;
+            let _ = rx.recv_timeout(timeout);
+        }
+        rx.drain();
+
+        let mut rtts = Vec::with_capacity(count);
+        for _ in 0..count {
+            engine_state.signals().check(&span)?;
+            session_signals.check(span)?;
+
+            let start = Instant::now();
+            put()?;
+            rx.recv_timeout(timeout).map_err(|_| {
+                LabeledError::new("Ping timed out")
+                    .with_label(format!("No pong received within {timeout:?}"), span)
+                    .with_help("Is 'zenoh perf pong' running on the same key expression?")
+            })?;
+            rtts.push(start.elapsed());
+        }
+
+        subscriber.undeclare().wait().map_err(ShellError::from)?;
+        rtts.sort();
+
+        // NOTE: percentiles use the nearest-rank method
+        let percentile = |p: usize| rtts[(p * rtts.len()).div_ceil(100).max(1) - 1];
+        let mean = rtts.iter().sum::<Duration>() / rtts.len() as u32;
+        let stats = [
+            ("min", rtts[0]),
+            ("p50", percentile(50)),
+            ("p90", percentile(90)),
+            ("p99", percentile(99)),
+            ("max", rtts[rtts.len() - 1]),
+            ("mean", mean),
+        ];
+
+        let values = stats
+            .into_iter()
+            .map(|(stat, rtt)| {
+                record!(
+                    "stat" => stat.into_value(span),
+                    "rtt" => Value::duration(rtt.as_nanos() as i64, span),
+                    "latency" => Value::duration((rtt / 2).as_nanos() as i64, span),
+                )
+                .into_value(span)
+            })
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(values, span), None))
+    }
+}
@@ -0,0 +1,112 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+use zenoh::Wait;
+
+use super::Publication;
+use crate::{
+    call_ext2::CallExt2, interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Pong {
+    state: State,
+}
+
+impl Pong {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Pong {
+    fn name(&self) -> &str {
+        "zenoh perf pong"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .publication()
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "Number of pings to answer (defaults to answering until interrupted)",
+                Some('n'),
+            )
+            .input_output_type(Type::Nothing, Type::record())
+    }
+
+    fn description(&self) -> &str {
+        "Answer the pings of 'zenoh perf ping'"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Payloads received on '<keyexpr>/ping' are published back on '<keyexpr>/pong'. \
+        Returns the number of answered pings once done."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let publication = Publication::new(engine_state, stack, call)?;
+        let count = call.get_flag::<usize>(engine_state, stack, "count")?;
+
+        let (tx, rx) = flume::unbounded();
+
+        let subscriber = self
+            .state
+            .with_session(&session, move |sess| -> zenoh::Result<_> {
+                let publisher = publication.declare(sess, format!("{keyexpr}/pong"))?;
+
+                sess.declare_subscriber(format!("{keyexpr}/ping"))
+                    .callback(move |sample| {
+                        if publication
+                            .put(&publisher, sample.payload().clone())
+                            .is_ok()
+                        {
+                            let _ = tx.send(());
+                        }
+                    })
+                    .wait()
+            })?
+            .map_err(|e| {
+                LabeledError::new("Pong declaration failed")
+                    .with_label(format!("Zenoh pong failed: {e}"), span)
+            })?;
+
+        let pongs = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), subscriber)
+            .with_session(self.state.session_signals(&session))
+            .take(count.unwrap_or(usize::MAX))
+            .count();
+
+        Ok(PipelineData::Value(
+            record!("messages" => Value::int(pongs as i64, span)).into_value(span),
+            None,
+        ))
+    }
+}
@@ -0,0 +1,126 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::Instant;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+use super::{msgs_per_sec, payload, payload_size, Publication};
+use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct PubThr {
+    state: State,
+}
+
+impl PubThr {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for PubThr {
+    fn name(&self) -> &str {
+        "zenoh perf pub-thr"
+    }
+
+    fn signature(&self) -> Signature {
+        payload_size(
+            Signature::build(self.name())
+                .session()
+                .zenoh_category()
+                .publication()
+                .input_output_type(Type::Nothing, Type::record()),
+        )
+        .named(
+            "count",
+            SyntaxShape::Int,
+            "Number of messages to publish",
+            Some('n'),
+        )
+        .named(
+            "duration",
+            SyntaxShape::Duration,
+            "Duration of the publication",
+            Some('d'),
+        )
+    }
+
+    fn description(&self) -> &str {
+        "Publish messages as fast as possible for 'zenoh perf sub-thr'"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Publishes until --count messages were sent, --duration elapsed or the command is interrupted. \
+        Returns the number of published messages and the publication rate."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let publication = Publication::new(engine_state, stack, call)?;
+        let payload = payload(engine_state, stack, call)?;
+        let count = call
+            .get_flag::<usize>(engine_state, stack, "count")?
+            .unwrap_or(usize::MAX);
+        let duration = call.duration(engine_state, stack, "duration")?;
+
+        let publisher = self
+            .state
+            .with_session(&session, |sess| publication.declare(sess, keyexpr))?
+            .map_err(|e| {
+                LabeledError::new("Publisher declaration failed")
+                    .with_label(format!("Zenoh publisher failed: {e}"), span)
+            })?;
+
+        let signals = engine_state.signals();
+        let session_signals = self.state.session_signals(&session);
+
+        let start = Instant::now();
+        let mut messages = 0;
+        while messages < count
+            && duration.is_none_or(|duration| start.elapsed() < duration)
+            && !signals.interrupted()
+        {
+            session_signals.check(span)?;
+            publication.put(&publisher, payload.clone()).map_err(|e| {
+                LabeledError::new("Put operation failed")
+                    .with_label(format!("Zenoh put failed: {e}"), span)
+            })?;
+            messages += 1;
+        }
+        let elapsed = start.elapsed();
+
+        Ok(PipelineData::Value(
+            record!(
+                "messages" => Value::int(messages as i64, span),
+                "payload_size" => Value::int(payload.len() as i64, span),
+                "duration" => Value::duration(elapsed.as_nanos() as i64, span),
+                "msgs_per_sec" => msgs_per_sec(messages, elapsed).into_value(span),
+            )
+            .into_value(span),
+            None,
+        ))
+    }
+}
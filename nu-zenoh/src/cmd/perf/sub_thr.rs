@@ -0,0 +1,170 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, ListStream, PipelineData, ShellError, Signature, SyntaxShape,
+    Type, Value,
+};
+use zenoh::Wait;
+
+use super::msgs_per_sec;
+use crate::{
+    call_ext2::CallExt2, interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt,
+    State,
+};
+
+/// Messages received during a measurement round
+#[derive(Default)]
+struct Round {
+    start: Option<Instant>,
+    messages: usize,
+    bytes: usize,
+}
+
+/// A completed measurement round
+struct RoundStats {
+    messages: usize,
+    bytes: usize,
+    duration: Duration,
+}
+
+#[derive(Clone)]
+pub(crate) struct SubThr {
+    state: State,
+}
+
+impl SubThr {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for SubThr {
+    fn name(&self) -> &str {
+        "zenoh perf sub-thr"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .keyexpr()
+            .allowed_origin()
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "Number of messages per round (defaults to 100000)",
+                Some('n'),
+            )
+            .named(
+                "rounds",
+                SyntaxShape::Int,
+                "Number of measurement rounds (defaults to measuring until interrupted)",
+                Some('r'),
+            )
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        "Measure the throughput of 'zenoh perf pub-thr' publications"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each round starts with the first received message and ends after --count messages. \
+        Returns a stream of rounds with their message rate and bandwidth."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const DEFAULT_COUNT: usize = 100_000;
+
+        let span = call.head;
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let rounds = call.get_flag::<usize>(engine_state, stack, "rounds")?;
+        let count = call
+            .get_flag::<usize>(engine_state, stack, "count")?
+            .unwrap_or(DEFAULT_COUNT);
+
+        if count == 0 {
+            return Err(LabeledError::new("Invalid count")
+                .with_label("Rounds must have at least one message", span)
+                .into());
+        }
+
+        let (tx, rx) = flume::unbounded();
+        let round = Mutex::new(Round::default());
+
+        let subscriber = self
+            .state
+            .with_session(&session, |sess| -> zenoh::Result<_> {
+                let mut subscriber = sess.declare_subscriber(keyexpr).callback(move |sample| {
+                    let mut round = round.lock().unwrap();
+                    let start = *round.start.get_or_insert_with(Instant::now);
+                    round.messages += 1;
+                    round.bytes += sample.payload().len();
+
+                    if round.messages == count {
+                        let _ = tx.send(RoundStats {
+                            messages: round.messages,
+                            bytes: round.bytes,
+                            duration: start.elapsed(),
+                        });
+                        *round = Round::default();
+                    }
+                });
+
+                if let Some(origin) = call.allowed_origin(engine_state, stack)? {
+                    subscriber = subscriber.allowed_origin(origin);
+                }
+
+                subscriber.wait()
+            })?
+            .map_err(|e| {
+                LabeledError::new("Subscriber declaration failed")
+                    .with_label(format!("Zenoh subscriber failed: {e}"), span)
+            })?;
+
+        let mut index = 0;
+        let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), subscriber)
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |stats: RoundStats| {
+                index += 1;
+                let secs = stats.duration.as_secs_f64();
+                record!(
+                    "round" => Value::int(index, span),
+                    "messages" => Value::int(stats.messages as i64, span),
+                    "payload_size" => Value::int((stats.bytes / stats.messages) as i64, span),
+                    "duration" => Value::duration(stats.duration.as_nanos() as i64, span),
+                    "msgs_per_sec" => msgs_per_sec(stats.messages, stats.duration).into_value(span),
+                    "mbps" => (stats.bytes as f64 * 8.0 / secs / 1e6).into_value(span),
+                )
+                .into_value(span)
+            })
+            .take(rounds.unwrap_or(usize::MAX));
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
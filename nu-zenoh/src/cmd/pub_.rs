@@ -11,18 +11,66 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+use std::sync::Arc;
+
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    record, IntoValue, ListStream, PipelineData, ShellError, Signature, Type,
+    record, IntoValue, ListStream, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use zenoh::{
+    bytes::{Encoding, ZBytes},
+    Wait,
 };
-use zenoh::Wait;
+use zenoh_ext::{AdvancedPublisher, AdvancedPublisherBuilderExt, CacheConfig, MissDetectionConfig};
 
 use crate::{
-    call_ext2::CallExt2, interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt,
+    call_ext2::CallExt2,
+    cmd::{
+        entity::{Entity, EntityHandle, EntityValue},
+        shm::ShmPool,
+    },
+    conv,
+    interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt,
     State,
 };
 
+/// A plain or advanced (i.e. zenoh-ext) publisher
+pub(crate) enum Publisher {
+    Plain(zenoh::pubsub::Publisher<'static>),
+    Advanced(AdvancedPublisher<'static>),
+}
+
+impl Publisher {
+    fn put(&self, payload: ZBytes, encoding: Option<Encoding>) -> zenoh::Result<()> {
+        match self {
+            Publisher::Plain(publisher) => {
+                let mut put = publisher.put(payload);
+                if let Some(encoding) = encoding {
+                    put = put.encoding(encoding);
+                }
+                put.wait()
+            }
+            Publisher::Advanced(publisher) => {
+                let mut put = publisher.put(payload);
+                if let Some(encoding) = encoding {
+                    put = put.encoding(encoding);
+                }
+                put.wait()
+            }
+        }
+    }
+
+    pub(crate) fn undeclare(self) -> zenoh::Result<()> {
+        match self {
+            Publisher::Plain(publisher) => publisher.undeclare().wait(),
+            Publisher::Advanced(publisher) => publisher.undeclare().wait(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct Pub {
     state: State,
@@ -50,6 +98,26 @@ impl Command for Pub {
             .express()
             .priority()
             .encoding()
+            .shm()
+            .background()
+            .switch("advanced", "Declare an advanced publisher", None)
+            .named(
+                "cache",
+                SyntaxShape::Int,
+                "Number of samples kept in cache for late joiners and recovery (implies --advanced)",
+                None,
+            )
+            .named(
+                "heartbeat",
+                SyntaxShape::Duration,
+                "Period of heartbeats used for sample miss detection (implies --advanced)",
+                None,
+            )
+            .switch(
+                "publisher-detection",
+                "Make the publisher detectable by advanced subscribers (implies --advanced)",
+                None,
+            )
             .input_output_type(Type::Any, Type::Any)
     }
 
@@ -57,6 +125,11 @@ impl Command for Pub {
         "Declare a publisher"
     }
 
+    fn extra_description(&self) -> &str {
+        "Input values other than strings and binaries are serialized according to the encoding (JSON by default). \
+        With --background, the publisher is kept alive and further values can be published with 'zenoh pub send'."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -65,13 +138,29 @@ impl Command for Pub {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let key = call.req::<String>(engine_state, stack, 0)?;
+        let encoding = call.encoding(engine_state, stack)?;
+        let session = call.session(engine_state, stack)?;
+
+        let shm = if call.has_flag(engine_state, stack, "shm")? {
+            Some(self.state.shm_pool(&session)?)
+        } else {
+            None
+        };
+
+        let cache = call.get_flag::<usize>(engine_state, stack, "cache")?;
+        let heartbeat = call.duration(engine_state, stack, "heartbeat")?;
+        let publisher_detection = call.has_flag(engine_state, stack, "publisher-detection")?;
+        let advanced = cache.is_some()
+            || heartbeat.is_some()
+            || publisher_detection
+            || call.has_flag(engine_state, stack, "advanced")?;
 
         let pub_ = self
             .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
-                let mut pub_ = sess.declare_publisher(key);
+            .with_session(&session, |sess| -> zenoh::Result<_> {
+                let mut pub_ = sess.declare_publisher(key.clone());
 
-                if let Some(encoding) = call.encoding(engine_state, stack)? {
+                if let Some(encoding) = encoding.clone() {
                     pub_ = pub_.encoding(encoding);
                 }
 
@@ -95,21 +184,157 @@ impl Command for Pub {
                     pub_ = pub_.allowed_destination(destination);
                 }
 
-                pub_.wait()
+                if !advanced {
+                    return Ok(Publisher::Plain(pub_.wait()?));
+                }
+
+                let mut pub_ = pub_.advanced();
+
+                if let Some(max_samples) = cache {
+                    pub_ = pub_.cache(CacheConfig::default().max_samples(max_samples));
+                }
+
+                if let Some(heartbeat) = heartbeat {
+                    pub_ = pub_
+                        .sample_miss_detection(MissDetectionConfig::default().heartbeat(heartbeat));
+                }
+
+                if publisher_detection {
+                    pub_ = pub_.publisher_detection();
+                }
+
+                Ok(Publisher::Advanced(pub_.wait()?))
             })?
             .map_err(|e| {
                 nu_protocol::LabeledError::new("Declare publisher operation failed")
                     .with_label(format!("Declare publisher failed: {e}"), call.head)
             })?;
 
-        for value in input {
-            pub_.put(value.as_str()?).wait().unwrap();
+        publish(
+            engine_state,
+            &pub_,
+            input,
+            encoding.clone(),
+            shm.as_deref(),
+            call.head,
+        )?;
+
+        if call.has_flag(engine_state, stack, "background")? {
+            let entity = Entity {
+                keyexpr: key,
+                session,
+                handle: EntityHandle::Publisher {
+                    publisher: Arc::new(pub_),
+                    encoding,
+                    shm,
+                },
+            };
+
+            return Ok(PipelineData::Value(
+                self.state.insert_entity(entity, call.head),
+                None,
+            ));
         }
 
         Ok(nu_protocol::PipelineData::empty())
     }
 }
 
+/// Helper function to publish every input value
+#[allow(clippy::result_large_err)]
+fn publish(
+    engine_state: &EngineState,
+    publisher: &Publisher,
+    input: PipelineData,
+    encoding: Option<Encoding>,
+    shm: Option<&ShmPool>,
+    span: Span,
+) -> Result<(), ShellError> {
+    for value in input {
+        let (mut payload, encoding) =
+            conv::value_to_bytes(engine_state, &value, encoding.clone(), span)?;
+
+        if let Some(shm) = shm {
+            payload = shm.copy(payload).map_err(|e| {
+                nu_protocol::LabeledError::new("Shared-memory allocation failed")
+                    .with_label(format!("Could not allocate payload: {e}"), span)
+            })?;
+        }
+
+        publisher.put(payload, encoding).map_err(|e| {
+            nu_protocol::LabeledError::new("Put operation failed")
+                .with_label(format!("Zenoh put failed: {e}"), span)
+        })?;
+    }
+
+    Ok(())
+}
+
+#[derive(Clone)]
+pub(crate) struct SendValues {
+    state: State,
+}
+
+impl SendValues {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for SendValues {
+    fn name(&self) -> &str {
+        "zenoh pub send"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .required("publisher", SyntaxShape::Any, "Publisher handle or id")
+            .input_output_type(Type::Any, Type::Nothing)
+    }
+
+    fn description(&self) -> &str {
+        "Publish input values with a background publisher"
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let id = EntityValue::id(&call.req::<Value>(engine_state, stack, 0)?)?;
+
+        // NOTE: the publisher is cloned out of the registry so that the entities aren't locked
+        // while the input is consumed
+        let (publisher, encoding, shm) =
+            self.state.with_entity(id, |entity| match &entity.handle {
+                EntityHandle::Publisher {
+                    publisher,
+                    encoding,
+                    shm,
+                } => Ok((publisher.clone(), encoding.clone(), shm.clone())),
+                _ => Err(nu_protocol::LabeledError::new("Invalid entity").with_label(
+                    format!("Entity '{id}' is a {}, not a publisher", entity.kind()),
+                    call.head,
+                )),
+            })??;
+
+        publish(
+            engine_state,
+            &publisher,
+            input,
+            encoding,
+            shm.as_deref(),
+            call.head,
+        )?;
+
+        Ok(PipelineData::Value(Value::nothing(call.head), None))
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct MatchingListener {
     state: State,
@@ -151,29 +376,27 @@ impl Command for MatchingListener {
 
         let span = call.head;
         let key = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
 
         let (pub_, listener) = self
             .state
-            .with_session(
-                &call.session(engine_state, stack)?,
-                move |sess| -> zenoh::Result<_> {
-                    let mut pub_ = sess.declare_publisher(key);
-
-                    if let Some(destination) = call.allowed_destination(engine_state, stack)? {
-                        pub_ = pub_.allowed_destination(destination);
-                    }
-
-                    let pub_ = pub_.wait()?;
-                    let listener = pub_
-                        .matching_listener()
-                        .callback(move |status| {
-                            let _ = tx.send(status);
-                        })
-                        .wait()?;
-
-                    Ok((pub_, listener))
-                },
-            )?
+            .with_session(&session, move |sess| -> zenoh::Result<_> {
+                let mut pub_ = sess.declare_publisher(key);
+
+                if let Some(destination) = call.allowed_destination(engine_state, stack)? {
+                    pub_ = pub_.allowed_destination(destination);
+                }
+
+                let pub_ = pub_.wait()?;
+                let listener = pub_
+                    .matching_listener()
+                    .callback(move |status| {
+                        let _ = tx.send(status);
+                    })
+                    .wait()?;
+
+                Ok((pub_, listener))
+            })?
             .map_err(|e| {
                 nu_protocol::LabeledError::new("Failed to declare publisher matching listener")
                     .with_label(
@@ -184,7 +407,8 @@ impl Command for MatchingListener {
 
         let iter =
             InterruptibleChannel::with_data(rx, engine_state.signals().clone(), (pub_, listener))
-                .map(move |status| {
+                .with_session(self.state.session_signals(&session))
+                .into_values(span, move |status| {
                     record!(
                         "matching" => status.matching().into_value(span),
                     )
@@ -14,11 +14,11 @@
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    PipelineData, ShellError, Signature, SyntaxShape,
+    PipelineData, ShellError, Signature, SyntaxShape, Value,
 };
 use zenoh::Wait;
 
-use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+use crate::{call_ext2::CallExt2, conv, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Put {
@@ -37,16 +37,22 @@ impl Command for Put {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build(self.name())
+        let sig = Signature::build(self.name())
             .session()
             .zenoh_category()
             .publication()
             .encoding()
             .required(
                 "payload",
-                SyntaxShape::OneOf(vec![SyntaxShape::String, SyntaxShape::Binary]),
-                "Publication payload",
-            )
+                SyntaxShape::Any,
+                "Publication payload (values other than strings and binaries are serialized according to the encoding, JSON by default)",
+            );
+
+        if self.state.options.experimental_options {
+            sig.shm()
+        } else {
+            sig
+        }
     }
 
     fn description(&self) -> &str {
@@ -61,13 +67,27 @@ impl Command for Put {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let key = call.req::<String>(engine_state, stack, 0)?;
-        let value = call.req::<Vec<u8>>(engine_state, stack, 1)?;
+        let value = call.req::<Value>(engine_state, stack, 1)?;
+        let session = call.session(engine_state, stack)?;
+        let (mut payload, encoding) = conv::value_to_bytes(
+            engine_state,
+            &value,
+            call.encoding(engine_state, stack)?,
+            call.head,
+        )?;
+
+        if call.has_flag(engine_state, stack, "shm")? {
+            payload = self.state.shm_pool(&session)?.copy(payload).map_err(|e| {
+                nu_protocol::LabeledError::new("Shared-memory allocation failed")
+                    .with_label(format!("Could not allocate payload: {e}"), call.head)
+            })?;
+        }
 
         self.state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
-                let mut put = sess.put(key, value);
+            .with_session(&session, |sess| {
+                let mut put = sess.put(key, payload);
 
-                if let Some(encoding) = call.encoding(engine_state, stack)? {
+                if let Some(encoding) = encoding {
                     put = put.encoding(encoding);
                 }
 
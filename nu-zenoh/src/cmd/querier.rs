@@ -20,11 +20,17 @@ use nu_protocol::{
     record, IntoValue, ListStream, PipelineData, PipelineIterator, ShellError, Signals, Signature,
     Span, SyntaxShape, Type, Value,
 };
-use zenoh::Wait;
+use zenoh::{
+    bytes::{Encoding, ZBytes},
+    Wait,
+};
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
-    signature_ext::SignatureExt, State,
+    call_ext2::CallExt2,
+    conv,
+    interruptible_channel::{InterruptibleChannel, SessionSignals},
+    signature_ext::SignatureExt,
+    State,
 };
 
 #[derive(Clone)]
@@ -55,13 +61,27 @@ impl Command for Querier {
             .target()
             .consolidation()
             .named("timeout", SyntaxShape::Duration, "Query timeout", None)
-            .input_output_type(Type::Any, Type::list(Type::list(Type::record())))
+            .switch(
+                "flat",
+                "Emit replies as they arrive with a 'query_index' column instead of one list per query",
+                None,
+            )
+            .input_output_types(vec![
+                (Type::Any, Type::list(Type::list(Type::record()))),
+                (Type::Any, Type::list(Type::record())),
+            ])
     }
 
     fn description(&self) -> &str {
         "Declare a querier"
     }
 
+    fn extra_description(&self) -> &str {
+        "A query is issued for each input value. Records with a 'parameters', 'payload', 'encoding' or 'attachment' column \
+        describe the query, i.e. {parameters, payload, encoding, attachment} where parameters is a string or a record; \
+        any other value is used as the query payload. Failed queries are returned as error values."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -71,10 +91,11 @@ impl Command for Querier {
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
         let key = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
 
         let querier = self
             .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
+            .with_session(&session, |sess| {
                 let mut querier = sess.declare_querier(key);
 
                 if let Some(priority) = call.priority(engine_state, stack)? {
@@ -113,68 +134,19 @@ impl Command for Querier {
                     .with_label(format!("Declare querier failed: {e}"), call.head)
             })?;
 
-        struct Iter {
-            input: PipelineIterator,
-            querier: zenoh::query::Querier<'static>,
-            receiver: Option<flume::Receiver<zenoh::query::Reply>>,
-            signals: Signals,
-            buffer: Vec<Value>,
-            span: Span,
-        }
-
-        impl Iterator for Iter {
-            type Item = Value;
-
-            fn next(&mut self) -> Option<Self::Item> {
-                loop {
-                    if self.signals.interrupted() {
-                        return None;
-                    }
-
-                    match self.receiver.as_ref().map(|r| {
-                        r.recv_timeout(Duration::from_millis(50))
-                            .map(|r| r.into_result())
-                    }) {
-                        None => {
-                            let input = self.input.next()?;
-                            let Ok(payload) = input.as_str() else {
-                                continue;
-                            };
-                            const QUERY_CHANNEL_SIZE: usize = 256;
-                            let receiver = self
-                                .querier
-                                .get()
-                                .payload(payload)
-                                .with(flume::bounded(QUERY_CHANNEL_SIZE))
-                                .wait()
-                                .unwrap();
-                            self.receiver.replace(receiver);
-                        }
-                        Some(Ok(Ok(sample))) => self
-                            .buffer
-                            .push(conv::sample_to_record_value(sample, self.span)),
-                        Some(Ok(Err(reply_err))) => self
-                            .buffer
-                            .push(conv::reply_error_to_error_value(reply_err, self.span)),
-                        Some(Err(RecvTimeoutError::Timeout)) => continue,
-                        Some(Err(RecvTimeoutError::Disconnected)) => {
-                            let _ = self.receiver.take();
-                            let values = mem::take(&mut self.buffer);
-                            return Some(Value::list(values, self.span));
-                        }
-                    }
-                }
-            }
-        }
-
         let signals = engine_state.signals().clone();
 
         Ok(ListStream::new(
             Iter {
+                engine_state: engine_state.clone(),
                 input: input.into_iter(),
                 querier,
                 receiver: None,
+                query_index: 0,
+                flat: call.has_flag(engine_state, stack, "flat")?,
                 signals: signals.clone(),
+                session: self.state.session_signals(&session),
+                session_closed: false,
                 buffer: Vec::default(),
                 span,
             },
@@ -185,6 +157,207 @@ impl Command for Querier {
     }
 }
 
+/// Replies to the queries issued for each input value
+struct Iter {
+    engine_state: EngineState,
+    input: PipelineIterator,
+    querier: zenoh::query::Querier<'static>,
+    receiver: Option<flume::Receiver<zenoh::query::Reply>>,
+    /// Index of the current query in the input
+    query_index: usize,
+    flat: bool,
+    signals: Signals,
+    session: SessionSignals,
+    session_closed: bool,
+    buffer: Vec<Value>,
+    span: Span,
+}
+
+impl Iter {
+    /// Issues the query described by an input value
+    #[allow(clippy::result_large_err)]
+    fn query(&self, value: &Value) -> Result<flume::Receiver<zenoh::query::Reply>, ShellError> {
+        const QUERY_CHANNEL_SIZE: usize = 256;
+
+        let options = QueryOptions::from_value(&self.engine_state, value, self.span)?;
+        let mut get = self.querier.get();
+
+        if let Some(parameters) = options.parameters {
+            get = get.parameters(parameters);
+        }
+
+        if let Some(payload) = options.payload {
+            get = get.payload(payload);
+        }
+
+        if let Some(encoding) = options.encoding {
+            get = get.encoding(encoding);
+        }
+
+        if let Some(attachment) = options.attachment {
+            get = get.attachment(attachment);
+        }
+
+        get.with(flume::bounded(QUERY_CHANNEL_SIZE))
+            .wait()
+            .map_err(|e| {
+                nu_protocol::LabeledError::new("Query operation failed")
+                    .with_label(format!("Zenoh query failed: {e}"), self.span)
+                    .into()
+            })
+    }
+
+    /// Prepends the `query_index` column to a reply record
+    fn with_query_index(&self, reply: Value) -> Value {
+        match reply {
+            Value::Record { val, .. } => {
+                let mut record =
+                    record!("query_index" => Value::int(self.query_index as i64, self.span));
+                record.extend(val.into_owned());
+                Value::record(record, self.span)
+            }
+            reply => reply,
+        }
+    }
+}
+
+impl Iterator for Iter {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.signals.interrupted() {
+                return None;
+            }
+
+            if self.session.interrupted() {
+                // NOTE: end with a single error value, the querier is no longer usable
+                return (!mem::replace(&mut self.session_closed, true))
+                    .then(|| self.session.error_value(self.span));
+            }
+
+            let Some(receiver) = self.receiver.as_ref() else {
+                let input = self.input.next()?;
+                match self.query(&input) {
+                    Ok(receiver) => {
+                        self.receiver.replace(receiver);
+                        continue;
+                    }
+                    Err(err) => {
+                        let error = Value::error(err, self.span);
+                        self.query_index += 1;
+                        return Some(error);
+                    }
+                }
+            };
+
+            let value = match receiver.recv_timeout(Duration::from_millis(50)) {
+                Ok(reply) => match reply.into_result() {
+                    Ok(sample) => {
+                        conv::sample_to_record_value(sample, &conv::PayloadFormat::Auto, self.span)
+                    }
+                    Err(reply_err) => conv::reply_error_to_error_value(reply_err, self.span),
+                },
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => {
+                    let _ = self.receiver.take();
+                    self.query_index += 1;
+
+                    if self.flat {
+                        continue;
+                    }
+
+                    let values = mem::take(&mut self.buffer);
+                    return Some(Value::list(values, self.span));
+                }
+            };
+
+            if self.flat {
+                return Some(self.with_query_index(value));
+            }
+
+            self.buffer.push(value);
+        }
+    }
+}
+
+/// Query options parsed from an input value of the querier
+#[derive(Default)]
+struct QueryOptions {
+    parameters: Option<String>,
+    payload: Option<ZBytes>,
+    encoding: Option<Encoding>,
+    attachment: Option<ZBytes>,
+}
+
+impl QueryOptions {
+    /// Columns of records describing a query
+    const COLUMNS: [&str; 4] = ["parameters", "payload", "encoding", "attachment"];
+
+    #[allow(clippy::result_large_err)]
+    fn from_value(
+        engine_state: &EngineState,
+        value: &Value,
+        span: Span,
+    ) -> Result<Self, ShellError> {
+        let record = match value {
+            Value::Record { val, .. }
+                if Self::COLUMNS.iter().any(|column| val.contains(column)) =>
+            {
+                val
+            }
+            Value::Nothing { .. } => return Ok(Self::default()),
+            _ => {
+                let (payload, encoding) = conv::value_to_bytes(engine_state, value, None, span)?;
+                return Ok(Self {
+                    payload: Some(payload),
+                    encoding,
+                    ..Self::default()
+                });
+            }
+        };
+
+        // NOTE: null columns are treated as missing so that query records can be re-issued as-is
+        let get = |column: &str| record.get(column).filter(|value| !value.is_nothing());
+
+        let parameters = get("parameters")
+            .map(|parameters| match parameters {
+                Value::Record { val, .. } => val
+                    .iter()
+                    .map(|(key, value)| Ok(format!("{key}={}", value.coerce_str()?)))
+                    .collect::<Result<Vec<_>, ShellError>>()
+                    .map(|parameters| parameters.join(";")),
+                parameters => parameters.coerce_string(),
+            })
+            .transpose()?;
+
+        let encoding = get("encoding")
+            .map(|encoding| encoding.as_str().map(Encoding::from))
+            .transpose()?;
+
+        let (payload, encoding) = match get("payload") {
+            Some(payload) => {
+                let (payload, encoding) =
+                    conv::value_to_bytes(engine_state, payload, encoding, span)?;
+                (Some(payload), encoding)
+            }
+            None => (None, encoding),
+        };
+
+        let attachment = get("attachment")
+            .map(|attachment| conv::value_to_bytes(engine_state, attachment, None, span))
+            .transpose()?
+            .map(|(attachment, _)| attachment);
+
+        Ok(Self {
+            parameters,
+            payload,
+            encoding,
+            attachment,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct MatchingListener {
     state: State,
@@ -227,33 +400,31 @@ impl Command for MatchingListener {
 
         let span = call.head;
         let key = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
 
         let (querier, listener) = self
             .state
-            .with_session(
-                &call.session(engine_state, stack)?,
-                move |sess| -> zenoh::Result<_> {
-                    let mut querier = sess.declare_querier(key);
+            .with_session(&session, move |sess| -> zenoh::Result<_> {
+                let mut querier = sess.declare_querier(key);
 
-                    if let Some(destination) = call.allowed_destination(engine_state, stack)? {
-                        querier = querier.allowed_destination(destination);
-                    }
+                if let Some(destination) = call.allowed_destination(engine_state, stack)? {
+                    querier = querier.allowed_destination(destination);
+                }
 
-                    if let Some(target) = call.target(engine_state, stack)? {
-                        querier = querier.target(target);
-                    }
+                if let Some(target) = call.target(engine_state, stack)? {
+                    querier = querier.target(target);
+                }
 
-                    let querier = querier.wait()?;
-                    let listener = querier
-                        .matching_listener()
-                        .callback(move |status| {
-                            let _ = tx.send(status);
-                        })
-                        .wait()?;
+                let querier = querier.wait()?;
+                let listener = querier
+                    .matching_listener()
+                    .callback(move |status| {
+                        let _ = tx.send(status);
+                    })
+                    .wait()?;
 
-                    Ok((querier, listener))
-                },
-            )?
+                Ok((querier, listener))
+            })?
             .map_err(|e| {
                 nu_protocol::LabeledError::new("Failed to declare querier matching listener")
                     .with_label(
@@ -267,7 +438,8 @@ impl Command for MatchingListener {
             engine_state.signals().clone(),
             (querier, listener),
         )
-        .map(move |status| {
+        .with_session(self.state.session_signals(&session))
+        .into_values(span, move |status| {
             record!(
                 "matching" => status.matching().into_value(span),
             )
@@ -11,16 +11,31 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use nu_engine::{CallExt, ClosureEval};
+use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Closure, Command, EngineState, Stack},
-    ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    shell_error::generic::GenericError,
+    ListStream, PipelineData, Record, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+use zenoh::{
+    bytes::{Encoding, ZBytes},
+    key_expr::KeyExpr,
+    qos::Priority,
+    query::Query,
+    sample::SampleKind,
+    time::Timestamp,
+    Wait,
 };
-use zenoh::Wait;
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
-    signature_ext::SignatureExt, State,
+    call_ext2::CallExt2,
+    closure::Handler,
+    cmd::entity::{Entity, EntityHandle},
+    conv::{self, PayloadFormat},
+    interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt,
+    State,
 };
 
 #[derive(Clone)]
@@ -52,12 +67,21 @@ impl Command for Queryable {
             )
             .complete()
             .allowed_origin()
+            .payload_format()
+            .background()
     }
 
     fn description(&self) -> &str {
         "Declare a queryable"
     }
 
+    fn extra_description(&self) -> &str {
+        "The handler is called with each query and every value it returns is sent as a reply. \
+        Records with a 'payload' or 'kind' column describe the reply, i.e. \
+        {keyexpr, payload, encoding, attachment, timestamp, kind, priority, congestion_control, express}; \
+        any other value is used as the reply payload on the query key expression."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -66,23 +90,27 @@ impl Command for Queryable {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
+        let format = call.payload_format(engine_state, stack)?;
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let background = call.has_flag(engine_state, stack, "background")?;
 
         const REPLY_CHANNEL_SIZE: usize = 256;
         let (tx, rx) = flume::bounded(REPLY_CHANNEL_SIZE);
 
-        let handler = call.req::<Closure>(engine_state, stack, 1)?;
-        let mut closure = ClosureEval::new(engine_state, stack, handler);
+        let handler = call.req::<Spanned<Closure>>(engine_state, stack, 1)?;
+        let mut closure = Handler::new(engine_state, stack, handler);
         let signals = engine_state.signals().clone();
         let engine = engine_state.clone();
 
         let queryable = self
             .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
-                let mut queryable = sess
-                    .declare_queryable(call.req::<String>(engine_state, stack, 0)?)
-                    .callback(move |query| {
-                        let _ = tx.send(query);
-                    });
+            .with_session(&session, |sess| {
+                let mut queryable =
+                    sess.declare_queryable(keyexpr.clone())
+                        .callback(move |query| {
+                            let _ = tx.send(query);
+                        });
 
                 if let Some(origin) = call.allowed_origin(engine_state, stack)? {
                     queryable = queryable.allowed_origin(origin);
@@ -99,33 +127,220 @@ impl Command for Queryable {
                     .with_label(format!("Zenoh queryable failed: {e}"), span)
             })?;
 
-        let iter = InterruptibleChannel::with_data(rx, engine.signals().clone(), queryable).map(
-            move |query| {
-                let value = conv::query_to_record_value(&query, span);
-
-                match closure.run_with_value(value) {
-                    Ok(stream) => {
-                        for value in stream {
-                            let bytes = match conv::value_to_bytes(&value) {
-                                Ok(bytes) => bytes,
-                                Err(err) => return Value::error(err, span),
-                            };
-
-                            if let Err(err) = query.reply(query.key_expr(), bytes).wait() {
-                                return Value::error(ShellError::from(err), span);
-                            }
-                        }
-
-                        Value::nothing(span)
-                    }
-                    Err(err) => match query.reply_err(err.to_string()).wait() {
-                        Ok(()) => Value::nothing(span),
-                        Err(err) => Value::error(ShellError::from(err), span),
-                    },
+        if background {
+            // NOTE: the channel is disconnected once the queryable is undeclared, which ends the
+            // handler thread
+            std::thread::spawn(move || {
+                for query in rx {
+                    let _ = handle_query(&engine, &mut closure, &query, &format, span);
                 }
-            },
-        );
+            });
+
+            let entity = Entity {
+                keyexpr,
+                session,
+                handle: EntityHandle::Queryable(queryable),
+            };
+
+            return Ok(PipelineData::Value(
+                self.state.insert_entity(entity, span),
+                None,
+            ));
+        }
+
+        let iter = InterruptibleChannel::with_data(rx, engine.signals().clone(), queryable)
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |query| {
+                handle_query(&engine, &mut closure, &query, &format, span)
+            });
 
         Ok(ListStream::new(iter, span, signals).into())
     }
 }
+
+/// Helper function to run the handler on a query and send back its replies
+fn handle_query(
+    engine_state: &EngineState,
+    closure: &mut Handler,
+    query: &Query,
+    format: &PayloadFormat,
+    span: Span,
+) -> Value {
+    let value = conv::query_to_record_value(query, format, span);
+
+    match closure.run_with_value(value) {
+        Ok(stream) => {
+            for value in stream {
+                if let Err(err) = reply(engine_state, query, &value, span) {
+                    return Value::error(err, span);
+                }
+            }
+
+            Value::nothing(span)
+        }
+        Err(err) => match query.reply_err(err.to_string()).wait() {
+            Ok(()) => Value::nothing(span),
+            Err(err) => Value::error(ShellError::from(err), span),
+        },
+    }
+}
+
+/// Helper function to reply to a query with a value returned by the handler
+#[allow(clippy::result_large_err)]
+fn reply(
+    engine_state: &EngineState,
+    query: &Query,
+    value: &Value,
+    span: Span,
+) -> Result<(), ShellError> {
+    let record = match value {
+        Value::Record { val, .. } if val.contains("payload") || val.contains("kind") => val,
+        _ => {
+            let (bytes, encoding) = conv::value_to_bytes(engine_state, value, None, span)?;
+            let mut reply = query.reply(query.key_expr(), bytes);
+
+            if let Some(encoding) = encoding {
+                reply = reply.encoding(encoding);
+            }
+
+            return reply.wait().map_err(ShellError::from);
+        }
+    };
+
+    let options = ReplyOptions::from_record(engine_state, record, span)?;
+    let keyexpr = options.keyexpr.unwrap_or_else(|| query.key_expr().clone());
+
+    match options.kind {
+        SampleKind::Put => {
+            let mut reply = query
+                .reply(keyexpr, options.payload)
+                .attachment(options.attachment)
+                .timestamp(options.timestamp);
+
+            if let Some(encoding) = options.encoding {
+                reply = reply.encoding(encoding);
+            }
+
+            if let Some(express) = options.express {
+                reply = reply.express(express);
+            }
+
+            reply.wait().map_err(ShellError::from)
+        }
+        SampleKind::Delete => {
+            let mut reply = query
+                .reply_del(keyexpr)
+                .attachment(options.attachment)
+                .timestamp(options.timestamp);
+
+            if let Some(express) = options.express {
+                reply = reply.express(express);
+            }
+
+            reply.wait().map_err(ShellError::from)
+        }
+    }
+}
+
+/// Reply options parsed from a record returned by the handler
+struct ReplyOptions {
+    kind: SampleKind,
+    keyexpr: Option<KeyExpr<'static>>,
+    payload: ZBytes,
+    encoding: Option<Encoding>,
+    attachment: Option<ZBytes>,
+    timestamp: Option<Timestamp>,
+    express: Option<bool>,
+}
+
+impl ReplyOptions {
+    #[allow(clippy::result_large_err)]
+    fn from_record(
+        engine_state: &EngineState,
+        record: &Record,
+        span: Span,
+    ) -> Result<Self, ShellError> {
+        let invalid = |msg: String| {
+            ShellError::Generic(
+                GenericError::new("Invalid reply", msg, span).with_help(
+                    "Replies are records of {keyexpr, payload, encoding, attachment, timestamp, kind, priority, congestion_control, express}",
+                ),
+            )
+        };
+
+        // NOTE: null columns are treated as missing so that sample records can be replied as-is
+        let get = |column: &str| record.get(column).filter(|value| !value.is_nothing());
+
+        let kind = match get("kind") {
+            None => SampleKind::Put,
+            Some(kind) => match kind.as_str()?.to_lowercase().as_str() {
+                "put" => SampleKind::Put,
+                "delete" => SampleKind::Delete,
+                kind => {
+                    return Err(invalid(format!(
+                        "Kind must be 'put' or 'delete', found '{kind}'"
+                    )))
+                }
+            },
+        };
+
+        let keyexpr = get("keyexpr")
+            .map(|keyexpr| {
+                KeyExpr::try_from(keyexpr.as_str()?.to_string())
+                    .map_err(|err| invalid(format!("Invalid key expression: {err}")))
+            })
+            .transpose()?;
+
+        let encoding = get("encoding")
+            .map(|encoding| encoding.as_str().map(Encoding::from))
+            .transpose()?;
+
+        let (payload, encoding) = match get("payload") {
+            Some(payload) => conv::value_to_bytes(engine_state, payload, encoding, span)?,
+            None => (ZBytes::new(), encoding),
+        };
+
+        let attachment = get("attachment")
+            .map(|attachment| conv::value_to_bytes(engine_state, attachment, None, span))
+            .transpose()?
+            .map(|(attachment, _)| attachment);
+
+        let timestamp = get("timestamp")
+            .map(|timestamp| {
+                Timestamp::parse_rfc3339(timestamp.as_str()?)
+                    .map_err(|err| invalid(format!("Failed to parse RFC3339 timestamp: {err:?}")))
+            })
+            .transpose()?;
+
+        let express = get("express")
+            .map(|express| express.as_bool())
+            .transpose()?;
+
+        // NOTE: replies always use the priority and congestion control of the query; these are
+        // validated but otherwise ignored as Zenoh doesn't allow overriding them
+        if let Some(priority) = get("priority") {
+            u8::try_from(priority.as_int()?)
+                .ok()
+                .and_then(|priority| Priority::try_from(priority).ok())
+                .ok_or_else(|| invalid("Priority must be between 1-7".to_string()))?;
+        }
+
+        let congestion_control = get("congestion_control").map(Value::as_int).transpose()?;
+        if congestion_control.is_some_and(|congestion_control| !matches!(congestion_control, 0 | 1))
+        {
+            return Err(invalid(
+                "Congestion control must be 0 (drop) or 1 (block)".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            kind,
+            keyexpr,
+            payload,
+            encoding,
+            attachment,
+            timestamp,
+            express,
+        })
+    }
+}
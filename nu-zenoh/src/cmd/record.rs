@@ -0,0 +1,559 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    shell_error::generic::GenericError,
+    LabeledError, ListStream, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+    Type, Value,
+};
+use zenoh::{
+    bytes::{Encoding, ZBytes},
+    key_expr::KeyExpr,
+    qos::{CongestionControl, Priority, Reliability},
+    sample::SampleKind,
+    Session, Wait,
+};
+
+use crate::{
+    call_ext2::CallExt2,
+    conv::{self, PayloadFormat},
+    interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt,
+    State,
+};
+
+/// Column of recorded samples holding the time elapsed since the first sample
+const ELAPSED_COLUMN: &str = "elapsed";
+
+#[derive(Clone)]
+pub(crate) struct Record {
+    state: State,
+}
+
+impl Record {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Record {
+    fn name(&self) -> &str {
+        "zenoh record"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .session()
+            .zenoh_category()
+            .keyexpr()
+            .required_named(
+                "output",
+                SyntaxShape::Filepath,
+                "File to which samples are written (truncated if it exists)",
+                Some('o'),
+            )
+            .allowed_origin()
+    }
+
+    fn description(&self) -> &str {
+        "Record the samples of a key expression to a file"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns a stream of the recorded samples, which are written until the stream is dropped. \
+        The file has one JSON sample record per line, as output by 'zenoh sub' \
+        with an additional 'elapsed' duration since the first sample; \
+        binary payloads and attachments are written as lists of bytes. \
+        Use 'zenoh replay' to publish the samples again."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const SUB_CHANNEL_SIZE: usize = 256;
+        let (tx, rx) = flume::bounded(SUB_CHANNEL_SIZE);
+
+        let span = call.head;
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let output = call.get_flag::<Spanned<String>>(engine_state, stack, "output")?;
+        let Some(output) = output else {
+            return Err(ShellError::Generic(GenericError::new(
+                "Missing output file",
+                "--output is required",
+                span,
+            )));
+        };
+
+        let mut file = File::create(call.expand_path(engine_state, stack, output.item.as_ref())?)
+            .map(BufWriter::new)
+            .map_err(|e| {
+                LabeledError::new("Recording failed").with_label(
+                    format!("Could not create '{}': {e}", output.item),
+                    output.span,
+                )
+            })?;
+
+        let sub = self
+            .state
+            .with_session(&session, |sess| {
+                let mut sub = sess.declare_subscriber(keyexpr).callback(move |sample| {
+                    let _ = tx.send((Instant::now(), sample));
+                });
+
+                if let Some(origin) = call.allowed_origin(engine_state, stack)? {
+                    sub = sub.allowed_origin(origin);
+                }
+
+                sub.wait()
+            })?
+            .map_err(|e| {
+                LabeledError::new("Subscriber declaration failed")
+                    .with_label(format!("Zenoh subscriber failed: {e}"), span)
+            })?;
+
+        let engine = engine_state.clone();
+        let mut start = None;
+        let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), sub)
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |(received, sample)| {
+                let start = *start.get_or_insert(received);
+                let mut value = conv::sample_to_record_value(sample, &PayloadFormat::Auto, span);
+                if let Value::Record { val, .. } = &mut value {
+                    let elapsed = received.duration_since(start).as_nanos() as i64;
+                    val.to_mut()
+                        .push(ELAPSED_COLUMN, Value::duration(elapsed, span));
+                }
+
+                match write_line(&engine, &mut file, &value, span) {
+                    Ok(()) => value,
+                    Err(err) => Value::error(err, span),
+                }
+            });
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn write_line(
+    engine_state: &EngineState,
+    file: &mut BufWriter<File>,
+    value: &Value,
+    span: Span,
+) -> Result<(), ShellError> {
+    let json = conv::value_to_json_value(engine_state, value, span, false)?;
+    let line = nu_json::to_string_raw(&json).map_err(|e| {
+        ShellError::Generic(GenericError::new(
+            "Recording failed",
+            format!("Could not serialize sample: {e}"),
+            span,
+        ))
+    })?;
+
+    writeln!(file, "{line}")
+        .and_then(|_| file.flush())
+        .map_err(|e| {
+            ShellError::Generic(GenericError::new(
+                "Recording failed",
+                format!("Could not write sample: {e}"),
+                span,
+            ))
+        })
+}
+
+#[derive(Clone)]
+pub(crate) struct Replay {
+    state: State,
+}
+
+impl Replay {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Replay {
+    fn name(&self) -> &str {
+        "zenoh replay"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .session()
+            .zenoh_category()
+            .required(
+                "file",
+                SyntaxShape::Filepath,
+                "File written by 'zenoh record'",
+            )
+            .named(
+                "speed",
+                SyntaxShape::Number,
+                "Playback speed factor (defaults to 1, i.e. the original timing)",
+                None,
+            )
+            .switch(
+                "loop",
+                "Replay the samples indefinitely, until the stream is dropped",
+                Some('l'),
+            )
+            .named(
+                "remap",
+                SyntaxShape::Record(vec![]),
+                "Record mapping key expression prefixes to their replacement (e.g. {demo: test/demo})",
+                None,
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Publish the samples recorded to a file"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns a stream of the published samples, which are published with the original relative timing \
+        (scaled by --speed), payload, encoding, attachment, kind and QoS. \
+        Timestamps are not replayed. \
+        Prefixes given to --remap only match whole chunks (e.g. 'demo' matches 'demo/a' but not 'demos/a')."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let file = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let looping = call.has_flag(engine_state, stack, "loop")?;
+
+        let speed = call
+            .get_flag::<Spanned<f64>>(engine_state, stack, "speed")?
+            .map(|speed| {
+                if speed.item > 0.0 {
+                    Ok(speed.item)
+                } else {
+                    Err(LabeledError::new("Invalid speed")
+                        .with_label("Speed must be a positive number", speed.span))
+                }
+            })
+            .transpose()?
+            .unwrap_or(1.0);
+
+        let mut remap = Vec::new();
+        if let Some(prefixes) = call.get_flag::<Value>(engine_state, stack, "remap")? {
+            for (from, to) in prefixes.into_record()? {
+                remap.push((from, to.into_string()?));
+            }
+        }
+
+        let mut samples = Vec::new();
+        let path = call.expand_path(engine_state, stack, file.item.as_ref())?;
+        for sample in read_samples(&path, &file)? {
+            let (elapsed, mut value) = sample;
+            let publication = Publication::from_value(engine_state, &mut value, &remap, span)?;
+            samples.push((elapsed.div_f64(speed), publication, value));
+        }
+
+        if samples.is_empty() {
+            return Ok(PipelineData::Value(Value::list(vec![], span), None));
+        }
+
+        // NOTE: samples are sent to the stream with their original timing, which ends once the
+        // stream is dropped
+        let (tx, rx) = flume::bounded(0);
+        thread::spawn(move || loop {
+            let start = Instant::now();
+            for (elapsed, publication, value) in &samples {
+                thread::sleep((start + *elapsed).saturating_duration_since(Instant::now()));
+                if tx.send((publication.clone(), value.clone())).is_err() {
+                    return;
+                }
+            }
+
+            if !looping {
+                return;
+            }
+        });
+
+        let state = self.state.clone();
+        let iter = InterruptibleChannel::new(rx, engine_state.signals().clone())
+            .with_session(self.state.session_signals(&session))
+            .into_values(
+                span,
+                move |(publication, value): (Publication, Value)| match state
+                    .with_session(&session, |sess| publication.publish(sess))
+                {
+                    Ok(Ok(())) => value,
+                    Ok(Err(err)) => Value::error(ShellError::from(err), span),
+                    Err(err) => Value::error(err.into(), span),
+                },
+            );
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+/// Reads the samples of a recording along with their elapsed time
+#[allow(clippy::result_large_err)]
+fn read_samples(path: &Path, file: &Spanned<String>) -> Result<Vec<(Duration, Value)>, ShellError> {
+    let span = file.span;
+    let error = |msg: String| {
+        ShellError::Generic(
+            GenericError::new("Invalid recording", msg, span)
+                .with_help("Recordings are written by 'zenoh record'"),
+        )
+    };
+
+    let reader = File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| error(format!("Could not open '{}': {e}", file.item)))?;
+
+    let mut samples = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| error(format!("Could not read '{}': {e}", file.item)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let json = nu_json::from_str(&line)
+            .map_err(|e| error(format!("Line {} is not valid JSON: {e}", index + 1)))?;
+        let value = conv::nujson_to_value(json, span);
+
+        let elapsed = value
+            .get_data_by_key(ELAPSED_COLUMN)
+            .and_then(|elapsed| elapsed.as_int().ok())
+            .and_then(|elapsed| u64::try_from(elapsed).ok())
+            .ok_or_else(|| {
+                error(format!(
+                    "Line {} has no valid '{ELAPSED_COLUMN}' column",
+                    index + 1
+                ))
+            })?;
+
+        samples.push((Duration::from_nanos(elapsed), value));
+    }
+
+    Ok(samples)
+}
+
+/// A recorded sample to be published
+#[derive(Clone)]
+struct Publication {
+    kind: SampleKind,
+    keyexpr: KeyExpr<'static>,
+    payload: ZBytes,
+    encoding: Option<Encoding>,
+    attachment: Option<ZBytes>,
+    congestion_control: Option<CongestionControl>,
+    priority: Option<Priority>,
+    reliability: Option<Reliability>,
+    express: Option<bool>,
+}
+
+impl Publication {
+    /// Parses a recorded sample, remapping its key expression and restoring its binary columns
+    #[allow(clippy::result_large_err)]
+    fn from_value(
+        engine_state: &EngineState,
+        value: &mut Value,
+        remap: &[(String, String)],
+        span: Span,
+    ) -> Result<Self, ShellError> {
+        let invalid = |msg: String| {
+            ShellError::Generic(
+                GenericError::new("Invalid recorded sample", msg, span)
+                    .with_help("Recordings are written by 'zenoh record'"),
+            )
+        };
+
+        let Value::Record { val, .. } = value else {
+            return Err(invalid(format!(
+                "Expected a record, found {}",
+                value.get_type()
+            )));
+        };
+        let record = val.to_mut();
+
+        // NOTE: binary values are written as lists of bytes, while strings are kept as-is
+        for column in ["payload", "attachment"] {
+            if let Some(Value::List { vals, .. }) = record.get_mut(column) {
+                let bytes = vals
+                    .iter()
+                    .map(|byte| {
+                        u8::try_from(byte.as_int()?)
+                            .map_err(|_| invalid("Binary columns must be lists of bytes".into()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                record.insert(column, Value::binary(bytes, span));
+            }
+        }
+
+        // NOTE: null columns are treated as missing, as in the samples of 'zenoh sub'
+        let get = |column: &str| record.get(column).filter(|value| !value.is_nothing());
+
+        let kind = match get("kind").map(Value::as_str).transpose()? {
+            None | Some("PUT") => SampleKind::Put,
+            Some("DELETE") => SampleKind::Delete,
+            Some(kind) => {
+                return Err(invalid(format!(
+                    "Kind must be 'PUT' or 'DELETE', found '{kind}'"
+                )))
+            }
+        };
+
+        let keyexpr = get("keyexpr")
+            .ok_or_else(|| invalid("Missing 'keyexpr' column".to_string()))?
+            .as_str()?;
+        let keyexpr = remap_keyexpr(keyexpr, remap);
+        let keyexpr = KeyExpr::try_from(keyexpr.clone())
+            .map_err(|err| invalid(format!("Invalid key expression '{keyexpr}': {err}")))?;
+
+        let encoding = get("encoding")
+            .map(|encoding| encoding.as_str().map(Encoding::from))
+            .transpose()?;
+
+        let (payload, encoding) = match get("payload") {
+            Some(payload) => conv::value_to_bytes(engine_state, payload, encoding, span)?,
+            None => (ZBytes::new(), encoding),
+        };
+
+        let attachment = get("attachment")
+            .map(|attachment| conv::value_to_bytes(engine_state, attachment, None, span))
+            .transpose()?
+            .map(|(attachment, _)| attachment);
+
+        let congestion_control = get("congestion_control")
+            .map(|congestion_control| match congestion_control.as_int()? {
+                0 => Ok(CongestionControl::Drop),
+                1 => Ok(CongestionControl::Block),
+                2 => Ok(CongestionControl::BlockFirst),
+                _ => Err(invalid(
+                    "Congestion control must be 0 (drop), 1 (block) or 2 (block first)".into(),
+                )),
+            })
+            .transpose()?;
+
+        let priority = get("priority")
+            .map(|priority| {
+                u8::try_from(priority.as_int()?)
+                    .ok()
+                    .and_then(|priority| Priority::try_from(priority).ok())
+                    .ok_or_else(|| invalid("Priority must be between 1-7".to_string()))
+            })
+            .transpose()?;
+
+        let reliability = get("reliable")
+            .map(|reliable| reliable.as_bool().map(Reliability::from))
+            .transpose()?;
+
+        let express = get("express").map(Value::as_bool).transpose()?;
+
+        let publication = Self {
+            kind,
+            keyexpr,
+            payload,
+            encoding,
+            attachment,
+            congestion_control,
+            priority,
+            reliability,
+            express,
+        };
+
+        record.insert(
+            "keyexpr",
+            Value::string(publication.keyexpr.to_string(), span),
+        );
+
+        Ok(publication)
+    }
+
+    fn publish(&self, session: &Session) -> zenoh::Result<()> {
+        match self.kind {
+            SampleKind::Put => {
+                let mut put = session
+                    .put(&self.keyexpr, self.payload.clone())
+                    .attachment(self.attachment.clone());
+
+                if let Some(encoding) = &self.encoding {
+                    put = put.encoding(encoding.clone());
+                }
+                if let Some(congestion_control) = self.congestion_control {
+                    put = put.congestion_control(congestion_control);
+                }
+                if let Some(priority) = self.priority {
+                    put = put.priority(priority);
+                }
+                if let Some(reliability) = self.reliability {
+                    put = put.reliability(reliability);
+                }
+                if let Some(express) = self.express {
+                    put = put.express(express);
+                }
+
+                put.wait()
+            }
+            SampleKind::Delete => {
+                let mut delete = session
+                    .delete(&self.keyexpr)
+                    .attachment(self.attachment.clone());
+
+                if let Some(congestion_control) = self.congestion_control {
+                    delete = delete.congestion_control(congestion_control);
+                }
+                if let Some(priority) = self.priority {
+                    delete = delete.priority(priority);
+                }
+                if let Some(reliability) = self.reliability {
+                    delete = delete.reliability(reliability);
+                }
+                if let Some(express) = self.express {
+                    delete = delete.express(express);
+                }
+
+                delete.wait()
+            }
+        }
+    }
+}
+
+/// Replaces the first prefix of `remap` matching whole chunks of `keyexpr`
+fn remap_keyexpr(keyexpr: &str, remap: &[(String, String)]) -> String {
+    for (from, to) in remap {
+        let rest = keyexpr.strip_prefix(from.as_str());
+        if let Some(rest) = rest.filter(|rest| rest.is_empty() || rest.starts_with('/')) {
+            return format!("{to}{rest}");
+        }
+    }
+
+    keyexpr.to_string()
+}
@@ -0,0 +1,2 @@
+pub(crate) mod plugins;
+pub(crate) mod start;
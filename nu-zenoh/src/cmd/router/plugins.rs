@@ -0,0 +1,93 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Plugins {
+    state: State,
+}
+
+impl Plugins {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Plugins {
+    fn name(&self) -> &str {
+        "zenoh router plugins"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("router", SyntaxShape::String, "Router (i.e. runtime) name")
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+    }
+
+    fn description(&self) -> &str {
+        "List the plugins of a router"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Plugins are 'declared', 'loaded' or 'started'; \
+        the 'level' and 'messages' columns report why a plugin didn't get further."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let router_name = call.req::<String>(engine_state, stack, 0)?;
+        let runtime = self
+            .state
+            .runtimes
+            .read()
+            .unwrap()
+            .get(&router_name)
+            .ok_or_else(|| LabeledError::new(format!("router '{router_name}' was not found")))?
+            .clone();
+
+        let plugins = runtime
+            .plugins_manager()
+            .declared_plugins_iter()
+            .map(|plugin| {
+                let report = plugin.report();
+                record!(
+                    "id"       => plugin.id().into_value(span),
+                    "name"     => plugin.name().into_value(span),
+                    "state"    => format!("{:?}", plugin.state()).to_lowercase().into_value(span),
+                    "version"  => plugin.version().map(str::to_string).into_value(span),
+                    "path"     => plugin.path().into_value(span),
+                    "level"    => format!("{:?}", report.get_level()).to_lowercase().into_value(span),
+                    "messages" => report.messages().iter().map(|msg| msg.to_string()).collect::<Vec<_>>().into_value(span),
+                )
+                .into_value(span)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(PipelineData::Value(Value::list(plugins, span), None))
+    }
+}
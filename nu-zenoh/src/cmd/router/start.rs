@@ -0,0 +1,221 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    shell_error::generic::GenericError,
+    LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+use zenoh::{
+    config::WhatAmI,
+    internal::{
+        plugins::{PluginsManager, PLUGIN_PREFIX},
+        runtime::{RuntimeBuilder, ZRuntime},
+    },
+    Wait,
+};
+use zenoh_plugin_rest::RestPlugin;
+use zenoh_plugin_storage_manager::StoragesPlugin;
+use zenoh_plugin_trait::Plugin;
+
+use crate::{call_ext2::CallExt2, conv, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Start {
+    state: State,
+}
+
+impl Start {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Start {
+    fn name(&self) -> &str {
+        "zenoh router start"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("router", SyntaxShape::String, "Router (i.e. runtime) name")
+            .zenoh_category()
+            .config()
+            .named(
+                "config-file",
+                SyntaxShape::Filepath,
+                "Path to a Zenoh configuration file",
+                None,
+            )
+            .input_output_type(Type::Nothing, Type::Nothing)
+    }
+
+    fn description(&self) -> &str {
+        "Start or re-start a router along with its plugins"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The router runs in 'router' mode unless the configuration sets another 'mode'. \
+        It is registered as a runtime of the same name, which can be shared by sessions \
+        (see 'zenoh session open --runtime') and closed with 'zenoh runtime close'. \
+        Plugins are loaded from the 'plugins' configuration as zenohd does: \
+        'rest' and 'storage_manager' are linked statically, \
+        other plugins are loaded from the shared libraries in 'plugins_loading/search_dirs'."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let router_name = call.req::<String>(engine_state, stack, 0)?;
+        let file_path = call
+            .get_flag::<PathBuf>(engine_state, stack, "config-file")?
+            .map(|path| call.expand_path(engine_state, stack, &path))
+            .transpose()?;
+        let config_record = call.opt::<Value>(engine_state, stack, 1)?;
+
+        let mut config = match (file_path, config_record) {
+            (Some(file_path), None) => zenoh::Config::from_file(&file_path).map_err(|e| {
+                LabeledError::new("Failed to load config file").with_label(
+                    format!("Could not read config from {}: {}", file_path.display(), e),
+                    span,
+                )
+            })?,
+            (None, Some(val @ Value::Record { .. })) => {
+                let json_value = conv::value_to_json_value(engine_state, &val, span, false)?;
+                zenoh::Config::from_json5(&json_value.to_string()).map_err(|e| {
+                    LabeledError::new("Failed to parse config record")
+                        .with_label(format!("Could not parse config record: {e}"), span)
+                })?
+            }
+            (None, Some(_)) => {
+                return Err(ShellError::Generic(
+                    GenericError::new("Invalid config type", "Config must be a record", span)
+                        .with_help("Provide a record with Zenoh configuration options"),
+                ));
+            }
+            (Some(_), Some(_)) => {
+                return Err(ShellError::Generic(
+                    GenericError::new(
+                        "Conflicting arguments",
+                        "Cannot specify both --config-file and config record",
+                        span,
+                    )
+                    .with_help(
+                        "Use either --config-file <path> or provide a config record, not both",
+                    ),
+                ));
+            }
+            (None, None) => zenoh::Config::default(),
+        };
+
+        if config.mode().is_none() {
+            config
+                .insert_json5("mode", &format!("\"{}\"", WhatAmI::Router))
+                .map_err(|e| {
+                    LabeledError::new("Failed to configure router")
+                        .with_label(format!("Could not set router mode: {e}"), span)
+                })?;
+        }
+
+        let plugins = load_plugins(&config).map_err(|e| {
+            LabeledError::new("Failed to load plugins")
+                .with_label(format!("Could not load required plugin: {e}"), span)
+        })?;
+
+        let mut runtimes = self.state.runtimes.write().unwrap();
+        if let Some(runtime) = runtimes.remove(&router_name) {
+            runtime.close().wait().map_err(|e| {
+                LabeledError::new(format!("Failed to re-start Zenoh router '{router_name}'"))
+                    .with_label(format!("Could not close Zenoh runtime: {e}"), span)
+            })?
+        }
+
+        // NOTE: Zenoh panics when a required plugin fails to start
+        let new_runtime = panic::catch_unwind(AssertUnwindSafe(|| {
+            ZRuntime::Application
+                .block_on(RuntimeBuilder::new(config).plugins_manager(plugins).build())
+        }))
+        .map_err(|_| {
+            LabeledError::new("Failed to start Zenoh router")
+                .with_label("A required plugin failed to start", span)
+        })?;
+
+        let mut new_runtime = new_runtime.map_err(|e| {
+            LabeledError::new("Failed to start Zenoh router")
+                .with_label(format!("Could not open Zenoh runtime: {e}"), span)
+        })?;
+
+        ZRuntime::Application
+            .block_on(new_runtime.start())
+            .map_err(|e| {
+                LabeledError::new("Failed to start Zenoh router")
+                    .with_label(format!("Could not start Zenoh runtime: {e}"), span)
+            })?;
+
+        runtimes.insert(router_name, new_runtime);
+
+        Ok(PipelineData::Value(Value::nothing(span), None))
+    }
+}
+
+/// Declares and loads the plugins of the configuration
+///
+/// Plugins which failed to load remain declared (along with the failure report), unless they are
+/// required, in which case an error is returned.
+fn load_plugins(config: &zenoh::Config) -> zenoh::Result<PluginsManager> {
+    let mut manager = PluginsManager::dynamic(config.libloader(), PLUGIN_PREFIX.to_string());
+
+    for request in config.plugins().load_requests() {
+        let (id, name, required) = (request.id, request.name, request.required);
+        let declared = match (name.as_str(), &request.paths) {
+            (RestPlugin::DEFAULT_NAME, None) => {
+                manager.declare_static_plugin::<RestPlugin, _>(id.clone(), required);
+                manager.plugin_mut(&id).unwrap()
+            }
+            (StoragesPlugin::DEFAULT_NAME, None) => {
+                manager.declare_static_plugin::<StoragesPlugin, _>(id.clone(), required);
+                manager.plugin_mut(&id).unwrap()
+            }
+            (_, Some(paths)) => {
+                manager.declare_dynamic_plugin_by_paths(name, id.clone(), paths, required)?
+            }
+            (_, None) => manager.declare_dynamic_plugin_by_name(id.clone(), name, required)?,
+        };
+
+        match declared.load() {
+            Ok(Some(_)) => {}
+            Ok(None) if required => {
+                return Err(format!(
+                    "Plugin '{id}' can't be loaded as plugin loading is disabled (see 'plugins_loading/enabled')"
+                )
+                .into())
+            }
+            Err(e) if required => return Err(e),
+            Ok(None) | Err(_) => {}
+        }
+    }
+
+    Ok(manager)
+}
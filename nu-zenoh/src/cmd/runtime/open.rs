@@ -24,7 +24,7 @@ use zenoh::{
     Wait,
 };
 
-use crate::{conv, signature_ext::SignatureExt, State};
+use crate::{call_ext2::CallExt2, conv, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Open {
@@ -77,7 +77,10 @@ impl Command for Open {
     ) -> Result<PipelineData, ShellError> {
         // FIXME(fuzzypixelz): refactor this (see 'zenoh session open')
 
-        let file_path = call.get_flag::<PathBuf>(engine_state, stack, "file")?;
+        let file_path = call
+            .get_flag::<PathBuf>(engine_state, stack, "file")?
+            .map(|path| call.expand_path(engine_state, stack, &path))
+            .transpose()?;
         let config_record = call.opt::<Value>(engine_state, stack, 1)?;
 
         let config = match (file_path.as_ref(), config_record.as_ref()) {
@@ -12,20 +12,35 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
-use std::time::Instant;
+use std::{
+    any::Any,
+    io::ErrorKind,
+    net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
+use chrono::Local;
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
     record,
     shell_error::generic::GenericError,
-    IntoValue, ListStream, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    IntoValue, ListStream, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
 };
+use socket2::{Domain, Socket, Type as SocketType};
 use zenoh::{config::WhatAmIMatcher, scouting::Hello, Config, Wait};
+use zenoh_codec::{RCodec, WCodec, Zenoh080};
+use zenoh_protocol::scouting::{Scout as ScoutProto, ScoutingBody, ScoutingMessage};
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
-    signature_ext::SignatureExt, State,
+    call_ext2::CallExt2, cmd::decode::scouting_msg::scouting_message_to_value, conv,
+    interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt, State,
 };
 
 #[derive(Clone)]
@@ -45,9 +60,19 @@ impl Command for Scout {
     }
 
     fn signature(&self) -> Signature {
-        // FIXME: add a config option?
         Signature::build(self.name())
             .named("timeout", SyntaxShape::Duration, "Scouting timeout", None)
+            .named(
+                "what",
+                SyntaxShape::String,
+                "Kinds of nodes to scout for, e.g. 'router' or 'router|peer' (defaults to all)",
+                Some('w'),
+            )
+            .switch(
+                "raw",
+                "Capture the scouting messages on the multicast group instead, along with their sender",
+                Some('r'),
+            )
             .zenoh_category()
             .config()
             .input_output_type(Type::Nothing, Type::list(Type::record()))
@@ -57,6 +82,12 @@ impl Command for Scout {
         "Scout the Zenoh network"
     }
 
+    fn extra_description(&self) -> &str {
+        "With --raw, scouting messages sent to the multicast group (see 'scouting/multicast/address') \
+        and Hello replies to the Scout messages sent by this command are returned as \
+        {timestamp, src, message} records, where 'message' is decoded as by 'zenoh decode scouting-msg'."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -88,15 +119,38 @@ impl Command for Scout {
             None => Config::default(),
         };
 
-        let scout = zenoh::scout(WhatAmIMatcher::empty().client().peer().router(), config)
-            .callback(move |scout| {
-                let _ = tx.send(scout);
-            })
-            .wait()
-            .map_err(|e| {
+        let what = match call.get_flag::<Spanned<String>>(engine_state, stack, "what")? {
+            Some(what) => what.item.parse::<WhatAmIMatcher>().map_err(|_| {
+                ShellError::Generic(
+                    GenericError::new(
+                        "Invalid scouting target",
+                        format!("Could not parse '{}'", what.item),
+                        what.span,
+                    )
+                    .with_help("Use 'router', 'peer' or 'client', separated by '|'"),
+                )
+            })?,
+            None => WhatAmIMatcher::empty().client().peer().router(),
+        };
+
+        let guard: Box<dyn Any + Send> = if call.has_flag(engine_state, stack, "raw")? {
+            Box::new(RawScout::start(&config, what, tx, span).map_err(|e| {
                 nu_protocol::LabeledError::new("Scout operation failed")
-                    .with_label(format!("Zenoh scout failed: {e}"), call.head)
-            })?;
+                    .with_label(format!("Could not capture scouting messages: {e}"), span)
+            })?)
+        } else {
+            Box::new(
+                zenoh::scout(what, config)
+                    .callback(move |hello| {
+                        let _ = tx.send(hello_to_value(&hello, span));
+                    })
+                    .wait()
+                    .map_err(|e| {
+                        nu_protocol::LabeledError::new("Scout operation failed")
+                            .with_label(format!("Zenoh scout failed: {e}"), call.head)
+                    })?,
+            )
+        };
 
         fn hello_to_value(hello: &Hello, span: Span) -> Value {
             record!(
@@ -111,16 +165,158 @@ impl Command for Scout {
             let deadline = Instant::now() + timeout;
             let mut values = Vec::new();
 
-            while let Ok(hello) = rx.recv_deadline(deadline) {
-                values.push(hello_to_value(&hello, span))
+            while let Ok(value) = rx.recv_deadline(deadline) {
+                values.push(value)
             }
 
+            drop(guard);
             Ok(PipelineData::Value(Value::list(values, span), None))
         } else {
-            let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), scout)
-                .map(move |hello| hello_to_value(&hello, span));
+            let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), guard);
 
             Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
         }
     }
 }
+
+/// Capture of scouting messages, stopped once dropped
+///
+/// Messages are read from the multicast group and from the unicast socket that periodically
+/// sends Scout messages to it, which receives the Hello replies.
+struct RawScout {
+    stopped: Arc<AtomicBool>,
+}
+
+impl RawScout {
+    const READ_TIMEOUT: Duration = Duration::from_millis(50);
+    const SCOUT_INTERVAL: Duration = Duration::from_secs(1);
+
+    fn start(
+        config: &Config,
+        what: WhatAmIMatcher,
+        tx: flume::Sender<Value>,
+        span: Span,
+    ) -> std::io::Result<Self> {
+        let group = config
+            .get_json("scouting/multicast/address")
+            .ok()
+            .and_then(|addr| addr.trim_matches('"').parse::<SocketAddr>().ok())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    ErrorKind::InvalidInput,
+                    "invalid 'scouting/multicast/address'",
+                )
+            })?;
+
+        let multicast = Socket::new(Domain::for_address(group), SocketType::DGRAM, None)?;
+        multicast.set_reuse_address(true)?;
+        multicast.bind(&group.into())?;
+        match group.ip() {
+            IpAddr::V4(ip) => multicast.join_multicast_v4(&ip, &Ipv4Addr::UNSPECIFIED)?,
+            IpAddr::V6(ip) => multicast.join_multicast_v6(&ip, 0)?,
+        }
+        let multicast = UdpSocket::from(multicast);
+
+        let unspecified = match group {
+            SocketAddr::V4(_) => SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+            SocketAddr::V6(_) => SocketAddr::from((std::net::Ipv6Addr::UNSPECIFIED, 0)),
+        };
+        let unicast = UdpSocket::bind(unspecified)?;
+
+        let scout = ScoutingMessage {
+            body: ScoutingBody::Scout(ScoutProto {
+                version: zenoh_protocol::VERSION,
+                what,
+                zid: None,
+            }),
+        };
+        let mut bytes = Vec::new();
+        Zenoh080::new()
+            .write(&mut bytes, &scout)
+            .map_err(|_| std::io::Error::other("could not encode Scout message"))?;
+
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        for socket in [multicast, unicast.try_clone()?] {
+            socket.set_read_timeout(Some(Self::READ_TIMEOUT))?;
+            let (stopped, tx) = (stopped.clone(), tx.clone());
+            thread::spawn(move || Self::capture(socket, what, &stopped, &tx, span));
+        }
+
+        {
+            let stopped = stopped.clone();
+            thread::spawn(move || {
+                while !stopped.load(Ordering::Relaxed) {
+                    if let Err(e) = unicast.send_to(&bytes, group) {
+                        let _ = tx.send(scout_error(
+                            format!("Could not send Scout message to {group}: {e}"),
+                            span,
+                        ));
+                        return;
+                    }
+                    thread::sleep(Self::SCOUT_INTERVAL);
+                }
+            });
+        }
+
+        Ok(Self { stopped })
+    }
+
+    /// Sends the scouting messages received on the socket, skipping Hello messages of nodes
+    /// that don't match `what`
+    fn capture(
+        socket: UdpSocket,
+        what: WhatAmIMatcher,
+        stopped: &AtomicBool,
+        tx: &flume::Sender<Value>,
+        span: Span,
+    ) {
+        let mut buffer = vec![0; u16::MAX as usize];
+        while !stopped.load(Ordering::Relaxed) {
+            let (len, src) = match socket.recv_from(&mut buffer) {
+                Ok(received) => received,
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    continue
+                }
+                Err(e) => {
+                    let _ = tx.send(scout_error(
+                        format!("Could not receive scouting messages: {e}"),
+                        span,
+                    ));
+                    return;
+                }
+            };
+
+            let mut reader = &buffer[..len];
+            let message = match RCodec::<ScoutingMessage, _>::read(Zenoh080::new(), &mut reader) {
+                Ok(msg) => match &msg.body {
+                    ScoutingBody::Hello(hello) if !what.matches(hello.whatami) => continue,
+                    _ => scouting_message_to_value(&msg, span),
+                },
+                Err(_) => scout_error("Zenoh080 Codec error".to_string(), span),
+            };
+
+            let _ = tx.send(
+                record!(
+                    "timestamp" => Value::date(Local::now().fixed_offset(), span),
+                    "src"       => src.to_string().into_value(span),
+                    "message"   => message,
+                )
+                .into_value(span),
+            );
+        }
+    }
+}
+
+impl Drop for RawScout {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+fn scout_error(msg: String, span: Span) -> Value {
+    Value::error(
+        ShellError::Generic(GenericError::new("Scouting error", msg, span)),
+        span,
+    )
+}
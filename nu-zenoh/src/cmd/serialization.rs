@@ -0,0 +1,136 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    shell_error::generic::GenericError,
+    LabeledError, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
+};
+use zenoh::bytes::ZBytes;
+
+use crate::{serialization, serialization::Schema, signature_ext::SignatureExt};
+
+/// Helper function to get the schema positional argument
+#[allow(clippy::result_large_err)]
+fn schema(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Schema, ShellError> {
+    let schema = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+    Schema::parse(&schema.item).map_err(|err| {
+        LabeledError::new("Invalid schema")
+            .with_label(err, schema.span)
+            .into()
+    })
+}
+
+#[derive(Clone)]
+pub(crate) struct Serialize;
+
+impl Command for Serialize {
+    fn name(&self) -> &str {
+        "zenoh serialize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "schema",
+                SyntaxShape::String,
+                "Type schema (e.g. '(u32, string, list<f64>)')",
+            )
+            .input_output_type(Type::Any, Type::Binary)
+            .zenoh_category()
+    }
+
+    fn description(&self) -> &str {
+        "Serialize a value in the zenoh-ext serialization format"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Supported types are bool, u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, string, bytes, \
+        list<T>, map<K, V> (from records) and tuples (from lists), e.g. '(u32, string, list<f64>)'."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let schema = schema(engine_state, stack, call)?;
+        let value = input.into_value(call.head)?;
+        let bytes = serialization::serialize(&value, &schema, call.head)?;
+
+        Ok(PipelineData::Value(
+            Value::binary(bytes.to_bytes(), call.head),
+            None,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Deserialize;
+
+impl Command for Deserialize {
+    fn name(&self) -> &str {
+        "zenoh deserialize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "schema",
+                SyntaxShape::String,
+                "Type schema (e.g. '(u32, string, list<f64>)')",
+            )
+            .input_output_type(Type::Binary, Type::Any)
+            .zenoh_category()
+    }
+
+    fn description(&self) -> &str {
+        "Deserialize a value from the zenoh-ext serialization format"
+    }
+
+    fn extra_description(&self) -> &str {
+        "See `zenoh serialize` for supported types. Maps are returned as records and tuples as lists."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let schema = schema(engine_state, stack, call)?;
+        let span = call.head;
+        let bytes = match input {
+            PipelineData::Value(Value::Binary { val, .. }, ..) => ZBytes::from(val),
+            _ => {
+                return Err(ShellError::Generic(
+                    GenericError::new("Expected binary input", "Input must be binary data", span)
+                        .with_help("Pipe binary data to this command"),
+                ));
+            }
+        };
+
+        Ok(PipelineData::Value(
+            serialization::deserialize(&bytes, &schema, span)?,
+            None,
+        ))
+    }
+}
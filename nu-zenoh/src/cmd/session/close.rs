@@ -42,6 +42,7 @@ impl Command for Close {
     ) -> Result<PipelineData, ShellError> {
         let session_name = call.session(engine_state, stack)?;
         let mut sessions = self.state.sessions.write().unwrap();
+        self.state.cancel_session(&session_name);
         if let Some(sess) = sessions.remove(&session_name) {
             sess.close().wait().map_err(|e| {
                 nu_protocol::LabeledError::new("Failed to close Zenoh session '{session_name}'")
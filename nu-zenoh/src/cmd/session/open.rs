@@ -72,7 +72,10 @@ impl Command for Open {
     ) -> Result<PipelineData, ShellError> {
         // FIXME(fuzzypixelz): refactor this (see 'zenoh runtime open')
 
-        let file_path = call.get_flag::<PathBuf>(engine_state, stack, "config-file")?;
+        let file_path = call
+            .get_flag::<PathBuf>(engine_state, stack, "config-file")?
+            .map(|path| call.expand_path(engine_state, stack, &path))
+            .transpose()?;
         let runtime_name = call.get_flag::<String>(engine_state, stack, "runtime")?;
         let config_record = call.opt::<Value>(engine_state, stack, 0)?;
 
@@ -121,6 +124,7 @@ impl Command for Open {
 
                 let session_name = call.session(engine_state, stack)?;
                 let mut sessions = self.state.sessions.write().unwrap();
+                self.state.cancel_session(&session_name);
                 if let Some(sess) = sessions.remove(&session_name) {
                     sess.close().wait().map_err(|e| {
                         nu_protocol::LabeledError::new(
@@ -148,6 +152,7 @@ impl Command for Open {
 
         let session_name = call.session(engine_state, stack)?;
         let mut sessions = self.state.sessions.write().unwrap();
+        self.state.cancel_session(&session_name);
         if let Some(sess) = sessions.remove(&session_name) {
             sess.close().wait().map_err(|e| {
                 nu_protocol::LabeledError::new("Failed to reopen Zenoh session '{session_name}'")
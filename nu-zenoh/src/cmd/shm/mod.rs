@@ -0,0 +1,68 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::num::NonZeroUsize;
+
+use zenoh::{
+    bytes::ZBytes,
+    shm::{
+        AllocAlignment, Defragment, GarbageCollect, MemoryLayout, OwnedShmBuf,
+        PosixShmProviderBackend, ShmProvider,
+    },
+    Wait,
+};
+
+pub(crate) mod provider_create;
+
+/// Shared-memory provider of a session, see `zenoh shm provider create`
+pub(crate) struct ShmPool {
+    provider: ShmProvider<PosixShmProviderBackend>,
+    alignment: AllocAlignment,
+}
+
+impl ShmPool {
+    /// Copies a payload into a newly allocated shared-memory buffer
+    pub(crate) fn copy(&self, payload: ZBytes) -> Result<ZBytes, String> {
+        let bytes = payload.to_bytes();
+
+        // NOTE: empty layouts are invalid, there is nothing to share anyway
+        if bytes.is_empty() {
+            return Ok(payload);
+        }
+
+        // NOTE: layout sizes must be multiples of the alignment, the buffer is shrunk back to
+        // the payload length once allocated
+        let size = bytes
+            .len()
+            .next_multiple_of(self.alignment.get_alignment_value().get());
+        let layout = MemoryLayout::new(size, self.alignment)
+            .map_err(|err| format!("invalid layout: {err:?}"))?;
+
+        // NOTE: the allocation must not block, a payload larger than the pool would never fit
+        let mut buffer = self
+            .provider
+            .alloc_layout(layout)
+            .map_err(|err| format!("invalid layout: {err:?}"))?
+            .alloc()
+            .with_policy::<Defragment<GarbageCollect>>()
+            .wait()
+            .map_err(|err| format!("allocation of {size} bytes failed: {err:?}"))?;
+
+        buffer[..bytes.len()].copy_from_slice(&bytes);
+        buffer
+            .try_resize(NonZeroUsize::new(bytes.len()).unwrap())
+            .ok_or_else(|| "could not shrink buffer".to_string())?;
+
+        Ok(buffer.into())
+    }
+}
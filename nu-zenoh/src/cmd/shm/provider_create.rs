@@ -0,0 +1,124 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::sync::Arc;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+use zenoh::{
+    shm::{AllocAlignment, PosixShmProviderBackend, ShmProviderBuilder},
+    Wait,
+};
+
+use crate::{call_ext2::CallExt2, cmd::shm::ShmPool, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct ProviderCreate {
+    state: State,
+}
+
+impl ProviderCreate {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for ProviderCreate {
+    fn name(&self) -> &str {
+        "zenoh shm provider create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .named(
+                "size",
+                SyntaxShape::Filesize,
+                "Size of the shared-memory pool (defaults to 16MiB)",
+                None,
+            )
+            .named(
+                "alignment",
+                SyntaxShape::Int,
+                "Alignment of allocated buffers in bytes, a power of two (defaults to 1)",
+                None,
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Create (or replace) the shared-memory provider of a session"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The provider is used by 'zenoh put --shm' and 'zenoh pub --shm' on the same session"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const DEFAULT_SIZE: usize = 16 * 1024 * 1024;
+
+        let session = call.session(engine_state, stack)?;
+
+        let size = match call.get_flag::<Value>(engine_state, stack, "size")? {
+            Some(size) => usize::try_from(size.as_filesize()?.get()).map_err(|_| {
+                LabeledError::new("Invalid size").with_label("Size must be positive", call.head)
+            })?,
+            None => DEFAULT_SIZE,
+        };
+
+        let alignment = match call.get_flag::<i64>(engine_state, stack, "alignment")? {
+            Some(alignment) if alignment > 0 && (alignment as u64).is_power_of_two() => {
+                AllocAlignment::new((alignment as u64).trailing_zeros().try_into().unwrap())
+                    .map_err(|err| {
+                        LabeledError::new("Invalid alignment")
+                            .with_label(format!("Unsupported alignment: {err:?}"), call.head)
+                    })?
+            }
+            Some(_) => {
+                return Err(LabeledError::new("Invalid alignment")
+                    .with_label("Alignment must be a power of two", call.head)
+                    .into())
+            }
+            None => AllocAlignment::default(),
+        };
+
+        let backend = PosixShmProviderBackend::builder((size, alignment))
+            .wait()
+            .map_err(|e| {
+                LabeledError::new("Shared-memory provider creation failed")
+                    .with_label(format!("Could not create POSIX backend: {e}"), call.head)
+            })?;
+
+        let provider = ShmProviderBuilder::backend(backend).wait();
+
+        self.state.shm_pools.write().unwrap().insert(
+            session,
+            Arc::new(ShmPool {
+                provider,
+                alignment,
+            }),
+        );
+
+        Ok(PipelineData::Value(Value::nothing(call.head), None))
+    }
+}
@@ -0,0 +1,306 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::HashMap,
+    io::{ErrorKind, Read, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use chrono::Local;
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record,
+    shell_error::generic::GenericError,
+    IntoValue, ListStream, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    cmd::decode::transport_batch::{batch_error, decode_batch, split_batches},
+    interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt,
+};
+
+#[derive(Clone)]
+pub(crate) struct Sniff;
+
+impl Command for Sniff {
+    fn name(&self) -> &str {
+        "zenoh sniff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required_named(
+                "listen",
+                SyntaxShape::String,
+                "Address on which to accept client connections (e.g. 127.0.0.1:7448)",
+                Some('l'),
+            )
+            .required_named(
+                "upstream",
+                SyntaxShape::String,
+                "Address of the node to which connections are forwarded (e.g. 127.0.0.1:7447)",
+                Some('u'),
+            )
+            .switch(
+                "compression",
+                "Batches start with a header byte, as is the case once compression is negotiated",
+                Some('c'),
+            )
+            .switch(
+                "full",
+                "Decode the zenoh message bodies and extensions of network messages",
+                Some('f'),
+            )
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .zenoh_category()
+    }
+
+    fn description(&self) -> &str {
+        "Returns a stream of the Zenoh messages going through a TCP proxy"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Clients connect to the 'listen' address instead of the upstream node; \
+        bytes are forwarded both ways until the stream is dropped. \
+        Messages are {timestamp, connection, direction, src, dst, message} records, \
+        where 'direction' is 'in' from client to upstream and 'out' otherwise."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const MESSAGE_CHANNEL_SIZE: usize = 256;
+        let (tx, rx) = flume::bounded(MESSAGE_CHANNEL_SIZE);
+
+        let span = call.head;
+        let listen = call.get_flag::<String>(engine_state, stack, "listen")?;
+        let upstream = call.get_flag::<String>(engine_state, stack, "upstream")?;
+        let (Some(listen), Some(upstream)) = (listen, upstream) else {
+            return Err(ShellError::Generic(GenericError::new(
+                "Missing proxy addresses",
+                "Both --listen and --upstream are required",
+                span,
+            )));
+        };
+
+        let listener = TcpListener::bind(&listen)
+            .and_then(|listener| listener.set_nonblocking(true).map(|_| listener))
+            .map_err(|e| {
+                nu_protocol::LabeledError::new("Failed to start proxy")
+                    .with_label(format!("Could not listen on {listen}: {e}"), span)
+            })?;
+
+        let proxy = Proxy {
+            upstream,
+            compression: call.has_flag(engine_state, stack, "compression")?,
+            full: call.has_flag(engine_state, stack, "full")?,
+            stopped: Arc::new(AtomicBool::new(false)),
+            streams: Arc::new(Mutex::new(HashMap::new())),
+            span,
+        };
+
+        let guard = ProxyGuard {
+            stopped: proxy.stopped.clone(),
+            streams: proxy.streams.clone(),
+        };
+
+        thread::spawn(move || proxy.accept(listener, tx));
+
+        let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), guard)
+            .into_values(span, |value| value);
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+#[derive(Clone)]
+struct Proxy {
+    upstream: String,
+    compression: bool,
+    full: bool,
+    stopped: Arc<AtomicBool>,
+    /// Sockets of the open connections, shut down once the proxy is stopped
+    streams: Arc<Mutex<HashMap<usize, [TcpStream; 2]>>>,
+    span: Span,
+}
+
+impl Proxy {
+    const ACCEPT_INTERVAL: Duration = Duration::from_millis(50);
+
+    fn accept(self, listener: TcpListener, tx: flume::Sender<Value>) {
+        let mut connection = 0;
+        while !self.stopped.load(Ordering::Relaxed) {
+            let client = match listener.accept() {
+                Ok((client, _)) => client,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    thread::sleep(Self::ACCEPT_INTERVAL);
+                    continue;
+                }
+                Err(e) => {
+                    let _ = tx.send(batch_error(
+                        format!("Could not accept connection: {e}"),
+                        self.span,
+                    ));
+                    return;
+                }
+            };
+
+            if let Err(e) = self.connect(client, connection, &tx) {
+                let _ = tx.send(batch_error(
+                    format!("Could not connect to {}: {e}", self.upstream),
+                    self.span,
+                ));
+            }
+            connection += 1;
+        }
+    }
+
+    fn connect(
+        &self,
+        client: TcpStream,
+        connection: usize,
+        tx: &flume::Sender<Value>,
+    ) -> std::io::Result<()> {
+        client.set_nonblocking(false)?;
+        let upstream = TcpStream::connect(&self.upstream)?;
+
+        let client_addr = client.peer_addr()?;
+        let upstream_addr = upstream.peer_addr()?;
+
+        self.streams
+            .lock()
+            .unwrap()
+            .insert(connection, [client.try_clone()?, upstream.try_clone()?]);
+
+        // NOTE: the proxy may have been stopped before the sockets were registered
+        if self.stopped.load(Ordering::Relaxed) {
+            let _ = client.shutdown(Shutdown::Both);
+            let _ = upstream.shutdown(Shutdown::Both);
+            return Ok(());
+        }
+
+        let link = Link {
+            proxy: self.clone(),
+            connection,
+            directions: Arc::new(AtomicUsize::new(2)),
+            tx: tx.clone(),
+        };
+
+        let (client_rx, upstream_tx) = (client.try_clone()?, upstream.try_clone()?);
+        let inbound = link.clone();
+        thread::spawn(move || {
+            inbound.forward(client_rx, upstream_tx, "in", client_addr, upstream_addr)
+        });
+        thread::spawn(move || link.forward(upstream, client, "out", upstream_addr, client_addr));
+
+        Ok(())
+    }
+}
+
+/// One connection of the proxy
+#[derive(Clone)]
+struct Link {
+    proxy: Proxy,
+    connection: usize,
+    /// Number of directions still being forwarded
+    directions: Arc<AtomicUsize>,
+    tx: flume::Sender<Value>,
+}
+
+impl Link {
+    /// Copies bytes from `src` to `dst`, sending the messages of complete batches
+    fn forward(
+        self,
+        mut src: TcpStream,
+        mut dst: TcpStream,
+        direction: &str,
+        src_addr: SocketAddr,
+        dst_addr: SocketAddr,
+    ) {
+        let span = self.proxy.span;
+        let mut bytes = vec![0; u16::MAX as usize];
+        let mut buffer = Vec::new();
+        loop {
+            let len = match src.read(&mut bytes) {
+                Ok(0) | Err(_) => break,
+                Ok(len) => len,
+            };
+
+            // NOTE: messages are sent before being forwarded so that they are never reported
+            // after the replies they trigger (e.g. an init-ack before its init-syn)
+            buffer.extend_from_slice(&bytes[..len]);
+            for batch in split_batches(&mut buffer) {
+                for (_, message) in
+                    decode_batch(&batch, self.proxy.compression, self.proxy.full, span)
+                {
+                    let _ = self.tx.send(
+                        record!(
+                            "timestamp"  => Value::date(Local::now().fixed_offset(), span),
+                            "connection" => (self.connection as i64).into_value(span),
+                            "direction"  => direction.into_value(span),
+                            "src"        => src_addr.to_string().into_value(span),
+                            "dst"        => dst_addr.to_string().into_value(span),
+                            "message"    => message,
+                        )
+                        .into_value(span),
+                    );
+                }
+            }
+
+            if dst.write_all(&bytes[..len]).is_err() {
+                break;
+            }
+        }
+
+        let _ = dst.shutdown(Shutdown::Write);
+
+        // NOTE: the last direction to end unregisters the connection, closing its socket clones
+        if self.directions.fetch_sub(1, Ordering::Relaxed) == 1 {
+            self.proxy.streams.lock().unwrap().remove(&self.connection);
+        }
+    }
+}
+
+/// Stops the proxy once the output stream is dropped
+struct ProxyGuard {
+    stopped: Arc<AtomicBool>,
+    streams: Arc<Mutex<HashMap<usize, [TcpStream; 2]>>>,
+}
+
+impl Drop for ProxyGuard {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        for stream in self
+            .streams
+            .lock()
+            .unwrap()
+            .drain()
+            .flat_map(|(_, streams)| streams)
+        {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+    }
+}
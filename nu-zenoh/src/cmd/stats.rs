@@ -0,0 +1,269 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{collections::BTreeMap, time::Instant};
+
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    shell_error::generic::GenericError,
+    IntoValue, ListStream, PipelineData, Record, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use zenoh::Session;
+
+use crate::{
+    call_ext2::CallExt2, cmd::admin, interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt, State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Stats {
+    state: State,
+}
+
+impl Stats {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Stats {
+    fn name(&self) -> &str {
+        "zenoh stats"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .named(
+                "watch",
+                SyntaxShape::Duration,
+                "Poll statistics at this interval and stream the deltas",
+                None,
+            )
+            .input_output_type(Type::Nothing, Type::table())
+    }
+
+    fn description(&self) -> &str {
+        "Session transport statistics"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns one row per set of metric labels (e.g. transport, link and priority) with one column per counter. \
+        With --watch, every poll streams the same rows with the increments since the previous poll \
+        and the time elapsed since the first one. \
+        Metrics are read from the admin space, which must be enabled (i.e. 'adminspace.enabled')."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let session = call.session(engine_state, stack)?;
+        let sess = self.state.with_session(&session, |sess| sess.clone())?;
+
+        let Some(interval) = call.duration(engine_state, stack, "watch")? else {
+            let metrics = Metrics::fetch(&sess, span)?;
+            let rows = metrics
+                .into_rows(span)
+                .map(|row| Value::record(row, span))
+                .collect();
+            return Ok(PipelineData::Value(Value::list(rows, span), None));
+        };
+
+        let (tx, rx) = flume::bounded(1);
+
+        // NOTE: the polling thread ends as soon as the stream is dropped or a poll fails
+        std::thread::spawn(move || {
+            let start = Instant::now();
+            let mut previous = None;
+            loop {
+                let metrics = match Metrics::fetch(&sess, span) {
+                    Ok(metrics) => metrics,
+                    Err(err) => {
+                        let _ = tx.send(Value::error(err, span));
+                        return;
+                    }
+                };
+
+                let elapsed = Value::duration(start.elapsed().as_nanos() as i64, span);
+                if let Some(previous) = &previous {
+                    for mut row in metrics.delta(previous).into_rows(span) {
+                        row.insert("elapsed", elapsed.clone());
+                        if tx.send(Value::record(row, span)).is_err() {
+                            return;
+                        }
+                    }
+                }
+
+                previous = Some(metrics);
+                std::thread::sleep(interval);
+
+                if tx.is_disconnected() {
+                    return;
+                }
+            }
+        });
+
+        let iter = InterruptibleChannel::new(rx, engine_state.signals().clone())
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, |value| value);
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+/// Metric labels, in order of appearance
+type Labels = Vec<(String, String)>;
+
+/// Metric samples of a session grouped by labels
+struct Metrics(BTreeMap<Labels, BTreeMap<String, f64>>);
+
+impl Metrics {
+    /// Queries the metrics of the session runtime from the admin space
+    #[allow(clippy::result_large_err)]
+    fn fetch(session: &Session, span: Span) -> Result<Self, ShellError> {
+        let selector = format!(
+            "@/{}/*/metrics?compression=false;descriptors=false;per_key=false",
+            session.zid()
+        );
+
+        let mut metrics = Metrics(BTreeMap::new());
+        for reply in admin::query(session, &selector, span)? {
+            metrics.parse(reply.payload.as_str()?).map_err(|msg| {
+                ShellError::Generic(GenericError::new("Invalid metrics", msg, span))
+            })?;
+        }
+
+        Ok(metrics)
+    }
+
+    /// Parses metric samples in the OpenMetrics text format
+    fn parse(&mut self, text: &str) -> Result<(), String> {
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (name, labels, rest) = match line.find(['{', ' ']) {
+                Some(i) if line[i..].starts_with('{') => {
+                    let (labels, rest) = parse_labels(&line[i + 1..])
+                        .ok_or_else(|| format!("Invalid metric labels: '{line}'"))?;
+                    (&line[..i], labels, rest)
+                }
+                Some(i) => (&line[..i], Labels::new(), &line[i..]),
+                None => return Err(format!("Invalid metric sample: '{line}'")),
+            };
+
+            // NOTE: samples may be followed by a timestamp, which is ignored
+            let value = rest
+                .split_whitespace()
+                .next()
+                .and_then(|value| value.parse::<f64>().ok())
+                .ok_or_else(|| format!("Invalid metric value: '{line}'"))?;
+
+            self.0
+                .entry(labels)
+                .or_default()
+                .insert(name.to_string(), value);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the increments of every metric since a previous snapshot
+    fn delta(&self, previous: &Metrics) -> Metrics {
+        Metrics(
+            self.0
+                .iter()
+                .map(|(labels, values)| {
+                    let deltas = values
+                        .iter()
+                        .map(|(name, value)| {
+                            let previous = previous
+                                .0
+                                .get(labels)
+                                .and_then(|values| values.get(name))
+                                .copied()
+                                .unwrap_or_default();
+                            (name.clone(), value - previous)
+                        })
+                        .collect();
+                    (labels.clone(), deltas)
+                })
+                .collect(),
+        )
+    }
+
+    fn into_rows(self, span: Span) -> impl Iterator<Item = Record> {
+        self.0.into_iter().map(move |(labels, values)| {
+            let mut record = Record::new();
+
+            for (label, value) in labels {
+                record.push(label, value.into_value(span));
+            }
+
+            for (name, value) in values {
+                record.push(name, metric_to_value(value, span));
+            }
+
+            record
+        })
+    }
+}
+
+/// Parses `key="value",...}`, returning the labels and the remainder of the line
+fn parse_labels(input: &str) -> Option<(Labels, &str)> {
+    let mut labels = Labels::new();
+    let mut rest = input;
+
+    loop {
+        rest = rest.trim_start_matches([',', ' ']);
+
+        if let Some(rest) = rest.strip_prefix('}') {
+            return Some((labels, rest));
+        }
+
+        let (key, after) = rest.split_once("=\"")?;
+        let mut value = String::new();
+        let mut chars = after.char_indices();
+
+        let end = loop {
+            match chars.next()? {
+                (i, '"') => break i,
+                (_, '\\') => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    c => value.push(c),
+                },
+                (_, c) => value.push(c),
+            }
+        };
+
+        labels.push((key.trim().to_string(), value));
+        rest = &after[end + 1..];
+    }
+}
+
+/// Counters are integers but OpenMetrics represents all values as floats
+fn metric_to_value(value: f64, span: Span) -> Value {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        Value::int(value as i64, span)
+    } else {
+        Value::float(value, span)
+    }
+}
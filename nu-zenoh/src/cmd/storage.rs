@@ -0,0 +1,513 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record,
+    shell_error::generic::GenericError,
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use serde::{Deserialize, Serialize};
+use zenoh::{
+    bytes::{Encoding, ZBytes},
+    key_expr::{keyexpr, OwnedKeyExpr},
+    query::{Query, Queryable},
+    sample::{Sample, SampleKind},
+    session::ZenohId,
+    time::{Timestamp, NTP64},
+    Wait,
+};
+
+use crate::{
+    call_ext2::CallExt2,
+    cmd::entity::{Entity, EntityHandle, EntityValue},
+    conv::{self, PayloadFormat},
+    signature_ext::SignatureExt,
+    State,
+};
+
+/// A subscriber and a complete queryable sharing the latest sample of each key
+pub(crate) struct Storage {
+    subscriber: zenoh::pubsub::Subscriber<()>,
+    queryable: Queryable<()>,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl Storage {
+    pub(crate) fn undeclare(self) -> zenoh::Result<()> {
+        self.subscriber.undeclare().wait()?;
+        self.queryable.undeclare().wait()
+    }
+}
+
+/// Latest sample of a key, which is a tombstone if its kind is [`SampleKind::Delete`]
+#[derive(Clone)]
+struct Entry {
+    keyexpr: OwnedKeyExpr,
+    kind: SampleKind,
+    payload: ZBytes,
+    encoding: Encoding,
+    attachment: Option<ZBytes>,
+    timestamp: Timestamp,
+}
+
+impl Entry {
+    /// Returns the entry of a sample, timestamping it with `zid` if the sample has no timestamp
+    fn from_sample(sample: &Sample, zid: ZenohId) -> Self {
+        let timestamp = sample.timestamp().cloned().unwrap_or_else(|| {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+            Timestamp::new(NTP64::from(now), zid.into())
+        });
+
+        Self {
+            keyexpr: sample.key_expr().clone().into_owned().into(),
+            kind: sample.kind(),
+            payload: sample.payload().clone(),
+            encoding: sample.encoding().clone(),
+            attachment: sample.attachment().cloned(),
+            timestamp,
+        }
+    }
+
+    fn to_value(&self, format: &PayloadFormat, span: Span) -> Value {
+        record!(
+            "keyexpr" => self.keyexpr.to_string().into_value(span),
+            "kind" => self.kind.to_string().into_value(span),
+            "payload" => match self.kind {
+                SampleKind::Put => conv::payload_to_value(&self.payload, &self.encoding, format, span),
+                SampleKind::Delete => Value::nothing(span),
+            },
+            "encoding" => self.encoding.to_string().into_value(span),
+            "attachment" => self.attachment
+                .as_ref()
+                .map(|a| conv::bytes_to_value(a, span))
+                .unwrap_or_default(),
+            "timestamp" => self.timestamp.to_string_rfc3339_lossy().into_value(span),
+        )
+        .into_value(span)
+    }
+}
+
+/// An [`Entry`] as persisted in the storage file
+#[derive(Serialize, Deserialize)]
+struct StoredEntry {
+    keyexpr: String,
+    kind: String,
+    payload: Vec<u8>,
+    encoding: String,
+    attachment: Option<Vec<u8>>,
+    timestamp: String,
+}
+
+impl From<&Entry> for StoredEntry {
+    fn from(entry: &Entry) -> Self {
+        Self {
+            keyexpr: entry.keyexpr.to_string(),
+            kind: entry.kind.to_string(),
+            payload: entry.payload.to_bytes().into_owned(),
+            encoding: entry.encoding.to_string(),
+            attachment: entry.attachment.as_ref().map(|a| a.to_bytes().into_owned()),
+            timestamp: entry.timestamp.to_string(),
+        }
+    }
+}
+
+impl TryFrom<StoredEntry> for Entry {
+    type Error = String;
+
+    fn try_from(entry: StoredEntry) -> Result<Self, Self::Error> {
+        let kind = match entry.kind.as_str() {
+            "PUT" => SampleKind::Put,
+            "DELETE" => SampleKind::Delete,
+            other => return Err(format!("invalid sample kind '{other}'")),
+        };
+
+        Ok(Self {
+            keyexpr: OwnedKeyExpr::new(entry.keyexpr).map_err(|e| e.to_string())?,
+            kind,
+            payload: entry.payload.into(),
+            encoding: Encoding::from(entry.encoding),
+            attachment: entry.attachment.map(ZBytes::from),
+            timestamp: entry
+                .timestamp
+                .parse()
+                .map_err(|e| format!("invalid timestamp: {e:?}"))?,
+        })
+    }
+}
+
+/// Contents of a storage, optionally persisted to a file
+struct Entries {
+    map: BTreeMap<String, Entry>,
+    file: Option<PathBuf>,
+    /// Error of the last save on update, reported by `zenoh storage dump`
+    save_error: Option<String>,
+}
+
+impl Entries {
+    /// Creates the entries of a storage, loading them from `file` if it exists
+    fn load(file: Option<PathBuf>) -> Result<Self, String> {
+        let mut map = BTreeMap::new();
+        if let Some(bytes) = file.as_ref().filter(|file| file.exists()).map(fs::read) {
+            let bytes = bytes.map_err(|e| e.to_string())?;
+            let stored: Vec<StoredEntry> =
+                ciborium::from_reader(bytes.as_slice()).map_err(|e| e.to_string())?;
+            for entry in stored {
+                let entry = Entry::try_from(entry)?;
+                map.insert(entry.keyexpr.to_string(), entry);
+            }
+        }
+
+        let entries = Self {
+            map,
+            file,
+            save_error: None,
+        };
+        entries.save()?;
+        Ok(entries)
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let Some(file) = &self.file else {
+            return Ok(());
+        };
+
+        let stored = self.map.values().map(StoredEntry::from).collect::<Vec<_>>();
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&stored, &mut bytes).map_err(|e| e.to_string())?;
+
+        // Write to a temporary file first, so that the file is never left truncated
+        let mut tmp = file.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, bytes).map_err(|e| e.to_string())?;
+        fs::rename(&tmp, file).map_err(|e| e.to_string())
+    }
+
+    /// Stores an entry unless a newer one exists for its key
+    ///
+    /// As in the storage manager, wildcard puts and deletes update all the stored keys they
+    /// include, but are not stored themselves.
+    fn update(&mut self, entry: Entry) {
+        let keys = if entry.keyexpr.is_wild() {
+            self.map
+                .values()
+                .filter(|stored| entry.keyexpr.includes(&stored.keyexpr))
+                .map(|stored| stored.keyexpr.clone())
+                .collect()
+        } else {
+            vec![entry.keyexpr.clone()]
+        };
+
+        for key in keys {
+            let stored = self.map.get(key.as_str());
+            if stored.is_some_and(|stored| stored.timestamp >= entry.timestamp) {
+                continue;
+            }
+
+            let entry = Entry {
+                keyexpr: key.clone(),
+                ..entry.clone()
+            };
+            self.map.insert(key.to_string(), entry);
+        }
+
+        self.save_error = self.save().err();
+    }
+
+    fn clear(&mut self) -> Result<(), String> {
+        self.map.clear();
+        self.save_error = None;
+        self.save()
+    }
+
+    /// Returns the latest puts on the keys intersecting `keyexpr`
+    fn get(&self, keyexpr: &keyexpr) -> Vec<Entry> {
+        self.map
+            .values()
+            .filter(|entry| entry.kind == SampleKind::Put && entry.keyexpr.intersects(keyexpr))
+            .cloned()
+            .collect()
+    }
+}
+
+fn reply(query: &Query, entries: &Mutex<Entries>) {
+    let entries = entries.lock().unwrap().get(query.key_expr());
+    for entry in entries {
+        let _ = query
+            .reply(entry.keyexpr, entry.payload)
+            .encoding(entry.encoding)
+            .attachment(entry.attachment)
+            .timestamp(entry.timestamp)
+            .wait();
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Declare {
+    state: State,
+}
+
+impl Declare {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Declare {
+    fn name(&self) -> &str {
+        "zenoh storage"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Any)
+            .session()
+            .zenoh_category()
+            .keyexpr()
+            .named(
+                "file",
+                SyntaxShape::Filepath,
+                "File from which the storage is loaded (if it exists) and to which it is atomically saved on every update",
+                Some('f'),
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Declare an in-memory storage"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The storage subscribes to the key expression and keeps the latest sample of each key, \
+        which it replies with to the queries intersecting it through a complete queryable. \
+        As in the storage manager, samples older than the stored one are ignored and deletes are kept as tombstones; \
+        samples without a timestamp are timestamped on reception. \
+        Use 'zenoh storage dump' and 'zenoh storage clear' to inspect and reset the storage, \
+        and 'zenoh entity undeclare' to remove it."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let session = call.session(engine_state, stack)?;
+        let file = call
+            .get_flag::<PathBuf>(engine_state, stack, "file")?
+            .map(|path| call.expand_path(engine_state, stack, &path))
+            .transpose()?;
+
+        let entries = Entries::load(file).map_err(|e| {
+            LabeledError::new("Storage declaration failed")
+                .with_label(format!("Could not load storage file: {e}"), span)
+        })?;
+        let entries = Arc::new(Mutex::new(entries));
+
+        let storage = self
+            .state
+            .with_session(&session, |sess| -> zenoh::Result<_> {
+                let zid = sess.zid();
+                let sub_entries = entries.clone();
+                let subscriber = sess
+                    .declare_subscriber(keyexpr.clone())
+                    .callback(move |sample| {
+                        sub_entries
+                            .lock()
+                            .unwrap()
+                            .update(Entry::from_sample(&sample, zid))
+                    })
+                    .wait()?;
+
+                let query_entries = entries.clone();
+                let queryable = sess
+                    .declare_queryable(keyexpr.clone())
+                    .complete(true)
+                    .callback(move |query| reply(&query, &query_entries))
+                    .wait()?;
+
+                Ok(Storage {
+                    subscriber,
+                    queryable,
+                    entries,
+                })
+            })?
+            .map_err(|e| {
+                LabeledError::new("Storage declaration failed")
+                    .with_label(format!("Zenoh storage failed: {e}"), span)
+            })?;
+
+        let entity = Entity {
+            keyexpr,
+            session,
+            handle: EntityHandle::Storage(storage),
+        };
+
+        Ok(PipelineData::Value(
+            self.state.insert_entity(entity, span),
+            None,
+        ))
+    }
+}
+
+/// Runs `f` on the entries of a storage entity
+#[allow(clippy::result_large_err)]
+fn with_entries<T>(
+    state: &State,
+    storage: &Value,
+    span: Span,
+    f: impl FnOnce(&mut Entries) -> T,
+) -> Result<T, ShellError> {
+    let id = EntityValue::id(storage)?;
+    let entries = state.with_entity(id, |entity| match &entity.handle {
+        EntityHandle::Storage(storage) => Ok(storage.entries.clone()),
+        _ => Err(LabeledError::new("Invalid entity").with_label(
+            format!("Entity '{id}' is a {}, not a storage", entity.kind()),
+            span,
+        )),
+    })??;
+
+    let mut entries = entries.lock().unwrap();
+    Ok(f(&mut entries))
+}
+
+#[derive(Clone)]
+pub(crate) struct Dump {
+    state: State,
+}
+
+impl Dump {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Dump {
+    fn name(&self) -> &str {
+        "zenoh storage dump"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .required("storage", SyntaxShape::Any, "Storage handle or id")
+            .switch("tombstones", "Include the deleted keys", Some('t'))
+            .payload_format()
+    }
+
+    fn description(&self) -> &str {
+        "List the contents of a storage"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns one {keyexpr, kind, payload, encoding, attachment, timestamp} record per key, sorted by key; \
+        tombstones have the DELETE kind and no payload. \
+        Fails if the last update of a file-backed storage could not be saved."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let storage = call.req::<Value>(engine_state, stack, 0)?;
+        let tombstones = call.has_flag(engine_state, stack, "tombstones")?;
+        let format = call.payload_format(engine_state, stack)?;
+
+        let values = with_entries(&self.state, &storage, span, |entries| {
+            if let Some(e) = &entries.save_error {
+                return Err(e.clone());
+            }
+
+            Ok(entries
+                .map
+                .values()
+                .filter(|entry| tombstones || entry.kind == SampleKind::Put)
+                .map(|entry| entry.to_value(&format, span))
+                .collect())
+        })?
+        .map_err(|e| {
+            ShellError::Generic(GenericError::new(
+                "Storage dump failed",
+                format!("Could not save storage file: {e}"),
+                span,
+            ))
+        })?;
+
+        Ok(PipelineData::Value(Value::list(values, span), None))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Clear {
+    state: State,
+}
+
+impl Clear {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Clear {
+    fn name(&self) -> &str {
+        "zenoh storage clear"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .required("storage", SyntaxShape::Any, "Storage handle or id")
+    }
+
+    fn description(&self) -> &str {
+        "Remove all the entries of a storage, including its tombstones"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let storage = call.req::<Value>(engine_state, stack, 0)?;
+
+        with_entries(&self.state, &storage, span, Entries::clear)?.map_err(|e| {
+            ShellError::Generic(GenericError::new(
+                "Storage clear failed",
+                format!("Could not save storage file: {e}"),
+                span,
+            ))
+        })?;
+
+        Ok(PipelineData::Value(Value::nothing(span), None))
+    }
+}
@@ -14,15 +14,66 @@
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    record, IntoValue, ListStream, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use zenoh::{sample::Sample, Wait};
+use zenoh_ext::{
+    AdvancedSubscriber, AdvancedSubscriberBuilderExt, HistoryConfig, Miss, RecoveryConfig,
+    SampleMissListener,
 };
-use zenoh::Wait;
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
-    signature_ext::SignatureExt, State,
+    call_ext2::CallExt2,
+    cmd::entity::{Entity, EntityHandle, EntityValue},
+    conv::{self, PayloadFormat},
+    interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt,
+    State,
 };
 
+/// A plain or advanced (i.e. zenoh-ext) subscriber
+pub(crate) enum Subscriber {
+    Plain(zenoh::pubsub::Subscriber<()>),
+    Advanced(AdvancedSubscriber<()>, SampleMissListener<()>),
+}
+
+impl Subscriber {
+    pub(crate) fn undeclare(self) -> zenoh::Result<()> {
+        match self {
+            Subscriber::Plain(subscriber) => subscriber.undeclare().wait(),
+            Subscriber::Advanced(subscriber, listener) => {
+                listener.undeclare().wait()?;
+                subscriber.undeclare().wait()
+            }
+        }
+    }
+}
+
+/// Events received by a subscriber
+pub(crate) enum SubscriberEvent {
+    Sample(Sample),
+    /// Samples detected as lost by an advanced subscriber
+    Miss(Miss),
+}
+
+impl SubscriberEvent {
+    pub(crate) fn into_value(self, format: &PayloadFormat, span: Span) -> Value {
+        match self {
+            SubscriberEvent::Sample(sample) => conv::sample_to_record_value(sample, format, span),
+            SubscriberEvent::Miss(miss) => record!(
+                "kind" => "MISS".into_value(span),
+                "source_id" => record!(
+                    "zid" => miss.source().zid().to_string().into_value(span),
+                    "eid" => miss.source().eid().into_value(span),
+                ).into_value(span),
+                "missed" => miss.nb().into_value(span),
+            )
+            .into_value(span),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct Sub {
     state: State,
@@ -46,12 +97,41 @@ impl Command for Sub {
             .input_output_type(Type::Nothing, Type::list(Type::record()))
             .required("keyexpr", SyntaxShape::String, "key-expression")
             .allowed_origin()
+            .payload_format()
+            .background()
+            .switch("advanced", "Declare an advanced subscriber", None)
+            .switch(
+                "history",
+                "Query historical samples from the cache of advanced publishers (implies --advanced)",
+                None,
+            )
+            .switch(
+                "late-joiner",
+                "Query historical samples from late joining publishers (implies --history)",
+                None,
+            )
+            .switch(
+                "recovery",
+                "Recover lost samples using publisher heartbeats (implies --advanced)",
+                None,
+            )
+            .named(
+                "query-period",
+                SyntaxShape::Duration,
+                "Recover lost samples by periodically querying publishers instead (implies --recovery)",
+                None,
+            )
     }
 
     fn description(&self) -> &str {
         "Declare a subscriber"
     }
 
+    fn extra_description(&self) -> &str {
+        "With --background, samples are buffered (dropping the oldest ones when full) until drained with 'zenoh sub recv'. \
+        Advanced subscribers report lost samples as {kind: MISS, source_id, missed} records."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -60,32 +140,182 @@ impl Command for Sub {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         const SUB_CHANNEL_SIZE: usize = 256;
-        let (tx, rx) = flume::bounded(SUB_CHANNEL_SIZE);
 
         let span = call.head;
 
         let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let format = call.payload_format(engine_state, stack)?;
+        let session = call.session(engine_state, stack)?;
+        let background = call.has_flag(engine_state, stack, "background")?;
+
+        let late_joiner = call.has_flag(engine_state, stack, "late-joiner")?;
+        let history = late_joiner || call.has_flag(engine_state, stack, "history")?;
+        let query_period = call.duration(engine_state, stack, "query-period")?;
+        let recovery = query_period.is_some() || call.has_flag(engine_state, stack, "recovery")?;
+        let advanced = history || recovery || call.has_flag(engine_state, stack, "advanced")?;
+
+        let (tx, rx) = if background {
+            flume::bounded(Entity::SAMPLE_BUFFER_SIZE)
+        } else {
+            flume::bounded(SUB_CHANNEL_SIZE)
+        };
+
+        let send = {
+            // NOTE: only background subscribers keep a receiver, so that foreground sends fail
+            // instead of blocking once the stream is dropped
+            let events = background.then(|| rx.clone());
+            move |event: SubscriberEvent| match &events {
+                Some(events) => {
+                    // NOTE: background subscribers drop their oldest samples instead of
+                    // blocking the Zenoh runtime until `zenoh sub recv` is called
+                    let mut event = event;
+                    while let Err(flume::TrySendError::Full(e)) = tx.try_send(event) {
+                        let _ = events.try_recv();
+                        event = e;
+                    }
+                }
+                None => {
+                    let _ = tx.send(event);
+                }
+            }
+        };
 
         let sub = self
             .state
-            .with_session(&call.session(engine_state, stack)?, move |sess| {
-                let mut sub = sess.declare_subscriber(keyexpr).callback(move |sample| {
-                    let _ = tx.send(sample);
-                });
+            .with_session(&session, |sess| -> zenoh::Result<_> {
+                let send_sample = send.clone();
+                let mut sub = sess
+                    .declare_subscriber(keyexpr.clone())
+                    .callback(move |sample| send_sample(SubscriberEvent::Sample(sample)));
 
                 if let Some(origin) = call.allowed_origin(engine_state, stack)? {
                     sub = sub.allowed_origin(origin);
                 }
-                sub.wait()
+
+                if !advanced {
+                    return Ok(Subscriber::Plain(sub.wait()?));
+                }
+
+                let mut sub = sub.advanced();
+
+                if history {
+                    let mut config = HistoryConfig::default();
+                    if late_joiner {
+                        config = config.detect_late_publishers();
+                    }
+                    sub = sub.history(config);
+                }
+
+                if let Some(period) = query_period {
+                    sub = sub.recovery(RecoveryConfig::default().periodic_queries(period));
+                } else if recovery {
+                    sub = sub.recovery(RecoveryConfig::default().heartbeat());
+                }
+
+                let sub = sub.wait()?;
+                let listener = sub
+                    .sample_miss_listener()
+                    .callback(move |miss| send(SubscriberEvent::Miss(miss)))
+                    .wait()?;
+
+                Ok(Subscriber::Advanced(sub, listener))
             })?
             .map_err(|e| {
                 nu_protocol::LabeledError::new("Subscriber declaration failed")
                     .with_label(format!("Zenoh subscriber failed: {e}"), call.head)
             })?;
 
+        if background {
+            let entity = Entity {
+                keyexpr,
+                session,
+                handle: EntityHandle::Subscriber {
+                    subscriber: sub,
+                    events: rx,
+                    format,
+                },
+            };
+
+            return Ok(PipelineData::Value(
+                self.state.insert_entity(entity, span),
+                None,
+            ));
+        }
+
         let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), sub)
-            .map(move |sample| conv::sample_to_record_value(sample, span));
+            .with_session(self.state.session_signals(&session))
+            .into_values(span, move |event: SubscriberEvent| {
+                event.into_value(&format, span)
+            });
 
         Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
     }
 }
+
+#[derive(Clone)]
+pub(crate) struct Recv {
+    state: State,
+}
+
+impl Recv {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Recv {
+    fn name(&self) -> &str {
+        "zenoh sub recv"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .required("subscriber", SyntaxShape::Any, "Subscriber handle or id")
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "Wait up to this duration for a sample if none is buffered",
+                None,
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Drain the samples buffered by a background subscriber"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let id = EntityValue::id(&call.req::<Value>(engine_state, stack, 0)?)?;
+
+        let (events, format) = self.state.with_entity(id, |entity| match &entity.handle {
+            EntityHandle::Subscriber { events, format, .. } => Ok((events.clone(), format.clone())),
+            _ => Err(nu_protocol::LabeledError::new("Invalid entity").with_label(
+                format!("Entity '{id}' is a {}, not a subscriber", entity.kind()),
+                span,
+            )),
+        })??;
+
+        let mut received = events.drain().collect::<Vec<_>>();
+
+        let timeout = call.timeout(engine_state, stack)?;
+        if let Some(timeout) = timeout.filter(|_| received.is_empty()) {
+            received.extend(events.recv_timeout(timeout));
+            received.extend(events.drain());
+        }
+
+        let values = received
+            .into_iter()
+            .map(|event| event.into_value(&format, span))
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(values, span), None))
+    }
+}
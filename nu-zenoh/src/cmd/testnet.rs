@@ -0,0 +1,440 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    net::TcpListener,
+    thread,
+    time::{Duration, Instant},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record,
+    shell_error::generic::GenericError,
+    IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use zenoh::{config::WhatAmI, session::ZenohId, Session, Wait};
+
+use crate::{call_ext2::CallExt2, conv, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Up {
+    state: State,
+}
+
+impl Up {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+
+    /// Time to wait for the links of the network by default
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+}
+
+impl Command for Up {
+    fn name(&self) -> &str {
+        "zenoh testnet up"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "nodes",
+                SyntaxShape::Record(vec![]),
+                "Nodes of the network by name, as {mode, listen, connect, config} records",
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "Time to wait for the links to be established (defaults to 10sec)",
+                None,
+            )
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+    }
+
+    fn description(&self) -> &str {
+        "Open a network of sessions on localhost, replacing the previous one"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each node is opened as a session of the same name. \
+        'mode' defaults to 'peer'; non-client nodes listen on a free TCP port of 127.0.0.1 \
+        unless 'listen' is false; 'connect' is a list of node names; \
+        'config' is a Zenoh configuration record applied before the above (multicast scouting is always disabled). \
+        Returns once every node is connected to the nodes in its 'connect' list, \
+        as a list of {name, zid, mode, locators} records."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let spec = call.req::<Value>(engine_state, stack, 0)?;
+        let timeout = call
+            .timeout(engine_state, stack)?
+            .unwrap_or(Self::DEFAULT_TIMEOUT);
+
+        let mut nodes = Vec::new();
+        for (name, spec) in spec.as_record()?.iter() {
+            nodes.push(Node::parse(engine_state, name, spec, span)?);
+        }
+
+        for node in &nodes {
+            if let Some(target) = node.connect.iter().find(|target| {
+                !nodes
+                    .iter()
+                    .any(|other| &other.name == *target && other.listen)
+            }) {
+                return Err(invalid_node(
+                    format!(
+                        "Node '{}' connects to '{target}', which is not a listening node",
+                        node.name
+                    ),
+                    span,
+                ));
+            }
+        }
+
+        // NOTE: all ports are held until every node got one, so that they are distinct
+        let listeners = nodes
+            .iter()
+            .filter(|node| node.listen)
+            .map(|_| TcpListener::bind("127.0.0.1:0"))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                LabeledError::new("Failed to allocate ports")
+                    .with_label(format!("Could not bind a localhost port: {e}"), span)
+            })?;
+        for (node, listener) in nodes.iter_mut().filter(|node| node.listen).zip(&listeners) {
+            let port = listener.local_addr().map(|addr| addr.port()).map_err(|e| {
+                LabeledError::new("Failed to allocate ports")
+                    .with_label(format!("Could not get the allocated port: {e}"), span)
+            })?;
+            node.endpoint = Some(format!("tcp/127.0.0.1:{port}"));
+        }
+        drop(listeners);
+
+        down(&self.state, span)?;
+        for node in &nodes {
+            let mut sessions = self.state.sessions.write().unwrap();
+            self.state.cancel_session(&node.name);
+            if let Some(sess) = sessions.remove(&node.name) {
+                sess.close().wait().map_err(|e| {
+                    LabeledError::new(format!("Failed to reopen Zenoh session '{}'", node.name))
+                        .with_label(format!("Could not close Zenoh session: {e}"), span)
+                })?;
+            }
+        }
+
+        // NOTE: nodes are opened after the nodes they connect to, as clients don't retry
+        let mut opened = Vec::<(usize, Session)>::new();
+        while opened.len() < nodes.len() {
+            let pending = || (0..nodes.len()).filter(|i| opened.iter().all(|(j, _)| i != j));
+            let next = pending()
+                .find(|&i| {
+                    nodes[i]
+                        .connect
+                        .iter()
+                        .all(|target| opened.iter().any(|(j, _)| &nodes[*j].name == target))
+                })
+                .or_else(|| pending().next())
+                .unwrap();
+
+            match nodes[next]
+                .config(&nodes)
+                .and_then(|config| zenoh::open(config).wait())
+            {
+                Ok(sess) => opened.push((next, sess)),
+                Err(e) => {
+                    close(opened.into_iter().map(|(_, sess)| sess));
+                    return Err(LabeledError::new("Failed to open Zenoh session")
+                        .with_label(
+                            format!("Could not open node '{}': {e}", nodes[next].name),
+                            span,
+                        )
+                        .into());
+                }
+            }
+        }
+        opened.sort_by_key(|(i, _)| *i);
+        let opened = opened.into_iter().map(|(_, sess)| sess).collect::<Vec<_>>();
+
+        let zids = opened.iter().map(|sess| sess.zid()).collect::<Vec<_>>();
+        let links = nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, node)| node.connect.iter().map(move |target| (i, target)))
+            .map(|(i, target)| {
+                let j = nodes.iter().position(|node| &node.name == target).unwrap();
+                (i, j)
+            })
+            .collect::<Vec<_>>();
+
+        let deadline = Instant::now() + timeout;
+        let missing = loop {
+            let missing = links
+                .iter()
+                .filter(|&&(i, j)| !is_linked(&opened[i], zids[j]))
+                .collect::<Vec<_>>();
+
+            if missing.is_empty() || Instant::now() >= deadline {
+                break missing;
+            }
+
+            if let Err(e) = engine_state.signals().check(&span) {
+                close(opened);
+                return Err(e);
+            }
+            thread::sleep(Self::POLL_INTERVAL);
+        };
+
+        if !missing.is_empty() {
+            let missing = missing
+                .iter()
+                .map(|&&(i, j)| format!("{} -> {}", nodes[i].name, nodes[j].name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            close(opened);
+            return Err(ShellError::Generic(
+                GenericError::new(
+                    "Test network timeout",
+                    format!("Links were not established in time: {missing}"),
+                    span,
+                )
+                .with_help("Increase --timeout or check the configuration of the nodes"),
+            ));
+        }
+
+        let mut sessions = self.state.sessions.write().unwrap();
+        let mut testnet = self.state.testnet.write().unwrap();
+        let mut rows = Vec::with_capacity(nodes.len());
+        for (node, sess) in nodes.into_iter().zip(opened) {
+            rows.push(
+                record!(
+                    "name"     => node.name.clone().into_value(span),
+                    "zid"      => sess.zid().to_string().into_value(span),
+                    "mode"     => node.mode.to_string().into_value(span),
+                    "locators" => node.endpoint.into_iter().collect::<Vec<_>>().into_value(span),
+                )
+                .into_value(span),
+            );
+            testnet.push(node.name.clone());
+            sessions.insert(node.name, sess);
+        }
+
+        Ok(PipelineData::Value(Value::list(rows, span), None))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Down {
+    state: State,
+}
+
+impl Down {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Down {
+    fn name(&self) -> &str {
+        "zenoh testnet down"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::Nothing)
+    }
+
+    fn description(&self) -> &str {
+        "Close the sessions opened by 'zenoh testnet up'"
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        down(&self.state, call.head)?;
+        Ok(PipelineData::Value(Value::nothing(call.head), None))
+    }
+}
+
+/// Node of a test network
+struct Node {
+    name: String,
+    mode: WhatAmI,
+    listen: bool,
+    /// Names of the nodes to connect to
+    connect: Vec<String>,
+    config: zenoh::Config,
+    /// Listen endpoint, allocated once all nodes are parsed
+    endpoint: Option<String>,
+}
+
+impl Node {
+    #[allow(clippy::result_large_err)]
+    fn parse(
+        engine_state: &EngineState,
+        name: &str,
+        spec: &Value,
+        span: Span,
+    ) -> Result<Self, ShellError> {
+        let spec = spec.as_record()?;
+
+        if let Some(column) = spec
+            .columns()
+            .find(|column| !matches!(column.as_str(), "mode" | "listen" | "connect" | "config"))
+        {
+            return Err(invalid_node(
+                format!("Unknown column '{column}' of node '{name}'"),
+                span,
+            ));
+        }
+
+        let mode = match spec.get("mode") {
+            Some(mode) => mode.as_str()?.parse::<WhatAmI>().map_err(|_| {
+                invalid_node(
+                    format!("Mode of node '{name}' must be 'router', 'peer' or 'client'"),
+                    span,
+                )
+            })?,
+            None => WhatAmI::Peer,
+        };
+
+        let listen = match spec.get("listen") {
+            Some(listen) => listen.as_bool()?,
+            None => mode != WhatAmI::Client,
+        };
+        if listen && mode == WhatAmI::Client {
+            return Err(invalid_node(
+                format!("Node '{name}' is a client, which can't listen"),
+                span,
+            ));
+        }
+
+        let connect = match spec.get("connect") {
+            Some(connect) => connect
+                .as_list()?
+                .iter()
+                .map(|target| target.as_str().map(str::to_string))
+                .collect::<Result<_, _>>()?,
+            None => vec![],
+        };
+
+        let config = match spec.get("config") {
+            Some(val @ Value::Record { .. }) => {
+                let json_value = conv::value_to_json_value(engine_state, val, span, false)?;
+                zenoh::Config::from_json5(&json_value.to_string()).map_err(|e| {
+                    LabeledError::new("Failed to parse config record").with_label(
+                        format!("Could not parse config record of node '{name}': {e}"),
+                        span,
+                    )
+                })?
+            }
+            Some(_) => {
+                return Err(invalid_node(
+                    format!("Config of node '{name}' must be a record"),
+                    span,
+                ))
+            }
+            None => zenoh::Config::default(),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            mode,
+            listen,
+            connect,
+            config,
+            endpoint: None,
+        })
+    }
+
+    /// Returns the configuration of the node, with its mode and endpoints
+    fn config(&self, nodes: &[Node]) -> zenoh::Result<zenoh::Config> {
+        let endpoints = |endpoints: Vec<&String>| {
+            let endpoints = endpoints
+                .iter()
+                .map(|endpoint| format!("\"{endpoint}\""))
+                .collect::<Vec<_>>();
+            format!("[{}]", endpoints.join(","))
+        };
+
+        let connect = self
+            .connect
+            .iter()
+            .filter_map(|target| nodes.iter().find(|node| &node.name == target))
+            .filter_map(|node| node.endpoint.as_ref())
+            .collect();
+
+        let mut config = self.config.clone();
+        config.insert_json5("mode", &format!("\"{}\"", self.mode))?;
+        config.insert_json5("scouting/multicast/enabled", "false")?;
+        config.insert_json5(
+            "listen/endpoints",
+            &endpoints(self.endpoint.iter().collect()),
+        )?;
+        config.insert_json5("connect/endpoints", &endpoints(connect))?;
+        Ok(config)
+    }
+}
+
+fn invalid_node(msg: String, span: Span) -> ShellError {
+    ShellError::Generic(
+        GenericError::new("Invalid test network", msg, span)
+            .with_help("Nodes are {mode, listen, connect, config} records"),
+    )
+}
+
+/// Whether the session has a transport with the given node
+fn is_linked(sess: &Session, zid: ZenohId) -> bool {
+    let info = sess.info();
+    info.routers_zid()
+        .wait()
+        .chain(info.peers_zid().wait())
+        .any(|other| other == zid)
+}
+
+fn close(sessions: impl IntoIterator<Item = Session>) {
+    for sess in sessions {
+        let _ = sess.close().wait();
+    }
+}
+
+/// Closes the sessions of the current test network
+fn down(state: &State, span: Span) -> Result<(), LabeledError> {
+    let mut sessions = state.sessions.write().unwrap();
+    for name in state.testnet.write().unwrap().drain(..) {
+        state.cancel_session(&name);
+        if let Some(sess) = sessions.remove(&name) {
+            sess.close().wait().map_err(|e| {
+                LabeledError::new(format!("Failed to close Zenoh session '{name}'"))
+                    .with_label(format!("Could not close Zenoh session: {e}"), span)
+            })?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,397 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::collections::{BTreeMap, HashMap};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record,
+    shell_error::generic::GenericError,
+    IntoValue, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{call_ext2::CallExt2, cmd::admin, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Topology {
+    state: State,
+}
+
+impl Topology {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Topology {
+    fn name(&self) -> &str {
+        "zenoh topology"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .remote()
+            .named(
+                "format",
+                SyntaxShape::String,
+                "Output the graph as text instead, either 'dot' (Graphviz) or 'mermaid'",
+                Some('f'),
+            )
+            .input_output_types(vec![
+                (Type::Nothing, Type::record()),
+                (Type::list(Type::record()), Type::record()),
+                (Type::Nothing, Type::String),
+                (Type::list(Type::record()), Type::String),
+            ])
+    }
+
+    fn description(&self) -> &str {
+        "Network topology graph of routers and peers"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Without input, the graph is assembled from the linkstate of a node's admin space. \
+        Otherwise, the input is a list of linkstate records as output by 'zenoh decode transport-msg'. \
+        Returns {nodes, edges} tables; nodes of distinct connected components (i.e. partitions) \
+        have distinct 'component' values."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let format = call.get_flag::<String>(engine_state, stack, "format")?;
+        if let Some(format) = format
+            .as_deref()
+            .filter(|format| !matches!(*format, "dot" | "mermaid"))
+        {
+            return Err(ShellError::Generic(GenericError::new(
+                "Invalid topology format",
+                format!("Format must be 'dot' or 'mermaid', found '{format}'"),
+                span,
+            )));
+        }
+
+        let graph = match input {
+            PipelineData::Empty | PipelineData::Value(Value::Nothing { .. }, ..) => {
+                let remote = call.get_flag::<String>(engine_state, stack, "remote")?;
+                let sess = self
+                    .state
+                    .with_session(&call.session(engine_state, stack)?, |sess| sess.clone())?;
+                let zid = remote.unwrap_or_else(|| sess.zid().to_string());
+                let replies = admin::query(&sess, &format!("@/{zid}/*/linkstate/*"), span)?;
+
+                let mut graph = Graph::default();
+                for reply in replies {
+                    graph.parse_dot(reply.payload.as_str()?).map_err(|msg| {
+                        ShellError::Generic(GenericError::new("Invalid linkstate", msg, span))
+                    })?;
+                    graph.node(&reply.zid).whatami = Some(reply.whatami);
+                }
+                graph
+            }
+            input => Graph::from_linkstates(&input.into_value(span)?.into_list()?, span)?,
+        };
+
+        Ok(PipelineData::Value(
+            match format.as_deref() {
+                Some("dot") => Value::string(graph.to_dot(), span),
+                Some(_) => Value::string(graph.to_mermaid(), span),
+                None => graph.into_value(span),
+            },
+            None,
+        ))
+    }
+}
+
+#[derive(Default)]
+struct Node {
+    zid: String,
+    whatami: Option<String>,
+    locators: Option<Vec<String>>,
+}
+
+/// Undirected weighted graph of Zenoh nodes
+#[derive(Default)]
+struct Graph {
+    nodes: Vec<Node>,
+    /// Edge weights (if configured) indexed by ordered pairs of node indices
+    edges: BTreeMap<(usize, usize), Option<f64>>,
+}
+
+impl Graph {
+    /// Weight of links for which none was configured
+    const DEFAULT_LINK_WEIGHT: f64 = 100.0;
+
+    fn index(&mut self, zid: &str) -> usize {
+        match self.nodes.iter().position(|node| node.zid == zid) {
+            Some(index) => index,
+            None => {
+                self.nodes.push(Node {
+                    zid: zid.to_string(),
+                    ..Default::default()
+                });
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn node(&mut self, zid: &str) -> &mut Node {
+        let index = self.index(zid);
+        &mut self.nodes[index]
+    }
+
+    /// Adds an edge, keeping the largest configured weight if it already exists (as Zenoh does)
+    fn add_edge(&mut self, src: &str, dst: &str, weight: Option<f64>) {
+        let (src, dst) = (self.index(src), self.index(dst));
+        self.edges
+            .entry((src.min(dst), src.max(dst)))
+            .and_modify(|w| {
+                *w = match (*w, weight) {
+                    (Some(w1), Some(w2)) => Some(w1.max(w2)),
+                    (w1, w2) => w1.or(w2),
+                }
+            })
+            .or_insert(weight);
+    }
+
+    fn weights(&self) -> impl Iterator<Item = ((usize, usize), f64)> + '_ {
+        self.edges
+            .iter()
+            .map(|(&edge, weight)| (edge, weight.unwrap_or(Self::DEFAULT_LINK_WEIGHT)))
+    }
+
+    /// Parses a linkstate graph from the admin space, i.e. the DOT output of petgraph
+    fn parse_dot(&mut self, dot: &str) -> Result<(), String> {
+        let mut zids = HashMap::<String, String>::new();
+
+        for line in dot.lines().map(str::trim) {
+            if line.is_empty() || line.ends_with('{') || line == "}" {
+                continue;
+            }
+
+            let invalid = || format!("Invalid DOT statement: '{line}'");
+            let (ids, attrs) = line.split_once('[').ok_or_else(invalid)?;
+            let label = attrs
+                .trim()
+                .strip_prefix("label = \"")
+                .and_then(|attrs| attrs.strip_suffix("\" ]"))
+                .map(|label| label.replace("\\\"", "\"").replace("\\\\", "\\"))
+                .ok_or_else(invalid)?;
+
+            match ids.split_once("--") {
+                Some((src, dst)) => {
+                    let weight = label.parse::<f64>().map_err(|_| invalid())?;
+                    let src = zids.get(src.trim()).cloned().ok_or_else(invalid)?;
+                    let dst = zids.get(dst.trim()).cloned().ok_or_else(invalid)?;
+                    self.add_edge(&src, &dst, Some(weight));
+                }
+                None => {
+                    self.index(&label);
+                    zids.insert(ids.trim().to_string(), label);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Assembles a graph from decoded linkstate records of a single sender
+    #[allow(clippy::result_large_err)]
+    fn from_linkstates(linkstates: &[Value], span: Span) -> Result<Self, ShellError> {
+        let invalid = |msg: String| {
+            ShellError::Generic(GenericError::new("Invalid linkstate", msg, span).with_help(
+                "Linkstates are records of {psid, sn, zid, whatami, locators, links, link_weights}",
+            ))
+        };
+
+        let column = |linkstate: &Value, name: &str| {
+            linkstate
+                .get_data_by_key(name)
+                .filter(|value| !value.is_nothing())
+        };
+
+        // NOTE: linkstates only carry the zid of a node the first time its psid is advertised
+        let mut psids = Vec::with_capacity(linkstates.len());
+        let mut zids = HashMap::new();
+        for linkstate in linkstates {
+            let psid = column(linkstate, "psid")
+                .ok_or_else(|| invalid("Missing 'psid' column".to_string()))?
+                .as_int()?;
+
+            if let Some(zid) = column(linkstate, "zid") {
+                zids.insert(psid, zid.as_str()?.to_string());
+            }
+
+            psids.push(psid);
+        }
+
+        let zid = |psid: i64| {
+            zids.get(&psid)
+                .cloned()
+                .unwrap_or_else(|| format!("#{psid}"))
+        };
+
+        let mut graph = Graph::default();
+        for (linkstate, &psid) in linkstates.iter().zip(&psids) {
+            let src = zid(psid);
+            let node = graph.node(&src);
+
+            if let Some(whatami) = column(linkstate, "whatami") {
+                node.whatami = Some(whatami.as_str()?.to_string());
+            }
+
+            if let Some(locators) = column(linkstate, "locators") {
+                node.locators = Some(
+                    locators
+                        .as_list()?
+                        .iter()
+                        .map(|locator| locator.as_str().map(str::to_string))
+                        .collect::<Result<_, _>>()?,
+                );
+            }
+
+            let links = match column(linkstate, "links") {
+                Some(links) => links.as_list()?.to_vec(),
+                None => vec![],
+            };
+            let weights = match column(linkstate, "link_weights") {
+                Some(weights) => weights.as_list()?.to_vec(),
+                None => vec![],
+            };
+
+            for (i, link) in links.iter().enumerate() {
+                // NOTE: a zero weight means that none was configured
+                let weight = match weights.get(i) {
+                    Some(weight) if weight.as_int()? != 0 => Some(weight.as_int()? as f64),
+                    _ => None,
+                };
+                graph.add_edge(&src, &zid(link.as_int()?), weight);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns the connected component of each node
+    fn components(&self) -> Vec<usize> {
+        let mut components = vec![usize::MAX; self.nodes.len()];
+        let mut next = 0;
+
+        for root in 0..self.nodes.len() {
+            if components[root] != usize::MAX {
+                continue;
+            }
+
+            let mut stack = vec![root];
+            while let Some(node) = stack.pop() {
+                if components[node] != usize::MAX {
+                    continue;
+                }
+                components[node] = next;
+                for &(src, dst) in self.edges.keys() {
+                    if src == node {
+                        stack.push(dst);
+                    } else if dst == node {
+                        stack.push(src);
+                    }
+                }
+            }
+
+            next += 1;
+        }
+
+        components
+    }
+
+    fn into_value(self, span: Span) -> Value {
+        let components = self.components();
+
+        let edges = self
+            .weights()
+            .map(|((src, dst), weight)| {
+                record!(
+                    "src" => self.nodes[src].zid.clone().into_value(span),
+                    "dst" => self.nodes[dst].zid.clone().into_value(span),
+                    "weight" => weight.into_value(span),
+                )
+                .into_value(span)
+            })
+            .collect::<Vec<_>>();
+
+        let nodes = self
+            .nodes
+            .into_iter()
+            .zip(components)
+            .map(|(node, component)| {
+                record!(
+                    "zid" => node.zid.into_value(span),
+                    "whatami" => node.whatami.into_value(span),
+                    "locators" => node.locators.into_value(span),
+                    "component" => (component as i64).into_value(span),
+                )
+                .into_value(span)
+            })
+            .collect::<Vec<_>>();
+
+        record!(
+            "nodes" => nodes.into_value(span),
+            "edges" => edges.into_value(span),
+        )
+        .into_value(span)
+    }
+
+    fn node_label(node: &Node) -> String {
+        match &node.whatami {
+            Some(whatami) => format!("{} ({whatami})", node.zid),
+            None => node.zid.clone(),
+        }
+    }
+
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("graph {\n");
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            dot.push_str(&format!("    {i} [label=\"{}\"]\n", Self::node_label(node)));
+        }
+
+        for ((src, dst), weight) in self.weights() {
+            dot.push_str(&format!("    {src} -- {dst} [label=\"{weight:.0}\"]\n"));
+        }
+
+        dot.push('}');
+        dot
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("graph LR\n");
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            mermaid.push_str(&format!("    n{i}[\"{}\"]\n", Self::node_label(node)));
+        }
+
+        for ((src, dst), weight) in self.weights() {
+            mermaid.push_str(&format!("    n{src} ---|{weight:.0}| n{dst}\n"));
+        }
+
+        mermaid
+    }
+}
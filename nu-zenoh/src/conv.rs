@@ -15,7 +15,26 @@ use nu_protocol::{
     ast, engine::EngineState, record, shell_error::generic::GenericError, IntoValue, Record,
     ShellError, Span, Value,
 };
-use zenoh::{bytes::ZBytes, query::Query};
+use zenoh::{
+    bytes::{Encoding, ZBytes},
+    query::Query,
+};
+
+use crate::serialization::{self, Schema};
+
+/// How payloads are converted to Nu values
+#[derive(Debug, Clone, Default)]
+pub(crate) enum PayloadFormat {
+    /// String if valid UTF-8, otherwise binary
+    #[default]
+    Auto,
+    /// Structured value according to the payload encoding, falling back to [`PayloadFormat::Auto`]
+    Decode,
+    /// Always binary
+    Raw,
+    /// Structured value according to a zenoh-ext serialization schema
+    Schema(Schema),
+}
 
 /// Helper function to convert bytes to Nu value (string if valid UTF-8, otherwise bytes)
 pub(crate) fn bytes_to_value(bytes: &ZBytes, span: nu_protocol::Span) -> Value {
@@ -25,21 +44,152 @@ pub(crate) fn bytes_to_value(bytes: &ZBytes, span: nu_protocol::Span) -> Value {
     }
 }
 
+/// Helper function to convert a payload to a Nu value according to the given format
+pub(crate) fn payload_to_value(
+    bytes: &ZBytes,
+    encoding: &Encoding,
+    format: &PayloadFormat,
+    span: Span,
+) -> Value {
+    match format {
+        PayloadFormat::Auto => bytes_to_value(bytes, span),
+        PayloadFormat::Decode => {
+            decode_payload(bytes, encoding, span).unwrap_or_else(|| bytes_to_value(bytes, span))
+        }
+        PayloadFormat::Raw => Value::binary(bytes.to_bytes(), span),
+        PayloadFormat::Schema(schema) => serialization::deserialize(bytes, schema, span)
+            .unwrap_or_else(|err| Value::error(err, span)),
+    }
+}
+
+/// Returns the MIME type of an encoding (i.e. without its schema)
+pub(crate) fn encoding_mime(encoding: &Encoding) -> String {
+    let encoding = encoding.to_string();
+    match encoding.split_once(';') {
+        Some((mime, _)) => mime.to_string(),
+        None => encoding,
+    }
+}
+
+/// Structured serialization formats
+#[derive(Debug, Clone, Copy)]
+enum SerializationFormat {
+    Json,
+    Cbor,
+    MessagePack,
+    Yaml,
+}
+
+impl SerializationFormat {
+    const MESSAGE_PACK_ENCODING: &str = "application/msgpack";
+
+    fn from_mime(mime: &str) -> Option<Self> {
+        match mime {
+            "application/json" | "text/json" => Some(Self::Json),
+            "application/cbor" => Some(Self::Cbor),
+            Self::MESSAGE_PACK_ENCODING | "application/x-msgpack" => Some(Self::MessagePack),
+            "application/yaml" | "text/yaml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    fn serialize(self, value: &nu_json::Value) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Json => nu_json::to_string_raw(value)
+                .map(String::into_bytes)
+                .map_err(|e| e.to_string()),
+            Self::Cbor => {
+                let mut buf = Vec::new();
+                ciborium::into_writer(value, &mut buf).map_err(|e| e.to_string())?;
+                Ok(buf)
+            }
+            Self::MessagePack => rmp_serde::to_vec(value).map_err(|e| e.to_string()),
+            Self::Yaml => serde_yaml::to_string(value)
+                .map(String::into_bytes)
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    fn deserialize(self, bytes: &[u8]) -> Result<nu_json::Value, String> {
+        match self {
+            Self::Json => std::str::from_utf8(bytes)
+                .map_err(|e| e.to_string())
+                .and_then(|s| nu_json::from_str(s).map_err(|e| e.to_string())),
+            Self::Cbor => ciborium::from_reader(bytes).map_err(|e| e.to_string()),
+            Self::MessagePack => rmp_serde::from_slice(bytes).map_err(|e| e.to_string()),
+            Self::Yaml => serde_yaml::from_slice(bytes).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Decodes a structured payload, returns `None` if the encoding is not supported
+fn decode_payload(bytes: &ZBytes, encoding: &Encoding, span: Span) -> Option<Value> {
+    let mime = encoding_mime(encoding);
+    let format = SerializationFormat::from_mime(&mime)?;
+
+    Some(match format.deserialize(&bytes.to_bytes()) {
+        Ok(json) => nujson_to_value(json, span),
+        Err(err) => Value::error(
+            ShellError::Generic(GenericError::new(
+                "Payload decoding failed",
+                format!("Could not decode '{mime}' payload: {err}"),
+                span,
+            )),
+            span,
+        ),
+    })
+}
+
+/// Helper function to convert a Nu value to a payload
+///
+/// Strings and binaries are used as-is. Any other value is serialized according to the given
+/// encoding (JSON if none is given). Returns the payload along with the encoding it should be
+/// published with.
 #[allow(clippy::result_large_err)]
-pub(crate) fn value_to_bytes(value: &Value) -> Result<ZBytes, nu_protocol::ShellError> {
+pub(crate) fn value_to_bytes(
+    engine_state: &EngineState,
+    value: &Value,
+    encoding: Option<Encoding>,
+    span: Span,
+) -> Result<(ZBytes, Option<Encoding>), ShellError> {
     match value {
-        Value::String { val, .. } => Ok(ZBytes::from(val)),
-        Value::Binary { val, .. } => Ok(ZBytes::from(val)),
-        _ => Err(ShellError::Generic(GenericError::new_internal(
-            "Invalid value type",
-            "Value must be a String or Binary",
-        ))),
+        Value::String { val, .. } => Ok((ZBytes::from(val), encoding)),
+        Value::Binary { val, .. } => Ok((ZBytes::from(val), encoding)),
+        _ => {
+            let encoding = encoding.unwrap_or(Encoding::APPLICATION_JSON);
+            let mime = encoding_mime(&encoding);
+            let format = SerializationFormat::from_mime(&mime).ok_or_else(|| {
+                ShellError::Generic(
+                    GenericError::new(
+                        "Unsupported encoding",
+                        format!("Cannot serialize a {} value as '{mime}'", value.get_type()),
+                        span,
+                    )
+                    .with_help(format!(
+                        "Use one of 'application/json', 'application/cbor', '{}' or 'application/yaml'",
+                        SerializationFormat::MESSAGE_PACK_ENCODING
+                    )),
+                )
+            })?;
+
+            let json = value_to_json_value(engine_state, value, span, false)?;
+            let bytes = format.serialize(&json).map_err(|err| {
+                ShellError::Generic(GenericError::new(
+                    "Payload serialization failed",
+                    format!("Could not serialize value as '{mime}': {err}"),
+                    span,
+                ))
+            })?;
+
+            Ok((ZBytes::from(bytes), Some(encoding)))
+        }
     }
 }
 
 /// Helper function to convert a sample to a Nu record
 pub(crate) fn sample_to_record_value(
     sample: zenoh::sample::Sample,
+    format: &PayloadFormat,
     span: nu_protocol::Span,
 ) -> Value {
     record!(
@@ -52,7 +202,8 @@ pub(crate) fn sample_to_record_value(
         "attachment" => sample.attachment()
             .map(|a| bytes_to_value(a, span))
             .unwrap_or_default(),
-        "payload" => bytes_to_value(sample.payload(), span),
+        "payload" => payload_to_value(sample.payload(), sample.encoding(), format, span),
+        "shm" => sample.payload().as_shm().is_some().into_value(span),
         "timestamp" => sample.timestamp().map(|t| t.to_string_rfc3339_lossy().into_value(span)).unwrap_or_default(),
         "source_info" => sample
             .source_info()
@@ -87,14 +238,20 @@ pub(crate) fn reply_error_to_error_value(
 }
 
 /// Helper function to convert a query to a Nu record
-pub(crate) fn query_to_record_value(query: &Query, span: nu_protocol::Span) -> Value {
+pub(crate) fn query_to_record_value(
+    query: &Query,
+    format: &PayloadFormat,
+    span: nu_protocol::Span,
+) -> Value {
     record!(
         "keyexpr" => query.selector().to_string().into_value(span),
         "parameters" => Record::from_iter(
             query.parameters().iter().map(|(k, v)| (k.to_string(), v.into_value(span))),
         ).into_value(span),
         "encoding" => query.encoding().map(|e| e.to_string().into_value(span)).unwrap_or_default(),
-        "payload" => query.payload().map(|p| bytes_to_value(p, span)).unwrap_or_else(|| Value::nothing(span)),
+        "payload" => query.payload()
+            .map(|p| payload_to_value(p, query.encoding().unwrap_or(&Encoding::default()), format, span))
+            .unwrap_or_else(|| Value::nothing(span)),
         "attachment" => query.attachment().map(|a| bytes_to_value(a, span)).unwrap_or_default(),
     ).into_value(span)
 }
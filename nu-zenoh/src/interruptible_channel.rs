@@ -13,12 +13,50 @@
 //
 use std::time::Duration;
 
-use nu_protocol::Signals;
+use nu_protocol::{shell_error::generic::GenericError, ShellError, Signals, Span, Value};
+
+/// Cancellation signal of a session, triggered when it's closed or re-opened
+///
+/// See [`crate::State::session_signals`].
+#[derive(Debug, Clone)]
+pub(crate) struct SessionSignals {
+    pub(crate) name: String,
+    pub(crate) signals: Signals,
+}
+
+impl SessionSignals {
+    pub(crate) fn interrupted(&self) -> bool {
+        self.signals.interrupted()
+    }
+
+    /// Returns an error if the session was closed, like [`Signals::check`]
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn check(&self, span: Span) -> Result<(), ShellError> {
+        if self.interrupted() {
+            Err(self.error(span))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Error value ending streams tied to a closed session
+    pub(crate) fn error_value(&self, span: Span) -> Value {
+        Value::error(self.error(span), span)
+    }
+
+    fn error(&self, span: Span) -> ShellError {
+        ShellError::Generic(GenericError::new(
+            "Session closed",
+            format!("Session '{}' was closed or re-opened", self.name),
+            span,
+        ))
+    }
+}
 
-// TODO(fuzzypixelz): interupt on session drop?
 pub(crate) struct InterruptibleChannel<T, D = ()> {
     receiver: flume::Receiver<T>,
     signals: Signals,
+    session: Option<SessionSignals>,
     _data: D,
 }
 
@@ -27,6 +65,7 @@ impl<T> InterruptibleChannel<T> {
         InterruptibleChannel {
             receiver,
             signals,
+            session: None,
             _data: (),
         }
     }
@@ -41,9 +80,56 @@ impl<T, D> InterruptibleChannel<T, D> {
         InterruptibleChannel {
             receiver,
             signals,
+            session: None,
             _data: data,
         }
     }
+
+    /// Ends the channel once the given session is closed or re-opened
+    pub(crate) fn with_session(self, session: SessionSignals) -> InterruptibleChannel<T, D> {
+        InterruptibleChannel {
+            session: Some(session),
+            ..self
+        }
+    }
+
+    fn session_closed(&self) -> Option<&SessionSignals> {
+        self.session
+            .as_ref()
+            .filter(|session| session.interrupted())
+    }
+}
+
+impl<T, D> InterruptibleChannel<T, D>
+where
+    T: Send + 'static,
+    D: Send + 'static,
+{
+    /// Maps items to Nu values, ending with an error value if the session was closed
+    pub(crate) fn into_values<F>(
+        mut self,
+        span: Span,
+        mut f: F,
+    ) -> impl Iterator<Item = Value> + Send + 'static
+    where
+        F: FnMut(T) -> Value + Send + 'static,
+    {
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            match self.next() {
+                Some(item) => Some(f(item)),
+                None => {
+                    done = true;
+                    self.session_closed()
+                        .map(|session| session.error_value(span))
+                }
+            }
+        })
+    }
 }
 
 impl<T, D> InterruptibleChannel<T, D> {
@@ -56,7 +142,7 @@ impl<T, D> Iterator for InterruptibleChannel<T, D> {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if self.signals.interrupted() {
+            if self.signals.interrupted() || self.session_closed().is_some() {
                 return None;
             } else {
                 match self.receiver.recv_timeout(Self::TIMEOUT) {
@@ -13,21 +13,36 @@
 //
 use std::{
     collections::HashMap,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use nu_protocol::{
     engine::{EngineState, StateWorkingSet},
-    LabeledError,
+    LabeledError, Signals, Span, Value,
 };
 use zenoh::{internal::runtime::Runtime, Session, Wait};
 
+use crate::{
+    cmd::{
+        entity::{Entity, EntityValue},
+        shm::ShmPool,
+    },
+    interruptible_channel::SessionSignals,
+};
+
 mod call_ext2;
+mod closure;
 mod cmd;
 mod conv;
 mod interruptible_channel;
+mod serialization;
 mod signature_ext;
 
+pub use crate::closure::{with_closure_evaluator, ClosureEvaluator};
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub experimental_options: bool,
@@ -52,7 +67,14 @@ pub fn add_zenoh_context(mut engine_state: EngineState, options: Config) -> Engi
             working_set.add_decl(Box::new(cmd::runtime::open::Open::new(state.clone())));
             working_set.add_decl(Box::new(cmd::runtime::close::Close::new(state.clone())));
 
+            working_set.add_decl(Box::new(cmd::router::start::Start::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::router::plugins::Plugins::new(state.clone())));
+
+            working_set.add_decl(Box::new(cmd::testnet::Up::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::testnet::Down::new(state.clone())));
+
             working_set.add_decl(Box::new(cmd::pub_::Pub::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::pub_::SendValues::new(state.clone())));
             working_set.add_decl(Box::new(cmd::querier::Querier::new(state.clone())));
 
             working_set.add_decl(Box::new(cmd::liveliness::declare_token::DeclareToken::new(
@@ -64,32 +86,82 @@ pub fn add_zenoh_context(mut engine_state: EngineState, options: Config) -> Engi
             working_set.add_decl(Box::new(cmd::liveliness::get::Get::new(state.clone())));
             working_set.add_decl(Box::new(cmd::liveliness::sub::Sub::new(state.clone())));
 
+            working_set.add_decl(Box::new(cmd::storage::Declare::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::storage::Dump::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::storage::Clear::new(state.clone())));
+
+            working_set.add_decl(Box::new(cmd::record::Record::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::record::Replay::new(state.clone())));
+
+            working_set.add_decl(Box::new(cmd::perf::ping::Ping::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::perf::pong::Pong::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::perf::pub_thr::PubThr::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::perf::sub_thr::SubThr::new(state.clone())));
+
             working_set.add_decl(Box::new(cmd::pub_::MatchingListener::new(state.clone())));
             working_set.add_decl(Box::new(cmd::querier::MatchingListener::new(state.clone())));
 
+            working_set.add_decl(Box::new(cmd::shm::provider_create::ProviderCreate::new(
+                state.clone(),
+            )));
+
             working_set.add_decl(Box::new(cmd::decode::transport_msg::TransportMsg));
             working_set.add_decl(Box::new(cmd::decode::scouting_msg::ScoutingMsg));
+            working_set.add_decl(Box::new(cmd::decode::transport_batch::TransportBatch));
+            working_set.add_decl(Box::new(cmd::encode::transport_msg::TransportMsg));
+            working_set.add_decl(Box::new(cmd::encode::scouting_msg::ScoutingMsg));
+
+            working_set.add_decl(Box::new(cmd::pcap::read::Read));
+            working_set.add_decl(Box::new(cmd::sniff::Sniff));
         }
 
         working_set.add_decl(Box::new(cmd::put::Put::new(state.clone())));
         working_set.add_decl(Box::new(cmd::delete::Delete::new(state.clone())));
         working_set.add_decl(Box::new(cmd::get::Get::new(state.clone())));
         working_set.add_decl(Box::new(cmd::sub::Sub::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::sub::Recv::new(state.clone())));
         working_set.add_decl(Box::new(cmd::zid::Zid::new(state.clone())));
 
         working_set.add_decl(Box::new(cmd::session::list::List::new(state.clone())));
         working_set.add_decl(Box::new(cmd::session::open::Open::new(state.clone())));
         working_set.add_decl(Box::new(cmd::session::close::Close::new(state.clone())));
 
+        working_set.add_decl(Box::new(cmd::entity::list::List::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::entity::undeclare::Undeclare::new(
+            state.clone(),
+        )));
+
         working_set.add_decl(Box::new(cmd::log_path::LogPath::new(state.clone())));
         working_set.add_decl(Box::new(cmd::queryable::Queryable::new(state.clone())));
         working_set.add_decl(Box::new(cmd::scout::Scout::new(state.clone())));
         working_set.add_decl(Box::new(cmd::info::Info::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::info::Transports::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::info::Links::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::info::Events::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::stats::Stats::new(state.clone())));
+
+        working_set.add_decl(Box::new(cmd::admin::routers::Routers::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::admin::sessions::Sessions::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::admin::resources::Resources::subscribers(
+            state.clone(),
+        )));
+        working_set.add_decl(Box::new(cmd::admin::resources::Resources::queryables(
+            state.clone(),
+        )));
+        working_set.add_decl(Box::new(cmd::admin::resources::Resources::tokens(
+            state.clone(),
+        )));
+        working_set.add_decl(Box::new(cmd::admin::config::Config::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::topology::Topology::new(state.clone())));
+
         working_set.add_decl(Box::new(cmd::config::Config::new(state)));
 
         working_set.add_decl(Box::new(cmd::keyexpr::Includes));
         working_set.add_decl(Box::new(cmd::keyexpr::Intersects));
 
+        working_set.add_decl(Box::new(cmd::serialization::Serialize));
+        working_set.add_decl(Box::new(cmd::serialization::Deserialize));
+
         working_set.render()
     };
 
@@ -104,7 +176,13 @@ pub fn add_zenoh_context(mut engine_state: EngineState, options: Config) -> Engi
 struct State {
     options: Config,
     sessions: Arc<RwLock<HashMap<String, Session>>>,
+    session_signals: Arc<RwLock<HashMap<String, Signals>>>,
     runtimes: Arc<RwLock<HashMap<String, Runtime>>>,
+    shm_pools: Arc<RwLock<HashMap<String, Arc<ShmPool>>>>,
+    entities: Arc<RwLock<HashMap<u64, Entity>>>,
+    next_entity_id: Arc<AtomicU64>,
+    /// Sessions of the nodes opened by 'zenoh testnet up'
+    testnet: Arc<RwLock<Vec<String>>>,
 }
 
 impl State {
@@ -122,7 +200,12 @@ impl State {
         Self {
             options,
             sessions: Arc::new(RwLock::new(sessions)),
+            session_signals: Arc::new(RwLock::new(HashMap::new())),
             runtimes: Arc::new(RwLock::new(HashMap::new())),
+            shm_pools: Arc::new(RwLock::new(HashMap::new())),
+            entities: Arc::new(RwLock::new(HashMap::new())),
+            next_entity_id: Arc::new(AtomicU64::new(0)),
+            testnet: Arc::new(RwLock::new(Vec::new())),
         }
     }
 }
@@ -138,4 +221,80 @@ impl State {
             .ok_or_else(|| LabeledError::new(format!("session '{name}' not found")))?;
         Ok(f(session))
     }
+
+    /// Returns the cancellation signal of the current incarnation of a session
+    pub(crate) fn session_signals(&self, name: &str) -> SessionSignals {
+        let signals = self
+            .session_signals
+            .write()
+            .unwrap()
+            .entry(name.to_string())
+            .or_insert_with(|| Signals::new(Arc::new(AtomicBool::new(false))))
+            .clone();
+
+        SessionSignals {
+            name: name.to_string(),
+            signals,
+        }
+    }
+
+    /// Ends all streams, undeclares all background entities and drops the shared-memory provider
+    /// tied to a session; this should be called before closing or replacing it
+    pub(crate) fn cancel_session(&self, name: &str) {
+        if let Some(signals) = self.session_signals.write().unwrap().remove(name) {
+            signals.trigger();
+        }
+        self.shm_pools.write().unwrap().remove(name);
+
+        let entities = self
+            .entities
+            .write()
+            .unwrap()
+            .extract_if(|_, entity| entity.session == name)
+            .collect::<Vec<_>>();
+        // NOTE: the session is about to be closed, which undeclares whatever failed here anyway
+        for (_, entity) in entities {
+            let _ = entity.undeclare();
+        }
+    }
+
+    /// Returns the shared-memory provider of a session
+    pub(crate) fn shm_pool(&self, name: &str) -> Result<Arc<ShmPool>, LabeledError> {
+        self.shm_pools
+            .read()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| {
+                LabeledError::new(format!("no shared-memory provider for session '{name}'"))
+                    .with_help("Create one with 'zenoh shm provider create'")
+            })
+    }
+
+    /// Stores a background entity and returns its handle
+    pub(crate) fn insert_entity(&self, entity: Entity, span: Span) -> Value {
+        let id = self.next_entity_id.fetch_add(1, Ordering::Relaxed);
+        let handle = EntityValue::handle(id, &entity, span);
+        self.entities.write().unwrap().insert(id, entity);
+        handle
+    }
+
+    pub(crate) fn with_entity<F, T>(&self, id: u64, f: F) -> Result<T, LabeledError>
+    where
+        F: FnOnce(&Entity) -> T,
+    {
+        let entities = self.entities.read().unwrap();
+        let entity = entities
+            .get(&id)
+            .ok_or_else(|| LabeledError::new(format!("entity '{id}' not found")))?;
+        Ok(f(entity))
+    }
+
+    pub(crate) fn remove_entity(&self, id: u64) -> Result<Entity, LabeledError> {
+        self.entities
+            .write()
+            .unwrap()
+            .remove(&id)
+            .ok_or_else(|| LabeledError::new(format!("entity '{id}' not found")))
+    }
 }
@@ -0,0 +1,423 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{fmt, iter::Peekable, str::Chars};
+
+use nu_protocol::{shell_error::generic::GenericError, Record, ShellError, Span, Value};
+use zenoh::bytes::ZBytes;
+use zenoh_ext::{VarInt, ZDeserializer, ZSerializer};
+
+/// Type schema of a payload in the zenoh-ext serialization format
+///
+/// The textual syntax is (whitespace is ignored):
+///
+/// ```text
+/// schema := 'bool' | 'u8' | 'u16' | 'u32' | 'u64' | 'i8' | 'i16' | 'i32' | 'i64'
+///         | 'f32' | 'f64' | 'string' | 'bytes'
+///         | 'list<' schema '>'
+///         | 'map<' schema ',' schema '>'
+///         | '(' schema (',' schema)* ')'
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Schema {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    String,
+    Bytes,
+    List(Box<Schema>),
+    Map(Box<Schema>, Box<Schema>),
+    Tuple(Vec<Schema>),
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Schema::Bool => f.write_str("bool"),
+            Schema::U8 => f.write_str("u8"),
+            Schema::U16 => f.write_str("u16"),
+            Schema::U32 => f.write_str("u32"),
+            Schema::U64 => f.write_str("u64"),
+            Schema::I8 => f.write_str("i8"),
+            Schema::I16 => f.write_str("i16"),
+            Schema::I32 => f.write_str("i32"),
+            Schema::I64 => f.write_str("i64"),
+            Schema::F32 => f.write_str("f32"),
+            Schema::F64 => f.write_str("f64"),
+            Schema::String => f.write_str("string"),
+            Schema::Bytes => f.write_str("bytes"),
+            Schema::List(item) => write!(f, "list<{item}>"),
+            Schema::Map(key, value) => write!(f, "map<{key}, {value}>"),
+            Schema::Tuple(items) => {
+                f.write_str("(")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl Schema {
+    /// Parses a schema from its textual representation
+    pub(crate) fn parse(input: &str) -> Result<Schema, String> {
+        let mut chars = input.chars().peekable();
+        let schema = Self::parse_schema(&mut chars)?;
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            None => Ok(schema),
+            Some(c) => Err(format!("unexpected '{c}' after schema '{schema}'")),
+        }
+    }
+
+    fn parse_schema(chars: &mut Peekable<Chars>) -> Result<Schema, String> {
+        skip_whitespace(chars);
+
+        if chars.peek() == Some(&'(') {
+            chars.next();
+            let mut items = vec![Self::parse_schema(chars)?];
+            loop {
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => items.push(Self::parse_schema(chars)?),
+                    Some(')') => return Ok(Schema::Tuple(items)),
+                    Some(c) => return Err(format!("expected ',' or ')' in tuple, found '{c}'")),
+                    None => return Err("unterminated tuple".to_string()),
+                }
+            }
+        }
+
+        let mut ident = String::new();
+        while let Some(c) = chars.peek().filter(|c| c.is_ascii_alphanumeric()) {
+            ident.push(*c);
+            chars.next();
+        }
+
+        match ident.as_str() {
+            "bool" => Ok(Schema::Bool),
+            "u8" => Ok(Schema::U8),
+            "u16" => Ok(Schema::U16),
+            "u32" => Ok(Schema::U32),
+            "u64" => Ok(Schema::U64),
+            "i8" => Ok(Schema::I8),
+            "i16" => Ok(Schema::I16),
+            "i32" => Ok(Schema::I32),
+            "i64" => Ok(Schema::I64),
+            "f32" => Ok(Schema::F32),
+            "f64" => Ok(Schema::F64),
+            "string" => Ok(Schema::String),
+            "bytes" => Ok(Schema::Bytes),
+            "list" => {
+                expect(chars, '<')?;
+                let item = Self::parse_schema(chars)?;
+                expect(chars, '>')?;
+                Ok(Schema::List(Box::new(item)))
+            }
+            "map" => {
+                expect(chars, '<')?;
+                let key = Self::parse_schema(chars)?;
+                expect(chars, ',')?;
+                let value = Self::parse_schema(chars)?;
+                expect(chars, '>')?;
+                Ok(Schema::Map(Box::new(key), Box::new(value)))
+            }
+            "" => match chars.next() {
+                Some(c) => Err(format!("expected a type, found '{c}'")),
+                None => Err("expected a type, found end of schema".to_string()),
+            },
+            _ => Err(format!("unknown type '{ident}'")),
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Result<(), String> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        Some(c) => Err(format!("expected '{expected}', found '{c}'")),
+        None => Err(format!("expected '{expected}', found end of schema")),
+    }
+}
+
+/// Serializes a Nu value in the zenoh-ext format according to the given schema
+#[allow(clippy::result_large_err)]
+pub(crate) fn serialize(value: &Value, schema: &Schema, span: Span) -> Result<ZBytes, ShellError> {
+    let mut serializer = ZSerializer::new();
+    serialize_value(&mut serializer, value, schema, span)?;
+    Ok(serializer.finish())
+}
+
+/// Deserializes a Nu value from the zenoh-ext format according to the given schema
+#[allow(clippy::result_large_err)]
+pub(crate) fn deserialize(
+    bytes: &ZBytes,
+    schema: &Schema,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let mut deserializer = ZDeserializer::new(bytes);
+    let value = deserialize_value(&mut deserializer, schema, bytes.len(), span)?;
+
+    if deserializer.done() {
+        Ok(value)
+    } else {
+        Err(ShellError::Generic(GenericError::new(
+            "Deserialization failed",
+            format!("Payload has trailing bytes after schema '{schema}'"),
+            span,
+        )))
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn serialize_value(
+    serializer: &mut ZSerializer,
+    value: &Value,
+    schema: &Schema,
+    span: Span,
+) -> Result<(), ShellError> {
+    /// Helper function to narrow an integer to the schema type
+    #[allow(clippy::result_large_err)]
+    fn int<T: TryFrom<i64>>(value: &Value, schema: &Schema, span: Span) -> Result<T, ShellError> {
+        let int = value.as_int()?;
+        T::try_from(int).map_err(|_| {
+            ShellError::Generic(GenericError::new(
+                "Serialization failed",
+                format!("{int} is out of range for {schema}"),
+                span,
+            ))
+        })
+    }
+
+    /// Helper function to get a float, accepting integers as well
+    #[allow(clippy::result_large_err)]
+    fn float(value: &Value) -> Result<f64, ShellError> {
+        match value {
+            Value::Int { val, .. } => Ok(*val as f64),
+            _ => value.as_float(),
+        }
+    }
+
+    match schema {
+        Schema::Bool => serializer.serialize(value.as_bool()?),
+        Schema::U8 => serializer.serialize(int::<u8>(value, schema, span)?),
+        Schema::U16 => serializer.serialize(int::<u16>(value, schema, span)?),
+        Schema::U32 => serializer.serialize(int::<u32>(value, schema, span)?),
+        Schema::U64 => serializer.serialize(int::<u64>(value, schema, span)?),
+        Schema::I8 => serializer.serialize(int::<i8>(value, schema, span)?),
+        Schema::I16 => serializer.serialize(int::<i16>(value, schema, span)?),
+        Schema::I32 => serializer.serialize(int::<i32>(value, schema, span)?),
+        Schema::I64 => serializer.serialize(value.as_int()?),
+        Schema::F32 => serializer.serialize(float(value)? as f32),
+        Schema::F64 => serializer.serialize(float(value)?),
+        Schema::String => serializer.serialize(value.as_str()?),
+        Schema::Bytes => {
+            let bytes = value.as_binary()?;
+            serializer.serialize(VarInt(bytes.len()));
+            for byte in bytes {
+                serializer.serialize(*byte);
+            }
+        }
+        Schema::List(item) => {
+            let list = value.as_list()?;
+            serializer.serialize(VarInt(list.len()));
+            for value in list {
+                serialize_value(serializer, value, item, span)?;
+            }
+        }
+        Schema::Map(key, item) => {
+            let record = value.as_record()?;
+            serializer.serialize(VarInt(record.len()));
+            for (k, v) in record.iter() {
+                let k = match key.as_ref() {
+                    Schema::String => Value::string(k, span),
+                    _ => Value::int(
+                        k.parse::<i64>().map_err(|_| {
+                            ShellError::Generic(GenericError::new(
+                                "Serialization failed",
+                                format!("Record key '{k}' is not a valid {key}"),
+                                span,
+                            ))
+                        })?,
+                        span,
+                    ),
+                };
+                serialize_value(serializer, &k, key, span)?;
+                serialize_value(serializer, v, item, span)?;
+            }
+        }
+        Schema::Tuple(items) => {
+            let list = value.as_list()?;
+            if list.len() != items.len() {
+                return Err(ShellError::Generic(GenericError::new(
+                    "Serialization failed",
+                    format!(
+                        "Expected a list of {} items for {schema}, found {}",
+                        items.len(),
+                        list.len()
+                    ),
+                    span,
+                )));
+            }
+            for (value, item) in list.iter().zip(items) {
+                serialize_value(serializer, value, item, span)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::result_large_err)]
+fn deserialize_value(
+    deserializer: &mut ZDeserializer,
+    schema: &Schema,
+    size: usize,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let error = |_| {
+        ShellError::Generic(GenericError::new(
+            "Deserialization failed",
+            format!("Could not deserialize {schema}"),
+            span,
+        ))
+    };
+
+    // NOTE: every item takes at least one byte, so lengths larger than the payload size are
+    // invalid; checking them prevents huge allocations from untrusted payloads
+    let length = |deserializer: &mut ZDeserializer| {
+        let len = deserializer
+            .deserialize::<VarInt<usize>>()
+            .map_err(error)?
+            .0;
+        if len > size {
+            return Err(ShellError::Generic(GenericError::new(
+                "Deserialization failed",
+                format!("Length {len} of {schema} exceeds the payload size ({size} bytes)"),
+                span,
+            )));
+        }
+        Ok(len)
+    };
+    // NOTE: strings are read as bytes rather than with `deserialize::<String>`, which
+    // preallocates the unchecked length
+    let read_bytes = |deserializer: &mut ZDeserializer| {
+        let len = length(deserializer)?;
+        let mut bytes = Vec::with_capacity(len);
+        for _ in 0..len {
+            bytes.push(deserializer.deserialize::<u8>().map_err(error)?);
+        }
+        Ok::<_, ShellError>(bytes)
+    };
+
+    Ok(match schema {
+        Schema::Bool => Value::bool(deserializer.deserialize::<bool>().map_err(error)?, span),
+        Schema::U8 => Value::int(
+            deserializer.deserialize::<u8>().map_err(error)? as i64,
+            span,
+        ),
+        Schema::U16 => Value::int(
+            deserializer.deserialize::<u16>().map_err(error)? as i64,
+            span,
+        ),
+        Schema::U32 => Value::int(
+            deserializer.deserialize::<u32>().map_err(error)? as i64,
+            span,
+        ),
+        Schema::U64 => {
+            let int = deserializer.deserialize::<u64>().map_err(error)?;
+            Value::int(
+                i64::try_from(int).map_err(|_| ShellError::CantConvert {
+                    to_type: "i64 sized integer".into(),
+                    from_type: "value larger than i64".into(),
+                    span,
+                    help: None,
+                })?,
+                span,
+            )
+        }
+        Schema::I8 => Value::int(
+            deserializer.deserialize::<i8>().map_err(error)? as i64,
+            span,
+        ),
+        Schema::I16 => Value::int(
+            deserializer.deserialize::<i16>().map_err(error)? as i64,
+            span,
+        ),
+        Schema::I32 => Value::int(
+            deserializer.deserialize::<i32>().map_err(error)? as i64,
+            span,
+        ),
+        Schema::I64 => Value::int(deserializer.deserialize::<i64>().map_err(error)?, span),
+        Schema::F32 => Value::float(
+            deserializer.deserialize::<f32>().map_err(error)? as f64,
+            span,
+        ),
+        Schema::F64 => Value::float(deserializer.deserialize::<f64>().map_err(error)?, span),
+        Schema::String => {
+            let bytes = read_bytes(deserializer)?;
+            let string = String::from_utf8(bytes).map_err(|_| {
+                ShellError::Generic(GenericError::new(
+                    "Deserialization failed",
+                    "String is not valid UTF-8",
+                    span,
+                ))
+            })?;
+            Value::string(string, span)
+        }
+        Schema::Bytes => Value::binary(read_bytes(deserializer)?, span),
+        Schema::List(item) => {
+            let len = length(deserializer)?;
+            let mut list = Vec::with_capacity(len);
+            for _ in 0..len {
+                list.push(deserialize_value(deserializer, item, size, span)?);
+            }
+            Value::list(list, span)
+        }
+        Schema::Map(key, item) => {
+            let len = length(deserializer)?;
+            let mut record = Record::with_capacity(len);
+            for _ in 0..len {
+                let k = match deserialize_value(deserializer, key, size, span)? {
+                    Value::String { val, .. } => val,
+                    k => k.to_expanded_string("", &Default::default()),
+                };
+                let v = deserialize_value(deserializer, item, size, span)?;
+                record.push(k, v);
+            }
+            Value::record(record, span)
+        }
+        Schema::Tuple(items) => Value::list(
+            items
+                .iter()
+                .map(|item| deserialize_value(deserializer, item, size, span))
+                .collect::<Result<Vec<_>, _>>()?,
+            span,
+        ),
+    })
+}
@@ -43,6 +43,14 @@ pub(crate) trait SignatureExt: Sized {
     fn target(self) -> Self;
 
     fn consolidation(self) -> Self;
+
+    fn payload_format(self) -> Self;
+
+    fn background(self) -> Self;
+
+    fn shm(self) -> Self;
+
+    fn remote(self) -> Self;
 }
 
 impl SignatureExt for Signature {
@@ -173,4 +181,44 @@ impl SignatureExt for Signature {
             None,
         )
     }
+
+    fn payload_format(self) -> Self {
+        self.switch(
+            "decode",
+            "Decode payloads according to their encoding (JSON, CBOR, MessagePack and YAML are supported)",
+            None,
+        )
+        .switch("raw", "Return payloads as binary", None)
+        .named(
+            "schema",
+            SyntaxShape::String,
+            "Deserialize payloads from the zenoh-ext serialization format with the given type schema (e.g. '(u32, string, list<f64>)')",
+            None,
+        )
+    }
+
+    fn background(self) -> Self {
+        self.switch(
+            "background",
+            "Declare in the background and return a handle (see 'zenoh entity')",
+            None,
+        )
+    }
+
+    fn shm(self) -> Self {
+        self.switch(
+            "shm",
+            "Allocate payloads from the session shared-memory provider (see 'zenoh shm provider create')",
+            None,
+        )
+    }
+
+    fn remote(self) -> Self {
+        self.named(
+            "remote",
+            SyntaxShape::String,
+            "Zenoh ID of the node to query (defaults to the session's)",
+            Some('r'),
+        )
+    }
 }
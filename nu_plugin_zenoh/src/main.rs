@@ -0,0 +1,304 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::sync::{Arc, OnceLock};
+
+use nu_plugin::{
+    serve_plugin, EngineInterface, EvaluatedCall, MsgPackSerializer, Plugin, PluginCommand,
+};
+use nu_protocol::{
+    engine::{Closure, EngineState, Stack},
+    ir, Category, LabeledError, PipelineData, ShellError, Signature, Spanned, Type, Value,
+};
+
+fn main() {
+    #[cfg(unix)]
+    exit_with_parent();
+    serve_plugin(&ZenohPlugin::default(), MsgPackSerializer)
+}
+
+/// Exits once the engine process is gone
+///
+/// Streams waiting for Zenoh data (e.g. of an abandoned queryable) block their call thread, which
+/// would otherwise keep the plugin (and its sessions) alive after Nushell exits.
+#[cfg(unix)]
+fn exit_with_parent() {
+    use std::{os::unix::process::parent_id, thread, time::Duration};
+
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    let parent = parent_id();
+    thread::spawn(move || loop {
+        thread::sleep(POLL_INTERVAL);
+        if parent_id() != parent {
+            std::process::exit(0);
+        }
+    });
+}
+
+#[derive(Default)]
+struct ZenohPlugin {
+    /// Engine owning the commands and their state (e.g. sessions), created on the first call
+    engine_state: OnceLock<EngineState>,
+}
+
+impl ZenohPlugin {
+    /// Returns the plugin's engine, creating it with the plugin configuration if needed
+    ///
+    /// The configuration is read from `$env.config.plugins.zenoh`, which may be a record with a
+    /// `no_default_session` boolean.
+    #[allow(clippy::result_large_err)]
+    fn engine_state(&self, engine: &EngineInterface) -> Result<&EngineState, ShellError> {
+        if let Some(engine_state) = self.engine_state.get() {
+            return Ok(engine_state);
+        }
+
+        let no_default_session = match engine.get_plugin_config()? {
+            Some(Value::Record { val, .. }) => match val.get("no_default_session") {
+                Some(value) => value.as_bool()?,
+                None => false,
+            },
+            _ => false,
+        };
+
+        // NOTE: sessions and entities must outlive the calls, which requires the plugin to stay
+        // alive
+        engine.set_gc_disabled(true)?;
+
+        Ok(self.engine_state.get_or_init(|| {
+            zenoh_context(nu_zenoh::Config {
+                experimental_options: true,
+                no_default_session,
+                include_paths: vec![],
+            })
+        }))
+    }
+}
+
+impl Plugin for ZenohPlugin {
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").into()
+    }
+
+    fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
+        let engine_state = zenoh_context(nu_zenoh::Config {
+            experimental_options: true,
+            no_default_session: true,
+            include_paths: vec![],
+        });
+
+        let mut commands: Vec<Box<dyn PluginCommand<Plugin = Self>>> = vec![Box::new(Zenoh)];
+        for (name, decl_id) in engine_state.get_decls_sorted(false) {
+            let decl = engine_state.get_decl(decl_id);
+            let command = ZenohCommand {
+                name: String::from_utf8_lossy(&name).into_owned(),
+                decl_name: decl.name().to_string(),
+                signature: decl.signature(),
+                description: decl.description().to_string(),
+                extra_description: decl.extra_description().to_string(),
+            };
+
+            if command.decl_name == "zenoh session open" {
+                commands.push(Box::new(command.alias("zenoh open")));
+            }
+            commands.push(Box::new(command));
+        }
+
+        commands
+    }
+}
+
+fn zenoh_context(options: nu_zenoh::Config) -> EngineState {
+    nu_zenoh::add_zenoh_context(EngineState::new(), options)
+}
+
+/// A `zenoh *` command, run by the plugin's engine
+struct ZenohCommand {
+    name: String,
+    decl_name: String,
+    signature: Signature,
+    description: String,
+    extra_description: String,
+}
+
+impl ZenohCommand {
+    fn alias(&self, name: &str) -> Self {
+        let mut signature = self.signature.clone();
+        signature.name = name.to_string();
+        Self {
+            name: name.to_string(),
+            decl_name: self.decl_name.clone(),
+            signature,
+            description: self.description.clone(),
+            extra_description: self.extra_description.clone(),
+        }
+    }
+}
+
+impl PluginCommand for ZenohCommand {
+    type Plugin = ZenohPlugin;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> Signature {
+        self.signature.clone()
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn extra_description(&self) -> &str {
+        &self.extra_description
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn run(
+        &self,
+        plugin: &ZenohPlugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let mut engine_state = plugin.engine_state(engine)?.clone();
+        engine_state.set_signals(engine.signals().clone());
+
+        let decl_id = engine_state
+            .find_decl(self.decl_name.as_bytes(), &[])
+            .ok_or_else(|| LabeledError::new(format!("command '{}' not found", self.decl_name)))?;
+        let decl = engine_state.get_decl(decl_id);
+
+        // NOTE: relative paths (e.g. of configuration files) are resolved against $env.PWD
+        let mut stack = Stack::new();
+        stack.add_env_var(
+            "PWD".to_string(),
+            Value::string(engine.get_current_dir()?, call.head),
+        );
+        let mut builder = ir::Call::build(decl_id, call.head);
+        for value in &call.positional {
+            builder.add_positional(&mut stack, value.span(), value.clone());
+        }
+        for (name, value) in &call.named {
+            let short = self
+                .signature
+                .get_long_flag(&name.item)
+                .and_then(|flag| flag.short)
+                .map(String::from)
+                .unwrap_or_default();
+            match value {
+                Some(value) => {
+                    builder.add_named(&mut stack, &name.item, short, name.span, value.clone())
+                }
+                None => builder.add_flag(&mut stack, &name.item, short, name.span),
+            };
+        }
+
+        let evaluator = Arc::new(EngineEvaluator(engine.clone()));
+        let output = nu_zenoh::with_closure_evaluator(evaluator, || {
+            builder.with(&mut stack, |stack, call| {
+                decl.run(&engine_state, stack, call, input)
+            })
+        })?;
+
+        Ok(to_base_pipeline_data(output))
+    }
+}
+
+/// Root `zenoh` command, which only shows its help
+struct Zenoh;
+
+impl PluginCommand for Zenoh {
+    type Plugin = ZenohPlugin;
+
+    fn name(&self) -> &str {
+        "zenoh"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::String)
+            .category(Category::Custom("Zenoh".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Various commands to interact with Zenoh systems."
+    }
+
+    fn extra_description(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        _plugin: &ZenohPlugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        Ok(PipelineData::Value(
+            Value::string(engine.get_help()?, call.head),
+            None,
+        ))
+    }
+}
+
+/// Evaluates closure arguments in the engine which called the plugin
+struct EngineEvaluator(EngineInterface);
+
+impl nu_zenoh::ClosureEvaluator for EngineEvaluator {
+    fn run_with_value(
+        &self,
+        closure: &Spanned<Closure>,
+        value: Value,
+    ) -> Result<PipelineData, ShellError> {
+        self.0.eval_closure_with_stream(
+            closure,
+            vec![value.clone()],
+            PipelineData::Value(value, None),
+            true,
+            false,
+        )
+    }
+}
+
+/// Converts custom values (e.g. entity handles) to their base values, which unlike the custom
+/// values themselves can be sent to the engine
+fn to_base_pipeline_data(data: PipelineData) -> PipelineData {
+    match data {
+        PipelineData::Value(value, metadata) => PipelineData::Value(to_base_value(value), metadata),
+        PipelineData::ListStream(stream, metadata) => {
+            PipelineData::ListStream(stream.map(to_base_value), metadata)
+        }
+        data => data,
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn to_base_value(mut value: Value) -> Value {
+    let result = value.recurse_mut(&mut |value| {
+        if let Value::Custom {
+            val, internal_span, ..
+        } = value
+        {
+            *value = val.to_base_value(*internal_span)?;
+        }
+        Ok::<_, ShellError>(())
+    });
+
+    match result {
+        Ok(()) => value,
+        Err(err) => Value::error(err, value.span()),
+    }
+}
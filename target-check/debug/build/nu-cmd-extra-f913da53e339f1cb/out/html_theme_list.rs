@@ -0,0 +1 @@
+impl HtmlTheme { pub fn list () -> & 'static [HtmlTheme] { & [HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("3024 Day") , black : :: std :: borrow :: Cow :: Borrowed ("#090300") , red : :: std :: borrow :: Cow :: Borrowed ("#db2d20") , green : :: std :: borrow :: Cow :: Borrowed ("#01a252") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fded02") , blue : :: std :: borrow :: Cow :: Borrowed ("#01a0e4") , purple : :: std :: borrow :: Cow :: Borrowed ("#a16a94") , cyan : :: std :: borrow :: Cow :: Borrowed ("#b5e4f4") , white : :: std :: borrow :: Cow :: Borrowed ("#a5a2a2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5c5855") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e8bbd0") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#3a3432") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#4a4543") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#807d7c") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d6d5d4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#cdab53") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , background : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , foreground : :: std :: borrow :: Cow :: Borrowed ("#4a4543") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("3024 Night") , black : :: std :: borrow :: Cow :: Borrowed ("#090300") , red : :: std :: borrow :: Cow :: Borrowed ("#db2d20") , green : :: std :: borrow :: Cow :: Borrowed ("#01a252") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fded02") , blue : :: std :: borrow :: Cow :: Borrowed ("#01a0e4") , purple : :: std :: borrow :: Cow :: Borrowed ("#a16a94") , cyan : :: std :: borrow :: Cow :: Borrowed ("#b5e4f4") , white : :: std :: borrow :: Cow :: Borrowed ("#a5a2a2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5c5855") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e8bbd0") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#3a3432") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#4a4543") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#807d7c") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d6d5d4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#cdab53") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , background : :: std :: borrow :: Cow :: Borrowed ("#090300") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a5a2a2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("AdventureTime") , black : :: std :: borrow :: Cow :: Borrowed ("#050404") , red : :: std :: borrow :: Cow :: Borrowed ("#bd0013") , green : :: std :: borrow :: Cow :: Borrowed ("#4ab118") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e7741e") , blue : :: std :: borrow :: Cow :: Borrowed ("#0f4ac6") , purple : :: std :: borrow :: Cow :: Borrowed ("#665993") , cyan : :: std :: borrow :: Cow :: Borrowed ("#70a598") , white : :: std :: borrow :: Cow :: Borrowed ("#f8dcc0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#4e7cbf") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fc5f5a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#9eff6e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#efc11a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1997c6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#9b5953") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#c8faf4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f6f5fb") , background : :: std :: borrow :: Cow :: Borrowed ("#1f1d45") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f8dcc0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Afterglow") , black : :: std :: borrow :: Cow :: Borrowed ("#151515") , red : :: std :: borrow :: Cow :: Borrowed ("#ac4142") , green : :: std :: borrow :: Cow :: Borrowed ("#7e8e50") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e5b567") , blue : :: std :: borrow :: Cow :: Borrowed ("#6c99bb") , purple : :: std :: borrow :: Cow :: Borrowed ("#9f4e85") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7dd6cf") , white : :: std :: borrow :: Cow :: Borrowed ("#d0d0d0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#505050") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ac4142") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#7e8e50") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5b567") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6c99bb") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#9f4e85") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7dd6cf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f5f5f5") , background : :: std :: borrow :: Cow :: Borrowed ("#212121") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d0d0d0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("AlienBlood") , black : :: std :: borrow :: Cow :: Borrowed ("#112616") , red : :: std :: borrow :: Cow :: Borrowed ("#7f2b27") , green : :: std :: borrow :: Cow :: Borrowed ("#2f7e25") , yellow : :: std :: borrow :: Cow :: Borrowed ("#717f24") , blue : :: std :: borrow :: Cow :: Borrowed ("#2f6a7f") , purple : :: std :: borrow :: Cow :: Borrowed ("#47587f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#327f77") , white : :: std :: borrow :: Cow :: Borrowed ("#647d75") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#3c4812") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e08009") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#18e000") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#bde000") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#00aae0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#0058e0") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e0c4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#73fa91") , background : :: std :: borrow :: Cow :: Borrowed ("#0f1610") , foreground : :: std :: borrow :: Cow :: Borrowed ("#637d75") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Andromeda") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cd3131") , green : :: std :: borrow :: Cow :: Borrowed ("#05bc79") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e512") , blue : :: std :: borrow :: Cow :: Borrowed ("#2472c8") , purple : :: std :: borrow :: Cow :: Borrowed ("#bc3fbc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#0fa8cd") , white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#cd3131") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#05bc79") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e512") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2472c8") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#bc3fbc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#0fa8cd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#262a33") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Argonaut") , black : :: std :: borrow :: Cow :: Borrowed ("#232323") , red : :: std :: borrow :: Cow :: Borrowed ("#ff000f") , green : :: std :: borrow :: Cow :: Borrowed ("#8ce10b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffb900") , blue : :: std :: borrow :: Cow :: Borrowed ("#008df8") , purple : :: std :: borrow :: Cow :: Borrowed ("#6d43a6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00d8eb") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#444444") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff2740") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#abe15b") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd242") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0092ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#9a5feb") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#67fff0") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0e1019") , foreground : :: std :: borrow :: Cow :: Borrowed ("#fffaf4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Arthur") , black : :: std :: borrow :: Cow :: Borrowed ("#3d352a") , red : :: std :: borrow :: Cow :: Borrowed ("#cd5c5c") , green : :: std :: borrow :: Cow :: Borrowed ("#86af80") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e8ae5b") , blue : :: std :: borrow :: Cow :: Borrowed ("#6495ed") , purple : :: std :: borrow :: Cow :: Borrowed ("#deb887") , cyan : :: std :: borrow :: Cow :: Borrowed ("#b0c4de") , white : :: std :: borrow :: Cow :: Borrowed ("#bbaa99") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#554444") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#cc5533") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#88aa22") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffa75d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#87ceeb") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#996600") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#b0c4de") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ddccbb") , background : :: std :: borrow :: Cow :: Borrowed ("#1c1c1c") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ddeedd") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("AtelierSulphurpool") , black : :: std :: borrow :: Cow :: Borrowed ("#202746") , red : :: std :: borrow :: Cow :: Borrowed ("#c94922") , green : :: std :: borrow :: Cow :: Borrowed ("#ac9739") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c08b30") , blue : :: std :: borrow :: Cow :: Borrowed ("#3d8fd1") , purple : :: std :: borrow :: Cow :: Borrowed ("#6679cc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#22a2c9") , white : :: std :: borrow :: Cow :: Borrowed ("#979db4") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#6b7394") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c76b29") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#293256") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#5e6687") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#898ea4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#dfe2f1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#9c637a") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f5f7ff") , background : :: std :: borrow :: Cow :: Borrowed ("#202746") , foreground : :: std :: borrow :: Cow :: Borrowed ("#979db4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Atom") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#fd5ff1") , green : :: std :: borrow :: Cow :: Borrowed ("#87c38a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd7b1") , blue : :: std :: borrow :: Cow :: Borrowed ("#85befd") , purple : :: std :: borrow :: Cow :: Borrowed ("#b9b6fc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#85befd") , white : :: std :: borrow :: Cow :: Borrowed ("#e0e0e0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fd5ff1") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#94fa36") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f5ffa8") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#96cbfe") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#b9b6fc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#85befd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e0e0e0") , background : :: std :: borrow :: Cow :: Borrowed ("#161719") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c5c8c6") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("AtomOneLight") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#de3e35") , green : :: std :: borrow :: Cow :: Borrowed ("#3f953a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d2b67c") , blue : :: std :: borrow :: Cow :: Borrowed ("#2f5af3") , purple : :: std :: borrow :: Cow :: Borrowed ("#950095") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3f953a") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#de3e35") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#3f953a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d2b67c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2f5af3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a00095") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3f953a") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#f9f9f9") , foreground : :: std :: borrow :: Cow :: Borrowed ("#2a2c33") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Banana Blueberry") , black : :: std :: borrow :: Cow :: Borrowed ("#17141f") , red : :: std :: borrow :: Cow :: Borrowed ("#ff6b7f") , green : :: std :: borrow :: Cow :: Borrowed ("#00bd9c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e6c62f") , blue : :: std :: borrow :: Cow :: Borrowed ("#22e8df") , purple : :: std :: borrow :: Cow :: Borrowed ("#dc396a") , cyan : :: std :: borrow :: Cow :: Borrowed ("#56b6c2") , white : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#495162") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fe9ea1") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98c379") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f9e46b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#91fff4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#da70d6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#bcf3ff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#191323") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cccccc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Batman") , black : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , red : :: std :: borrow :: Cow :: Borrowed ("#e6dc44") , green : :: std :: borrow :: Cow :: Borrowed ("#c8be46") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f4fd22") , blue : :: std :: borrow :: Cow :: Borrowed ("#737174") , purple : :: std :: borrow :: Cow :: Borrowed ("#747271") , cyan : :: std :: borrow :: Cow :: Borrowed ("#62605f") , white : :: std :: borrow :: Cow :: Borrowed ("#c6c5bf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#505354") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fff78e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#fff27d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#feed6c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#919495") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#9a9a9d") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#a3a3a6") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#dadbd6") , background : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#6f6f6f") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Belafonte Day") , black : :: std :: borrow :: Cow :: Borrowed ("#20111b") , red : :: std :: borrow :: Cow :: Borrowed ("#be100e") , green : :: std :: borrow :: Cow :: Borrowed ("#858162") , yellow : :: std :: borrow :: Cow :: Borrowed ("#eaa549") , blue : :: std :: borrow :: Cow :: Borrowed ("#426a79") , purple : :: std :: borrow :: Cow :: Borrowed ("#97522c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#989a9c") , white : :: std :: borrow :: Cow :: Borrowed ("#968c83") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5e5252") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#be100e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#858162") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#eaa549") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#426a79") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#97522c") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#989a9c") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d5ccba") , background : :: std :: borrow :: Cow :: Borrowed ("#d5ccba") , foreground : :: std :: borrow :: Cow :: Borrowed ("#45373c") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Belafonte Night") , black : :: std :: borrow :: Cow :: Borrowed ("#20111b") , red : :: std :: borrow :: Cow :: Borrowed ("#be100e") , green : :: std :: borrow :: Cow :: Borrowed ("#858162") , yellow : :: std :: borrow :: Cow :: Borrowed ("#eaa549") , blue : :: std :: borrow :: Cow :: Borrowed ("#426a79") , purple : :: std :: borrow :: Cow :: Borrowed ("#97522c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#989a9c") , white : :: std :: borrow :: Cow :: Borrowed ("#968c83") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5e5252") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#be100e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#858162") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#eaa549") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#426a79") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#97522c") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#989a9c") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d5ccba") , background : :: std :: borrow :: Cow :: Borrowed ("#20111b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#968c83") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("BirdsOfParadise") , black : :: std :: borrow :: Cow :: Borrowed ("#573d26") , red : :: std :: borrow :: Cow :: Borrowed ("#be2d26") , green : :: std :: borrow :: Cow :: Borrowed ("#6ba18a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e99d2a") , blue : :: std :: borrow :: Cow :: Borrowed ("#5a86ad") , purple : :: std :: borrow :: Cow :: Borrowed ("#ac80a6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#74a6ad") , white : :: std :: borrow :: Cow :: Borrowed ("#e0dbb7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#9b6c4a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e84627") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#95d8ba") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d0d150") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#b8d3ed") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d19ecb") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#93cfd7") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fff9d5") , background : :: std :: borrow :: Cow :: Borrowed ("#2a1f1d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e0dbb7") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Blazer") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#b87a7a") , green : :: std :: borrow :: Cow :: Borrowed ("#7ab87a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b8b87a") , blue : :: std :: borrow :: Cow :: Borrowed ("#7a7ab8") , purple : :: std :: borrow :: Cow :: Borrowed ("#b87ab8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7ab8b8") , white : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#262626") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dbbdbd") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#bddbbd") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#dbdbbd") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#bdbddb") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#dbbddb") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#bddbdb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0d1926") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d9e6f2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Blue Matrix") , black : :: std :: borrow :: Cow :: Borrowed ("#101116") , red : :: std :: borrow :: Cow :: Borrowed ("#ff5680") , green : :: std :: borrow :: Cow :: Borrowed ("#00ff9c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fffc58") , blue : :: std :: borrow :: Cow :: Borrowed ("#00b0ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#d57bff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#76c1ff") , white : :: std :: borrow :: Cow :: Borrowed ("#c7c7c7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#686868") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6e67") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5ffa68") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffc67") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6871ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d682ec") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#60fdff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#101116") , foreground : :: std :: borrow :: Cow :: Borrowed ("#00a2ff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("BlueBerryPie") , black : :: std :: borrow :: Cow :: Borrowed ("#0a4c62") , red : :: std :: borrow :: Cow :: Borrowed ("#99246e") , green : :: std :: borrow :: Cow :: Borrowed ("#5cb1b3") , yellow : :: std :: borrow :: Cow :: Borrowed ("#eab9a8") , blue : :: std :: borrow :: Cow :: Borrowed ("#90a5bd") , purple : :: std :: borrow :: Cow :: Borrowed ("#9d54a7") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7e83cc") , white : :: std :: borrow :: Cow :: Borrowed ("#f0e8d6") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#201637") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c87272") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#0a6c7e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#7a3188") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#39173d") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#bc94b7") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5e6071") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#0a6c7e") , background : :: std :: borrow :: Cow :: Borrowed ("#1c0c28") , foreground : :: std :: borrow :: Cow :: Borrowed ("#babab9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("BlulocoDark") , black : :: std :: borrow :: Cow :: Borrowed ("#4a505d") , red : :: std :: borrow :: Cow :: Borrowed ("#f81141") , green : :: std :: borrow :: Cow :: Borrowed ("#23974a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fd7e57") , blue : :: std :: borrow :: Cow :: Borrowed ("#285bff") , purple : :: std :: borrow :: Cow :: Borrowed ("#8c62fd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#366f9a") , white : :: std :: borrow :: Cow :: Borrowed ("#ccd5e5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#61697a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fc4a6d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#37bd58") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f6be48") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#199ffd") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fc58f6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#50acae") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1e2127") , foreground : :: std :: borrow :: Cow :: Borrowed ("#abb2bf") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("BlulocoLight") , black : :: std :: borrow :: Cow :: Borrowed ("#cbccd5") , red : :: std :: borrow :: Cow :: Borrowed ("#c90e42") , green : :: std :: borrow :: Cow :: Borrowed ("#21883a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d54d17") , blue : :: std :: borrow :: Cow :: Borrowed ("#1e44dd") , purple : :: std :: borrow :: Cow :: Borrowed ("#6d1bed") , cyan : :: std :: borrow :: Cow :: Borrowed ("#1f4d7a") , white : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#dedfe8") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fc4a6d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#34b354") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#b89427") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1085d9") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c00db3") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5b80ad") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#1d1d22") , background : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , foreground : :: std :: borrow :: Cow :: Borrowed ("#2a2c33") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Borland") , black : :: std :: borrow :: Cow :: Borrowed ("#4f4f4f") , red : :: std :: borrow :: Cow :: Borrowed ("#ff6c60") , green : :: std :: borrow :: Cow :: Borrowed ("#a8ff60") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffffb6") , blue : :: std :: borrow :: Cow :: Borrowed ("#96cbfe") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff73fd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#c6c5fe") , white : :: std :: borrow :: Cow :: Borrowed ("#eeeeee") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7c7c7c") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ffb6b0") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#ceffac") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffffcc") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#b5dcff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff9cfe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#dfdffe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0000a4") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffff4e") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Breeze") , black : :: std :: borrow :: Cow :: Borrowed ("#31363b") , red : :: std :: borrow :: Cow :: Borrowed ("#ed1515") , green : :: std :: borrow :: Cow :: Borrowed ("#11d116") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f67400") , blue : :: std :: borrow :: Cow :: Borrowed ("#1d99f3") , purple : :: std :: borrow :: Cow :: Borrowed ("#9b59b6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#1abc9c") , white : :: std :: borrow :: Cow :: Borrowed ("#eff0f1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7f8c8d") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c0392b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#1cdc9a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdbc4b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3daee9") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8e44ad") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#16a085") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fcfcfc") , background : :: std :: borrow :: Cow :: Borrowed ("#31363b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#eff0f1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Bright Lights") , black : :: std :: borrow :: Cow :: Borrowed ("#191919") , red : :: std :: borrow :: Cow :: Borrowed ("#ff355b") , green : :: std :: borrow :: Cow :: Borrowed ("#b7e876") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc251") , blue : :: std :: borrow :: Cow :: Borrowed ("#76d4ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#ba76e7") , cyan : :: std :: borrow :: Cow :: Borrowed ("#6cbfb5") , white : :: std :: borrow :: Cow :: Borrowed ("#c2c8d7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#191919") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff355b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b7e876") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc251") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#76d5ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ba76e7") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#6cbfb5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#c2c8d7") , background : :: std :: borrow :: Cow :: Borrowed ("#191919") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b3c9d7") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Broadcast") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#da4939") , green : :: std :: borrow :: Cow :: Borrowed ("#519f50") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd24a") , blue : :: std :: borrow :: Cow :: Borrowed ("#6d9cbe") , purple : :: std :: borrow :: Cow :: Borrowed ("#d0d0ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#6e9cbe") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff7b6b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#83d182") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff7c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#9fcef0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#a0cef0") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#2b2b2b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e6e1dc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Brogrammer") , black : :: std :: borrow :: Cow :: Borrowed ("#1f1f1f") , red : :: std :: borrow :: Cow :: Borrowed ("#f81118") , green : :: std :: borrow :: Cow :: Borrowed ("#2dc55e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ecba0f") , blue : :: std :: borrow :: Cow :: Borrowed ("#2a84d2") , purple : :: std :: borrow :: Cow :: Borrowed ("#4e5ab7") , cyan : :: std :: borrow :: Cow :: Borrowed ("#1081d6") , white : :: std :: borrow :: Cow :: Borrowed ("#d6dbe5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#d6dbe5") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#de352e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#1dd361") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f3bd09") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1081d6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#5350b9") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#0f7ddb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#131313") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d6dbe5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Builtin Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#bb0000") , green : :: std :: borrow :: Cow :: Borrowed ("#00bb00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#bbbb00") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000bb") , purple : :: std :: borrow :: Cow :: Borrowed ("#bb00bb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00bbbb") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Builtin Light") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#bb0000") , green : :: std :: borrow :: Cow :: Borrowed ("#00bb00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#bbbb00") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000bb") , purple : :: std :: borrow :: Cow :: Borrowed ("#bb00bb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00bbbb") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Builtin Pastel Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#4f4f4f") , red : :: std :: borrow :: Cow :: Borrowed ("#ff6c60") , green : :: std :: borrow :: Cow :: Borrowed ("#a8ff60") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffffb6") , blue : :: std :: borrow :: Cow :: Borrowed ("#96cbfe") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff73fd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#c6c5fe") , white : :: std :: borrow :: Cow :: Borrowed ("#eeeeee") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7c7c7c") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ffb6b0") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#ceffac") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffffcc") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#b5dcff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff9cfe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#dfdffe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Builtin Solarized Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#073642") , red : :: std :: borrow :: Cow :: Borrowed ("#dc322f") , green : :: std :: borrow :: Cow :: Borrowed ("#859900") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b58900") , blue : :: std :: borrow :: Cow :: Borrowed ("#268bd2") , purple : :: std :: borrow :: Cow :: Borrowed ("#d33682") , cyan : :: std :: borrow :: Cow :: Borrowed ("#2aa198") , white : :: std :: borrow :: Cow :: Borrowed ("#eee8d5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#002b36") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#cb4b16") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#586e75") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#657b83") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#839496") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#6c71c4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#93a1a1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fdf6e3") , background : :: std :: borrow :: Cow :: Borrowed ("#002b36") , foreground : :: std :: borrow :: Cow :: Borrowed ("#839496") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Builtin Solarized Light") , black : :: std :: borrow :: Cow :: Borrowed ("#073642") , red : :: std :: borrow :: Cow :: Borrowed ("#dc322f") , green : :: std :: borrow :: Cow :: Borrowed ("#859900") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b58900") , blue : :: std :: borrow :: Cow :: Borrowed ("#268bd2") , purple : :: std :: borrow :: Cow :: Borrowed ("#d33682") , cyan : :: std :: borrow :: Cow :: Borrowed ("#2aa198") , white : :: std :: borrow :: Cow :: Borrowed ("#eee8d5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#002b36") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#cb4b16") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#586e75") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#657b83") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#839496") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#6c71c4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#93a1a1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fdf6e3") , background : :: std :: borrow :: Cow :: Borrowed ("#fdf6e3") , foreground : :: std :: borrow :: Cow :: Borrowed ("#657b83") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Builtin Tango Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cc0000") , green : :: std :: borrow :: Cow :: Borrowed ("#4e9a06") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c4a000") , blue : :: std :: borrow :: Cow :: Borrowed ("#3465a4") , purple : :: std :: borrow :: Cow :: Borrowed ("#75507b") , cyan : :: std :: borrow :: Cow :: Borrowed ("#06989a") , white : :: std :: borrow :: Cow :: Borrowed ("#d3d7cf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555753") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ef2929") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8ae234") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fce94f") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#729fcf") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ad7fa8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#34e2e2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Builtin Tango Light") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cc0000") , green : :: std :: borrow :: Cow :: Borrowed ("#4e9a06") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c4a000") , blue : :: std :: borrow :: Cow :: Borrowed ("#3465a4") , purple : :: std :: borrow :: Cow :: Borrowed ("#75507b") , cyan : :: std :: borrow :: Cow :: Borrowed ("#06989a") , white : :: std :: borrow :: Cow :: Borrowed ("#d3d7cf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555753") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ef2929") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8ae234") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fce94f") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#729fcf") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ad7fa8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#34e2e2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("C64") , black : :: std :: borrow :: Cow :: Borrowed ("#090300") , red : :: std :: borrow :: Cow :: Borrowed ("#883932") , green : :: std :: borrow :: Cow :: Borrowed ("#55a049") , yellow : :: std :: borrow :: Cow :: Borrowed ("#bfce72") , blue : :: std :: borrow :: Cow :: Borrowed ("#40318d") , purple : :: std :: borrow :: Cow :: Borrowed ("#8b3f96") , cyan : :: std :: borrow :: Cow :: Borrowed ("#67b6bd") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#883932") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55a049") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#bfce72") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#40318d") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8b3f96") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#67b6bd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , background : :: std :: borrow :: Cow :: Borrowed ("#40318d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#7869c4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("CLRS") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#f8282a") , green : :: std :: borrow :: Cow :: Borrowed ("#328a5d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fa701d") , blue : :: std :: borrow :: Cow :: Borrowed ("#135cd0") , purple : :: std :: borrow :: Cow :: Borrowed ("#9f00bd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#33c3c1") , white : :: std :: borrow :: Cow :: Borrowed ("#b3b3b3") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555753") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb0416") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#2cc631") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdd727") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1670ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e900b0") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3ad5ce") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#262626") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Calamity") , black : :: std :: borrow :: Cow :: Borrowed ("#2f2833") , red : :: std :: borrow :: Cow :: Borrowed ("#fc644d") , green : :: std :: borrow :: Cow :: Borrowed ("#a5f69c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e9d7a5") , blue : :: std :: borrow :: Cow :: Borrowed ("#3b79c7") , purple : :: std :: borrow :: Cow :: Borrowed ("#f92672") , cyan : :: std :: borrow :: Cow :: Borrowed ("#74d3de") , white : :: std :: borrow :: Cow :: Borrowed ("#d5ced9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7e6c88") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fc644d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#a5f69c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e9d7a5") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3b79c7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f92672") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#74d3de") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#2f2833") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d5ced9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Chalk") , black : :: std :: borrow :: Cow :: Borrowed ("#7d8b8f") , red : :: std :: borrow :: Cow :: Borrowed ("#b23a52") , green : :: std :: borrow :: Cow :: Borrowed ("#789b6a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b9ac4a") , blue : :: std :: borrow :: Cow :: Borrowed ("#2a7fac") , purple : :: std :: borrow :: Cow :: Borrowed ("#bd4f5a") , cyan : :: std :: borrow :: Cow :: Borrowed ("#44a799") , white : :: std :: borrow :: Cow :: Borrowed ("#d2d8d9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#888888") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f24840") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#80c470") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffeb62") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4196ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fc5275") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#53cdbd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d2d8d9") , background : :: std :: borrow :: Cow :: Borrowed ("#2b2d2e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d2d8d9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Chalkboard") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#c37372") , green : :: std :: borrow :: Cow :: Borrowed ("#72c373") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c2c372") , blue : :: std :: borrow :: Cow :: Borrowed ("#7372c3") , purple : :: std :: borrow :: Cow :: Borrowed ("#c372c2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#72c2c3") , white : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dbaaaa") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#aadbaa") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#dadbaa") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#aaaadb") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#dbaada") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#aadadb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#29262f") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d9e6f2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("ChallengerDeep") , black : :: std :: borrow :: Cow :: Borrowed ("#141228") , red : :: std :: borrow :: Cow :: Borrowed ("#ff5458") , green : :: std :: borrow :: Cow :: Borrowed ("#62d196") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffb378") , blue : :: std :: borrow :: Cow :: Borrowed ("#65b2ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#906cff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#63f2f1") , white : :: std :: borrow :: Cow :: Borrowed ("#a6b3cc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#565575") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff8080") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#95ffa4") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe9aa") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#91ddff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c991e1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#aaffe4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#cbe3e7") , background : :: std :: borrow :: Cow :: Borrowed ("#1e1c31") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cbe1e7") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Chester") , black : :: std :: borrow :: Cow :: Borrowed ("#080200") , red : :: std :: borrow :: Cow :: Borrowed ("#fa5e5b") , green : :: std :: borrow :: Cow :: Borrowed ("#16c98d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc83f") , blue : :: std :: borrow :: Cow :: Borrowed ("#288ad6") , purple : :: std :: borrow :: Cow :: Borrowed ("#d34590") , cyan : :: std :: borrow :: Cow :: Borrowed ("#28ddde") , white : :: std :: borrow :: Cow :: Borrowed ("#e7e7e7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#6f6b68") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fa5e5b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#16c98d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#feef6d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#278ad6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d34590") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#27dede") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#2c3643") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Ciapre") , black : :: std :: borrow :: Cow :: Borrowed ("#181818") , red : :: std :: borrow :: Cow :: Borrowed ("#810009") , green : :: std :: borrow :: Cow :: Borrowed ("#48513b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#cc8b3f") , blue : :: std :: borrow :: Cow :: Borrowed ("#576d8c") , purple : :: std :: borrow :: Cow :: Borrowed ("#724d7c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5c4f4b") , white : :: std :: borrow :: Cow :: Borrowed ("#aea47f") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ac3835") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#a6a75d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#dcdf7c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3097c6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d33061") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#f3dbb2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f4f4f4") , background : :: std :: borrow :: Cow :: Borrowed ("#191c27") , foreground : :: std :: borrow :: Cow :: Borrowed ("#aea47a") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Cobalt Neon") , black : :: std :: borrow :: Cow :: Borrowed ("#142631") , red : :: std :: borrow :: Cow :: Borrowed ("#ff2320") , green : :: std :: borrow :: Cow :: Borrowed ("#3ba5ff") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e9e75c") , blue : :: std :: borrow :: Cow :: Borrowed ("#8ff586") , purple : :: std :: borrow :: Cow :: Borrowed ("#781aa0") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8ff586") , white : :: std :: borrow :: Cow :: Borrowed ("#ba46b2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#fff688") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d4312e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8ff586") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e9f06d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3c7dd2") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8230a7") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#6cbc67") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#8ff586") , background : :: std :: borrow :: Cow :: Borrowed ("#142838") , foreground : :: std :: borrow :: Cow :: Borrowed ("#8ff586") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Cobalt2") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff0000") , green : :: std :: borrow :: Cow :: Borrowed ("#38de21") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe50a") , blue : :: std :: borrow :: Cow :: Borrowed ("#1460d2") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff005d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00bbbb") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f40e17") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#3bd01d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#edc809") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#6ae3fa") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#132738") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("CrayonPonyFish") , black : :: std :: borrow :: Cow :: Borrowed ("#2b1b1d") , red : :: std :: borrow :: Cow :: Borrowed ("#91002b") , green : :: std :: borrow :: Cow :: Borrowed ("#579524") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ab311b") , blue : :: std :: borrow :: Cow :: Borrowed ("#8c87b0") , purple : :: std :: borrow :: Cow :: Borrowed ("#692f50") , cyan : :: std :: borrow :: Cow :: Borrowed ("#e8a866") , white : :: std :: borrow :: Cow :: Borrowed ("#68525a") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#3d2b2e") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c5255d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8dff57") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#c8381d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#cfc9ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fc6cba") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ffceaf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#b0949d") , background : :: std :: borrow :: Cow :: Borrowed ("#150707") , foreground : :: std :: borrow :: Cow :: Borrowed ("#68525a") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Cyberdyne") , black : :: std :: borrow :: Cow :: Borrowed ("#080808") , red : :: std :: borrow :: Cow :: Borrowed ("#ff8373") , green : :: std :: borrow :: Cow :: Borrowed ("#00c172") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d2a700") , blue : :: std :: borrow :: Cow :: Borrowed ("#0071cf") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff90fe") , cyan : :: std :: borrow :: Cow :: Borrowed ("#6bffdd") , white : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#2e2e2e") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ffc4be") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#d6fcba") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffed5") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#c2e3ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffb2fe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#e6e7fe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#151144") , foreground : :: std :: borrow :: Cow :: Borrowed ("#00ff92") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Dark Pastel") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Dark+") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cd3131") , green : :: std :: borrow :: Cow :: Borrowed ("#0dbc79") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e510") , blue : :: std :: borrow :: Cow :: Borrowed ("#2472c8") , purple : :: std :: borrow :: Cow :: Borrowed ("#bc3fbc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#11a8cd") , white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f14c4c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#23d18b") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f5f543") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3b8eea") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d670d6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#29b8db") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#0e0e0e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cccccc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Darkside") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#e8341c") , green : :: std :: borrow :: Cow :: Borrowed ("#68c256") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f2d42c") , blue : :: std :: borrow :: Cow :: Borrowed ("#1c98e8") , purple : :: std :: borrow :: Cow :: Borrowed ("#8e69c9") , cyan : :: std :: borrow :: Cow :: Borrowed ("#1c98e8") , white : :: std :: borrow :: Cow :: Borrowed ("#bababa") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e05a4f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#77b869") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#efd64b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#387cd3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#957bbe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3d97e2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#bababa") , background : :: std :: borrow :: Cow :: Borrowed ("#222324") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bababa") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Desert") , black : :: std :: borrow :: Cow :: Borrowed ("#4d4d4d") , red : :: std :: borrow :: Cow :: Borrowed ("#ff2b2b") , green : :: std :: borrow :: Cow :: Borrowed ("#98fb98") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f0e68c") , blue : :: std :: borrow :: Cow :: Borrowed ("#cd853f") , purple : :: std :: borrow :: Cow :: Borrowed ("#ffdead") , cyan : :: std :: borrow :: Cow :: Borrowed ("#ffa0a0") , white : :: std :: borrow :: Cow :: Borrowed ("#f5deb3") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#87ceff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ffd700") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#333333") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("DimmedMonokai") , black : :: std :: borrow :: Cow :: Borrowed ("#3a3d43") , red : :: std :: borrow :: Cow :: Borrowed ("#be3f48") , green : :: std :: borrow :: Cow :: Borrowed ("#879a3b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c5a635") , blue : :: std :: borrow :: Cow :: Borrowed ("#4f76a1") , purple : :: std :: borrow :: Cow :: Borrowed ("#855c8d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#578fa4") , white : :: std :: borrow :: Cow :: Borrowed ("#b9bcba") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#888987") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb001f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#0f722f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#c47033") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#186de3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fb0067") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#2e706d") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fdffb9") , background : :: std :: borrow :: Cow :: Borrowed ("#1f1f1f") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b9bcba") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Django") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#fd6209") , green : :: std :: borrow :: Cow :: Borrowed ("#41a83e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe862") , blue : :: std :: borrow :: Cow :: Borrowed ("#245032") , purple : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#9df39f") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff943b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#73da70") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff94") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#568264") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#cfffd1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0b2f20") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("DjangoRebornAgain") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#fd6209") , green : :: std :: borrow :: Cow :: Borrowed ("#41a83e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe862") , blue : :: std :: borrow :: Cow :: Borrowed ("#245032") , purple : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#9df39f") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff943b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#73da70") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff94") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#568264") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#cfffd1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#051f14") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dadedc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("DjangoSmooth") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#fd6209") , green : :: std :: borrow :: Cow :: Borrowed ("#41a83e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe862") , blue : :: std :: borrow :: Cow :: Borrowed ("#989898") , purple : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#9df39f") , white : :: std :: borrow :: Cow :: Borrowed ("#e8e8e7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff943b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#73da70") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff94") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#cacaca") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#cfffd1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#245032") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("DoomOne") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff6c6b") , green : :: std :: borrow :: Cow :: Borrowed ("#98be65") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ecbe7b") , blue : :: std :: borrow :: Cow :: Borrowed ("#a9a1e1") , purple : :: std :: borrow :: Cow :: Borrowed ("#c678dd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#51afef") , white : :: std :: borrow :: Cow :: Borrowed ("#bbc2cf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6655") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#99bb66") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ecbe7b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#a9a1e1") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c678dd") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#51afef") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , background : :: std :: borrow :: Cow :: Borrowed ("#282c34") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bbc2cf") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("DotGov") , black : :: std :: borrow :: Cow :: Borrowed ("#191919") , red : :: std :: borrow :: Cow :: Borrowed ("#bf091d") , green : :: std :: borrow :: Cow :: Borrowed ("#3d9751") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f6bb34") , blue : :: std :: borrow :: Cow :: Borrowed ("#17b2e0") , purple : :: std :: borrow :: Cow :: Borrowed ("#7830b0") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8bd2ed") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#191919") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bf091d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#3d9751") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f6bb34") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#17b2e0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#7830b0") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8bd2ed") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#262c35") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ebebeb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Dracula") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , green : :: std :: borrow :: Cow :: Borrowed ("#50fa7b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f1fa8c") , blue : :: std :: borrow :: Cow :: Borrowed ("#bd93f9") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff79c6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8be9fd") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#50fa7b") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f1fa8c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#bd93f9") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff79c6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8be9fd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1e1f29") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f8f8f2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Duotone Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#1f1d27") , red : :: std :: borrow :: Cow :: Borrowed ("#d9393e") , green : :: std :: borrow :: Cow :: Borrowed ("#2dcd73") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d9b76e") , blue : :: std :: borrow :: Cow :: Borrowed ("#ffc284") , purple : :: std :: borrow :: Cow :: Borrowed ("#de8d40") , cyan : :: std :: borrow :: Cow :: Borrowed ("#2488ff") , white : :: std :: borrow :: Cow :: Borrowed ("#b7a1ff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#353147") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d9393e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#2dcd73") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d9b76e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#ffc284") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#de8d40") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#2488ff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#eae5ff") , background : :: std :: borrow :: Cow :: Borrowed ("#1f1d27") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b7a1ff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("ENCOM") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#9f0000") , green : :: std :: borrow :: Cow :: Borrowed ("#008b00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd000") , blue : :: std :: borrow :: Cow :: Borrowed ("#0081ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#bc00ca") , cyan : :: std :: borrow :: Cow :: Borrowed ("#008b8b") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00ee00") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff00ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00cdcd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#00a595") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Earthsong") , black : :: std :: borrow :: Cow :: Borrowed ("#121418") , red : :: std :: borrow :: Cow :: Borrowed ("#c94234") , green : :: std :: borrow :: Cow :: Borrowed ("#85c54c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f5ae2e") , blue : :: std :: borrow :: Cow :: Borrowed ("#1398b9") , purple : :: std :: borrow :: Cow :: Borrowed ("#d0633d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#509552") , white : :: std :: borrow :: Cow :: Borrowed ("#e5c6aa") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#675f54") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff645a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98e036") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e0d561") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5fdaff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff9269") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#84f088") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f6f7ec") , background : :: std :: borrow :: Cow :: Borrowed ("#292520") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e5c7a9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Elemental") , black : :: std :: borrow :: Cow :: Borrowed ("#3c3c30") , red : :: std :: borrow :: Cow :: Borrowed ("#98290f") , green : :: std :: borrow :: Cow :: Borrowed ("#479a43") , yellow : :: std :: borrow :: Cow :: Borrowed ("#7f7111") , blue : :: std :: borrow :: Cow :: Borrowed ("#497f7d") , purple : :: std :: borrow :: Cow :: Borrowed ("#7f4e2f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#387f58") , white : :: std :: borrow :: Cow :: Borrowed ("#807974") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555445") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e0502a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#61e070") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d69927") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#79d9d9") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#cd7c54") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#59d599") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fff1e9") , background : :: std :: borrow :: Cow :: Borrowed ("#22211d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#807a74") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Elementary") , black : :: std :: borrow :: Cow :: Borrowed ("#242424") , red : :: std :: borrow :: Cow :: Borrowed ("#d71c15") , green : :: std :: borrow :: Cow :: Borrowed ("#5aa513") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fdb40c") , blue : :: std :: borrow :: Cow :: Borrowed ("#063b8c") , purple : :: std :: borrow :: Cow :: Borrowed ("#e40038") , cyan : :: std :: borrow :: Cow :: Borrowed ("#2595e1") , white : :: std :: borrow :: Cow :: Borrowed ("#efefef") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#4b4b4b") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fc1c18") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#6bc219") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fec80e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0955ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fb0050") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3ea8fc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#8c00ec") , background : :: std :: borrow :: Cow :: Borrowed ("#181818") , foreground : :: std :: borrow :: Cow :: Borrowed ("#efefef") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Espresso Libre") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cc0000") , green : :: std :: borrow :: Cow :: Borrowed ("#1a921c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f0e53a") , blue : :: std :: borrow :: Cow :: Borrowed ("#0066ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#c5656b") , cyan : :: std :: borrow :: Cow :: Borrowed ("#06989a") , white : :: std :: borrow :: Cow :: Borrowed ("#d3d7cf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555753") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ef2929") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#9aff87") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffb5c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#43a8ed") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff818a") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#34e2e2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , background : :: std :: borrow :: Cow :: Borrowed ("#2a211c") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b8a898") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Espresso") , black : :: std :: borrow :: Cow :: Borrowed ("#353535") , red : :: std :: borrow :: Cow :: Borrowed ("#d25252") , green : :: std :: borrow :: Cow :: Borrowed ("#a5c261") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc66d") , blue : :: std :: borrow :: Cow :: Borrowed ("#6c99bb") , purple : :: std :: borrow :: Cow :: Borrowed ("#d197d9") , cyan : :: std :: borrow :: Cow :: Borrowed ("#bed6ff") , white : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#535353") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f00c0c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#c2e075") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e1e48b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#8ab7d9") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#efb5f7") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#dcf4ff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#323232") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Fahrenheit") , black : :: std :: borrow :: Cow :: Borrowed ("#1d1d1d") , red : :: std :: borrow :: Cow :: Borrowed ("#cda074") , green : :: std :: borrow :: Cow :: Borrowed ("#9e744d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fecf75") , blue : :: std :: borrow :: Cow :: Borrowed ("#720102") , purple : :: std :: borrow :: Cow :: Borrowed ("#734c4d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#979797") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffce") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fecea0") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#cc734d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fd9f4d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#cb4a05") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#4e739f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#fed04d") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffce") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Fideloper") , black : :: std :: borrow :: Cow :: Borrowed ("#292f33") , red : :: std :: borrow :: Cow :: Borrowed ("#cb1e2d") , green : :: std :: borrow :: Cow :: Borrowed ("#edb8ac") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b7ab9b") , blue : :: std :: borrow :: Cow :: Borrowed ("#2e78c2") , purple : :: std :: borrow :: Cow :: Borrowed ("#c0236f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#309186") , white : :: std :: borrow :: Cow :: Borrowed ("#eae3ce") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#092028") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d4605a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#d4605a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#a86671") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7c85c4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#5c5db2") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#819090") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fcf4df") , background : :: std :: borrow :: Cow :: Borrowed ("#292f33") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dbdae0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("FirefoxDev") , black : :: std :: borrow :: Cow :: Borrowed ("#002831") , red : :: std :: borrow :: Cow :: Borrowed ("#e63853") , green : :: std :: borrow :: Cow :: Borrowed ("#5eb83c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#a57706") , blue : :: std :: borrow :: Cow :: Borrowed ("#359ddf") , purple : :: std :: borrow :: Cow :: Borrowed ("#d75cff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#4b73a2") , white : :: std :: borrow :: Cow :: Borrowed ("#dcdcdc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#001e27") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e1003f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#1d9000") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#cd9409") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#006fc0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a200da") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#005794") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e2e2e2") , background : :: std :: borrow :: Cow :: Borrowed ("#0e1011") , foreground : :: std :: borrow :: Cow :: Borrowed ("#7c8fa4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Firewatch") , black : :: std :: borrow :: Cow :: Borrowed ("#585f6d") , red : :: std :: borrow :: Cow :: Borrowed ("#d95360") , green : :: std :: borrow :: Cow :: Borrowed ("#5ab977") , yellow : :: std :: borrow :: Cow :: Borrowed ("#dfb563") , blue : :: std :: borrow :: Cow :: Borrowed ("#4d89c4") , purple : :: std :: borrow :: Cow :: Borrowed ("#d55119") , cyan : :: std :: borrow :: Cow :: Borrowed ("#44a8b6") , white : :: std :: borrow :: Cow :: Borrowed ("#e6e5ff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#585f6d") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d95360") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5ab977") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#dfb563") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4c89c5") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d55119") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#44a8b6") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e6e5ff") , background : :: std :: borrow :: Cow :: Borrowed ("#1e2027") , foreground : :: std :: borrow :: Cow :: Borrowed ("#9ba2b2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("FishTank") , black : :: std :: borrow :: Cow :: Borrowed ("#03073c") , red : :: std :: borrow :: Cow :: Borrowed ("#c6004a") , green : :: std :: borrow :: Cow :: Borrowed ("#acf157") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fecd5e") , blue : :: std :: borrow :: Cow :: Borrowed ("#525fb8") , purple : :: std :: borrow :: Cow :: Borrowed ("#986f82") , cyan : :: std :: borrow :: Cow :: Borrowed ("#968763") , white : :: std :: borrow :: Cow :: Borrowed ("#ecf0fc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#6c5b30") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#da4b8a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#dbffa9") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fee6a9") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#b2befa") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fda5cd") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#a5bd86") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f6ffec") , background : :: std :: borrow :: Cow :: Borrowed ("#232537") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ecf0fe") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Flat") , black : :: std :: borrow :: Cow :: Borrowed ("#222d3f") , red : :: std :: borrow :: Cow :: Borrowed ("#a82320") , green : :: std :: borrow :: Cow :: Borrowed ("#32a548") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e58d11") , blue : :: std :: borrow :: Cow :: Borrowed ("#3167ac") , purple : :: std :: borrow :: Cow :: Borrowed ("#781aa0") , cyan : :: std :: borrow :: Cow :: Borrowed ("#2c9370") , white : :: std :: borrow :: Cow :: Borrowed ("#b0b6ba") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#212c3c") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d4312e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#2d9440") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5be0c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3c7dd2") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8230a7") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#35b387") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e7eced") , background : :: std :: borrow :: Cow :: Borrowed ("#002240") , foreground : :: std :: borrow :: Cow :: Borrowed ("#2cc55d") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Flatland") , black : :: std :: borrow :: Cow :: Borrowed ("#1d1d19") , red : :: std :: borrow :: Cow :: Borrowed ("#f18339") , green : :: std :: borrow :: Cow :: Borrowed ("#9fd364") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f4ef6d") , blue : :: std :: borrow :: Cow :: Borrowed ("#5096be") , purple : :: std :: borrow :: Cow :: Borrowed ("#695abc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#d63865") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#1d1d19") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d22a24") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#a7d42c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ff8949") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#61b9d0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#695abc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#d63865") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1d1f21") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b8dbef") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Floraverse") , black : :: std :: borrow :: Cow :: Borrowed ("#08002e") , red : :: std :: borrow :: Cow :: Borrowed ("#64002c") , green : :: std :: borrow :: Cow :: Borrowed ("#5d731a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#cd751c") , blue : :: std :: borrow :: Cow :: Borrowed ("#1d6da1") , purple : :: std :: borrow :: Cow :: Borrowed ("#b7077e") , cyan : :: std :: borrow :: Cow :: Borrowed ("#42a38c") , white : :: std :: borrow :: Cow :: Borrowed ("#f3e0b8") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#331e4d") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d02063") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b4ce59") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fac357") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#40a4cf") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f12aae") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#62caa8") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fff5db") , background : :: std :: borrow :: Cow :: Borrowed ("#0e0d15") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dbd1b9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("ForestBlue") , black : :: std :: borrow :: Cow :: Borrowed ("#333333") , red : :: std :: borrow :: Cow :: Borrowed ("#f8818e") , green : :: std :: borrow :: Cow :: Borrowed ("#92d3a2") , yellow : :: std :: borrow :: Cow :: Borrowed ("#1a8e63") , blue : :: std :: borrow :: Cow :: Borrowed ("#8ed0ce") , purple : :: std :: borrow :: Cow :: Borrowed ("#5e468c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#31658c") , white : :: std :: borrow :: Cow :: Borrowed ("#e2d8cd") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#3d3d3d") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb3d66") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#6bb48d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#30c85a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#39a7a2") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#7e62b3") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#6096bf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e2d8cd") , background : :: std :: borrow :: Cow :: Borrowed ("#051519") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e2d8cd") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Framer") , black : :: std :: borrow :: Cow :: Borrowed ("#141414") , red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , green : :: std :: borrow :: Cow :: Borrowed ("#98ec65") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcc33") , blue : :: std :: borrow :: Cow :: Borrowed ("#00aaff") , purple : :: std :: borrow :: Cow :: Borrowed ("#aa88ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#88ddff") , white : :: std :: borrow :: Cow :: Borrowed ("#cccccc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#414141") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff8888") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b6f292") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd966") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#33bbff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#cebbff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#bbecff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#111111") , foreground : :: std :: borrow :: Cow :: Borrowed ("#777777") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("FrontEndDelight") , black : :: std :: borrow :: Cow :: Borrowed ("#242526") , red : :: std :: borrow :: Cow :: Borrowed ("#f8511b") , green : :: std :: borrow :: Cow :: Borrowed ("#565747") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fa771d") , blue : :: std :: borrow :: Cow :: Borrowed ("#2c70b7") , purple : :: std :: borrow :: Cow :: Borrowed ("#f02e4f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3ca1a6") , white : :: std :: borrow :: Cow :: Borrowed ("#adadad") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5fac6d") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f74319") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#74ec4c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdc325") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3393ca") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e75e4f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#4fbce6") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#8c735b") , background : :: std :: borrow :: Cow :: Borrowed ("#1b1c1d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#adadad") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("FunForrest") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#d6262b") , green : :: std :: borrow :: Cow :: Borrowed ("#919c00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#be8a13") , blue : :: std :: borrow :: Cow :: Borrowed ("#4699a3") , purple : :: std :: borrow :: Cow :: Borrowed ("#8d4331") , cyan : :: std :: borrow :: Cow :: Borrowed ("#da8213") , white : :: std :: borrow :: Cow :: Borrowed ("#ddc265") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7f6a55") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e55a1c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#bfc65a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcb1b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7cc9cf") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d26349") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#e6a96b") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffeaa3") , background : :: std :: borrow :: Cow :: Borrowed ("#251200") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dec165") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Galaxy") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#f9555f") , green : :: std :: borrow :: Cow :: Borrowed ("#21b089") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fef02a") , blue : :: std :: borrow :: Cow :: Borrowed ("#589df6") , purple : :: std :: borrow :: Cow :: Borrowed ("#944d95") , cyan : :: std :: borrow :: Cow :: Borrowed ("#1f9ee7") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fa8c8f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#35bb9a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#589df6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e75699") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3979bc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1d2837") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Github") , black : :: std :: borrow :: Cow :: Borrowed ("#3e3e3e") , red : :: std :: borrow :: Cow :: Borrowed ("#970b16") , green : :: std :: borrow :: Cow :: Borrowed ("#07962a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f8eec7") , blue : :: std :: borrow :: Cow :: Borrowed ("#003e8a") , purple : :: std :: borrow :: Cow :: Borrowed ("#e94691") , cyan : :: std :: borrow :: Cow :: Borrowed ("#89d1ec") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#de0000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#87d5a2") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f1d007") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2e6cba") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffa29f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#1cfafe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#f4f4f4") , foreground : :: std :: borrow :: Cow :: Borrowed ("#3e3e3e") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Glacier") , black : :: std :: borrow :: Cow :: Borrowed ("#2e343c") , red : :: std :: borrow :: Cow :: Borrowed ("#bd0f2f") , green : :: std :: borrow :: Cow :: Borrowed ("#35a770") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fb9435") , blue : :: std :: borrow :: Cow :: Borrowed ("#1f5872") , purple : :: std :: borrow :: Cow :: Borrowed ("#bd2523") , cyan : :: std :: borrow :: Cow :: Borrowed ("#778397") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#404a55") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bd0f2f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#49e998") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fddf6e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2a8bc1") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ea4727") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#a0b6d3") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0c1115") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Grape") , black : :: std :: borrow :: Cow :: Borrowed ("#2d283f") , red : :: std :: borrow :: Cow :: Borrowed ("#ed2261") , green : :: std :: borrow :: Cow :: Borrowed ("#1fa91b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#8ddc20") , blue : :: std :: borrow :: Cow :: Borrowed ("#487df4") , purple : :: std :: borrow :: Cow :: Borrowed ("#8d35c9") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3bdeed") , white : :: std :: borrow :: Cow :: Borrowed ("#9e9ea0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#59516a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f0729a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#53aa5e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#b2dc87") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#a9bcec") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ad81c2") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#9de3eb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#a288f7") , background : :: std :: borrow :: Cow :: Borrowed ("#171423") , foreground : :: std :: borrow :: Cow :: Borrowed ("#9f9fa1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Grass") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#bb0000") , green : :: std :: borrow :: Cow :: Borrowed ("#00bb00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e7b000") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000a3") , purple : :: std :: borrow :: Cow :: Borrowed ("#950062") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00bbbb") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bb0000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00bb00") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e7b000") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000bb") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#13773d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#fff0a5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Gruvbox Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#1e1e1e") , red : :: std :: borrow :: Cow :: Borrowed ("#be0f17") , green : :: std :: borrow :: Cow :: Borrowed ("#868715") , yellow : :: std :: borrow :: Cow :: Borrowed ("#cc881a") , blue : :: std :: borrow :: Cow :: Borrowed ("#377375") , purple : :: std :: borrow :: Cow :: Borrowed ("#a04b73") , cyan : :: std :: borrow :: Cow :: Borrowed ("#578e57") , white : :: std :: borrow :: Cow :: Borrowed ("#978771") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7f7061") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f73028") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#aab01e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f7b125") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#719586") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c77089") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7db669") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e6d4a3") , background : :: std :: borrow :: Cow :: Borrowed ("#1e1e1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e6d4a3") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hacktober") , black : :: std :: borrow :: Cow :: Borrowed ("#191918") , red : :: std :: borrow :: Cow :: Borrowed ("#b34538") , green : :: std :: borrow :: Cow :: Borrowed ("#587744") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d08949") , blue : :: std :: borrow :: Cow :: Borrowed ("#206ec5") , purple : :: std :: borrow :: Cow :: Borrowed ("#864651") , cyan : :: std :: borrow :: Cow :: Borrowed ("#ac9166") , white : :: std :: borrow :: Cow :: Borrowed ("#f1eee7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#2c2b2a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#b33323") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#42824a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#c75a22") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5389c5") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e795a5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ebc587") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#141414") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c9c9c9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hardcore") , black : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , red : :: std :: borrow :: Cow :: Borrowed ("#f92672") , green : :: std :: borrow :: Cow :: Borrowed ("#a6e22e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fd971f") , blue : :: std :: borrow :: Cow :: Borrowed ("#66d9ef") , purple : :: std :: borrow :: Cow :: Borrowed ("#9e6ffe") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5e7175") , white : :: std :: borrow :: Cow :: Borrowed ("#ccccc6") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#505354") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff669d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#beed5f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e6db74") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#66d9ef") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#9e6ffe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#a3babf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f8f8f2") , background : :: std :: borrow :: Cow :: Borrowed ("#121212") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a0a0a0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Harper") , black : :: std :: borrow :: Cow :: Borrowed ("#010101") , red : :: std :: borrow :: Cow :: Borrowed ("#f8b63f") , green : :: std :: borrow :: Cow :: Borrowed ("#7fb5e1") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d6da25") , blue : :: std :: borrow :: Cow :: Borrowed ("#489e48") , purple : :: std :: borrow :: Cow :: Borrowed ("#b296c6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#f5bfd7") , white : :: std :: borrow :: Cow :: Borrowed ("#a8a49d") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#726e6a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f8b63f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#7fb5e1") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d6da25") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#489e48") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#b296c6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#f5bfd7") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fefbea") , background : :: std :: borrow :: Cow :: Borrowed ("#010101") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a8a49d") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Highway") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#d00e18") , green : :: std :: borrow :: Cow :: Borrowed ("#138034") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcb3e") , blue : :: std :: borrow :: Cow :: Borrowed ("#006bb3") , purple : :: std :: borrow :: Cow :: Borrowed ("#6b2775") , cyan : :: std :: borrow :: Cow :: Borrowed ("#384564") , white : :: std :: borrow :: Cow :: Borrowed ("#ededed") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5d504a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f07e18") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b1d130") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff120") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4fc2fd") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#de0071") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5d504a") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#222225") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ededed") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hipster Green") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#b6214a") , green : :: std :: borrow :: Cow :: Borrowed ("#00a600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#bfbf00") , blue : :: std :: borrow :: Cow :: Borrowed ("#246eb2") , purple : :: std :: borrow :: Cow :: Borrowed ("#b200b2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00a6b2") , white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e50000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#86a93e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e5e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#100b05") , foreground : :: std :: borrow :: Cow :: Borrowed ("#84c138") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hivacruz") , black : :: std :: borrow :: Cow :: Borrowed ("#202746") , red : :: std :: borrow :: Cow :: Borrowed ("#c94922") , green : :: std :: borrow :: Cow :: Borrowed ("#ac9739") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c08b30") , blue : :: std :: borrow :: Cow :: Borrowed ("#3d8fd1") , purple : :: std :: borrow :: Cow :: Borrowed ("#6679cc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#22a2c9") , white : :: std :: borrow :: Cow :: Borrowed ("#979db4") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#6b7394") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c76b29") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#73ad43") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#5e6687") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#898ea4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#dfe2f1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#9c637a") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f5f7ff") , background : :: std :: borrow :: Cow :: Borrowed ("#132638") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ede4e4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Homebrew") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#990000") , green : :: std :: borrow :: Cow :: Borrowed ("#00a600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#999900") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000b2") , purple : :: std :: borrow :: Cow :: Borrowed ("#b200b2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00a6b2") , white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e50000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00d900") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e5e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#00ff00") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hopscotch.256") , black : :: std :: borrow :: Cow :: Borrowed ("#322931") , red : :: std :: borrow :: Cow :: Borrowed ("#dd464c") , green : :: std :: borrow :: Cow :: Borrowed ("#8fc13e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fdcc59") , blue : :: std :: borrow :: Cow :: Borrowed ("#1290bf") , purple : :: std :: borrow :: Cow :: Borrowed ("#c85e7c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#149b93") , white : :: std :: borrow :: Cow :: Borrowed ("#b9b5b8") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#797379") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dd464c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8fc13e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdcc59") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1290bf") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c85e7c") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#149b93") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#322931") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b9b5b8") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hopscotch") , black : :: std :: borrow :: Cow :: Borrowed ("#322931") , red : :: std :: borrow :: Cow :: Borrowed ("#dd464c") , green : :: std :: borrow :: Cow :: Borrowed ("#8fc13e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fdcc59") , blue : :: std :: borrow :: Cow :: Borrowed ("#1290bf") , purple : :: std :: borrow :: Cow :: Borrowed ("#c85e7c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#149b93") , white : :: std :: borrow :: Cow :: Borrowed ("#b9b5b8") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#797379") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fd8b19") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#433b42") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#5c545b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#989498") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d5d3d5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#b33508") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#322931") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b9b5b8") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hurtado") , black : :: std :: borrow :: Cow :: Borrowed ("#575757") , red : :: std :: borrow :: Cow :: Borrowed ("#ff1b00") , green : :: std :: borrow :: Cow :: Borrowed ("#a5e055") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fbe74a") , blue : :: std :: borrow :: Cow :: Borrowed ("#496487") , purple : :: std :: borrow :: Cow :: Borrowed ("#fd5ff1") , cyan : :: std :: borrow :: Cow :: Borrowed ("#86e9fe") , white : :: std :: borrow :: Cow :: Borrowed ("#cbcccb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#262626") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d51d00") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#a5df55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fbe84a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#89beff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c001c1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#86eafe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#dbdbdb") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dbdbdb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Hybrid") , black : :: std :: borrow :: Cow :: Borrowed ("#2a2e33") , red : :: std :: borrow :: Cow :: Borrowed ("#b84d51") , green : :: std :: borrow :: Cow :: Borrowed ("#b3bf5a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e4b55e") , blue : :: std :: borrow :: Cow :: Borrowed ("#6e90b0") , purple : :: std :: borrow :: Cow :: Borrowed ("#a17eac") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7fbfb4") , white : :: std :: borrow :: Cow :: Borrowed ("#b5b9b6") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#1d1f22") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#8d2e32") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#798431") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e58a50") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4b6b88") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#6e5079") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#4d7b74") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#5a626a") , background : :: std :: borrow :: Cow :: Borrowed ("#161719") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b7bcba") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("IC_Green_PPL") , black : :: std :: borrow :: Cow :: Borrowed ("#014401") , red : :: std :: borrow :: Cow :: Borrowed ("#ff2736") , green : :: std :: borrow :: Cow :: Borrowed ("#41a638") , yellow : :: std :: borrow :: Cow :: Borrowed ("#76a831") , blue : :: std :: borrow :: Cow :: Borrowed ("#2ec3b9") , purple : :: std :: borrow :: Cow :: Borrowed ("#50a096") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3ca078") , white : :: std :: borrow :: Cow :: Borrowed ("#e6fef2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#035c03") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#b4fa5c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#aefb86") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#dafa87") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2efaeb") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#50fafa") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3cfac8") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e0f1dc") , background : :: std :: borrow :: Cow :: Borrowed ("#2c2c2c") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e0f1dc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("IC_Orange_PPL") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#c13900") , green : :: std :: borrow :: Cow :: Borrowed ("#a4a900") , yellow : :: std :: borrow :: Cow :: Borrowed ("#caaf00") , blue : :: std :: borrow :: Cow :: Borrowed ("#bd6d00") , purple : :: std :: borrow :: Cow :: Borrowed ("#fc5e00") , cyan : :: std :: borrow :: Cow :: Borrowed ("#f79500") , white : :: std :: borrow :: Cow :: Borrowed ("#ffc88a") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#6a4f2a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff8c68") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#f6ff40") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe36e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#ffbe55") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fc874f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#c69752") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fafaff") , background : :: std :: borrow :: Cow :: Borrowed ("#262626") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffcb83") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("IR_Black") , black : :: std :: borrow :: Cow :: Borrowed ("#4f4f4f") , red : :: std :: borrow :: Cow :: Borrowed ("#fa6c60") , green : :: std :: borrow :: Cow :: Borrowed ("#a8ff60") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fffeb7") , blue : :: std :: borrow :: Cow :: Borrowed ("#96cafe") , purple : :: std :: borrow :: Cow :: Borrowed ("#fa73fd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#c6c5fe") , white : :: std :: borrow :: Cow :: Borrowed ("#efedef") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7b7b7b") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fcb6b0") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#cfffab") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffffcc") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#b5dcff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fb9cfe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#e0e0fe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Jackie Brown") , black : :: std :: borrow :: Cow :: Borrowed ("#2c1d16") , red : :: std :: borrow :: Cow :: Borrowed ("#ef5734") , green : :: std :: borrow :: Cow :: Borrowed ("#2baf2b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#bebf00") , blue : :: std :: borrow :: Cow :: Borrowed ("#246eb2") , purple : :: std :: borrow :: Cow :: Borrowed ("#d05ec1") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00acee") , white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e50000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#86a93e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e5e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#2c1d16") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffcc2f") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Japanesque") , black : :: std :: borrow :: Cow :: Borrowed ("#343935") , red : :: std :: borrow :: Cow :: Borrowed ("#cf3f61") , green : :: std :: borrow :: Cow :: Borrowed ("#7bb75b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e9b32a") , blue : :: std :: borrow :: Cow :: Borrowed ("#4c9ad4") , purple : :: std :: borrow :: Cow :: Borrowed ("#a57fc4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#389aad") , white : :: std :: borrow :: Cow :: Borrowed ("#fafaf6") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#595b59") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d18fa6") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#767f2c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#78592f") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#135979") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#604291") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#76bbca") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#b2b5ae") , background : :: std :: borrow :: Cow :: Borrowed ("#1e1e1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f7f6ec") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Jellybeans") , black : :: std :: borrow :: Cow :: Borrowed ("#929292") , red : :: std :: borrow :: Cow :: Borrowed ("#e27373") , green : :: std :: borrow :: Cow :: Borrowed ("#94b979") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffba7b") , blue : :: std :: borrow :: Cow :: Borrowed ("#97bedc") , purple : :: std :: borrow :: Cow :: Borrowed ("#e1c0fa") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00988e") , white : :: std :: borrow :: Cow :: Borrowed ("#dedede") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#bdbdbd") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ffa1a1") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#bddeab") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffdca0") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#b1d8f6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fbdaff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#1ab2a8") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#121212") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dedede") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("JetBrains Darcula") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#fa5355") , green : :: std :: borrow :: Cow :: Borrowed ("#126e00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c2c300") , blue : :: std :: borrow :: Cow :: Borrowed ("#4581eb") , purple : :: std :: borrow :: Cow :: Borrowed ("#fa54ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#33c2c1") , white : :: std :: borrow :: Cow :: Borrowed ("#adadad") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb7172") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#67ff4f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6d9df1") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fb82ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#60d3d1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#eeeeee") , background : :: std :: borrow :: Cow :: Borrowed ("#202020") , foreground : :: std :: borrow :: Cow :: Borrowed ("#adadad") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Kibble") , black : :: std :: borrow :: Cow :: Borrowed ("#4d4d4d") , red : :: std :: borrow :: Cow :: Borrowed ("#c70031") , green : :: std :: borrow :: Cow :: Borrowed ("#29cf13") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d8e30e") , blue : :: std :: borrow :: Cow :: Borrowed ("#3449d1") , purple : :: std :: borrow :: Cow :: Borrowed ("#8400ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#0798ab") , white : :: std :: borrow :: Cow :: Borrowed ("#e2d1e3") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5a5a5a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f01578") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#6ce05c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f3f79e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#97a4f7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c495f0") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#68f2e0") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0e100a") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Kolorit") , black : :: std :: borrow :: Cow :: Borrowed ("#1d1a1e") , red : :: std :: borrow :: Cow :: Borrowed ("#ff5b82") , green : :: std :: borrow :: Cow :: Borrowed ("#47d7a1") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e8e562") , blue : :: std :: borrow :: Cow :: Borrowed ("#5db4ee") , purple : :: std :: borrow :: Cow :: Borrowed ("#da6cda") , cyan : :: std :: borrow :: Cow :: Borrowed ("#57e9eb") , white : :: std :: borrow :: Cow :: Borrowed ("#ededed") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#1d1a1e") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5b82") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#47d7a1") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e8e562") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5db4ee") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#da6cda") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#57e9eb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ededed") , background : :: std :: borrow :: Cow :: Borrowed ("#1d1a1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#efecec") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Konsolas") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#aa1717") , green : :: std :: borrow :: Cow :: Borrowed ("#18b218") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ebae1f") , blue : :: std :: borrow :: Cow :: Borrowed ("#2323a5") , purple : :: std :: borrow :: Cow :: Borrowed ("#ad1edc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#42b0c8") , white : :: std :: borrow :: Cow :: Borrowed ("#c8c1c1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7b716e") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff4141") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5fff5f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4b4bff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff54ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#69ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#060606") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c8c1c1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Lab Fox") , black : :: std :: borrow :: Cow :: Borrowed ("#2e2e2e") , red : :: std :: borrow :: Cow :: Borrowed ("#fc6d26") , green : :: std :: borrow :: Cow :: Borrowed ("#3eb383") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fca121") , blue : :: std :: borrow :: Cow :: Borrowed ("#db3b21") , purple : :: std :: borrow :: Cow :: Borrowed ("#380d75") , cyan : :: std :: borrow :: Cow :: Borrowed ("#6e49cb") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#464646") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6517") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#53eaa8") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fca013") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#db501f") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#441090") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7d53e7") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#2e2e2e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Laser") , black : :: std :: borrow :: Cow :: Borrowed ("#626262") , red : :: std :: borrow :: Cow :: Borrowed ("#ff8373") , green : :: std :: borrow :: Cow :: Borrowed ("#b4fb73") , yellow : :: std :: borrow :: Cow :: Borrowed ("#09b4bd") , blue : :: std :: borrow :: Cow :: Borrowed ("#fed300") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff90fe") , cyan : :: std :: borrow :: Cow :: Borrowed ("#d1d1fe") , white : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#8f8f8f") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ffc4be") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#d6fcba") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffed5") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#f92883") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffb2fe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#e6e7fe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#030d18") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f106e3") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Later This Evening") , black : :: std :: borrow :: Cow :: Borrowed ("#2b2b2b") , red : :: std :: borrow :: Cow :: Borrowed ("#d45a60") , green : :: std :: borrow :: Cow :: Borrowed ("#afba67") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e5d289") , blue : :: std :: borrow :: Cow :: Borrowed ("#a0bad6") , purple : :: std :: borrow :: Cow :: Borrowed ("#c092d6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#91bfb7") , white : :: std :: borrow :: Cow :: Borrowed ("#3c3d3d") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#454747") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d3232f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#aabb39") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5be39") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6699d6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ab53d6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5fc0ae") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#c1c2c2") , background : :: std :: borrow :: Cow :: Borrowed ("#222222") , foreground : :: std :: borrow :: Cow :: Borrowed ("#959595") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Lavandula") , black : :: std :: borrow :: Cow :: Borrowed ("#230046") , red : :: std :: borrow :: Cow :: Borrowed ("#7d1625") , green : :: std :: borrow :: Cow :: Borrowed ("#337e6f") , yellow : :: std :: borrow :: Cow :: Borrowed ("#7f6f49") , blue : :: std :: borrow :: Cow :: Borrowed ("#4f4a7f") , purple : :: std :: borrow :: Cow :: Borrowed ("#5a3f7f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#58777f") , white : :: std :: borrow :: Cow :: Borrowed ("#736e7d") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#372d46") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e05167") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#52e0c4") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e0c386") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#8e87e0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a776e0") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#9ad4e0") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#8c91fa") , background : :: std :: borrow :: Cow :: Borrowed ("#050014") , foreground : :: std :: borrow :: Cow :: Borrowed ("#736e7d") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("LiquidCarbon") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3030") , green : :: std :: borrow :: Cow :: Borrowed ("#559a70") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ccac00") , blue : :: std :: borrow :: Cow :: Borrowed ("#0099cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc69c8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7ac4cc") , white : :: std :: borrow :: Cow :: Borrowed ("#bccccc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff3030") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#559a70") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ccac00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0099cc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#cc69c8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7ac4cc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#bccccc") , background : :: std :: borrow :: Cow :: Borrowed ("#303030") , foreground : :: std :: borrow :: Cow :: Borrowed ("#afc2c2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("LiquidCarbonTransparent") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3030") , green : :: std :: borrow :: Cow :: Borrowed ("#559a70") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ccac00") , blue : :: std :: borrow :: Cow :: Borrowed ("#0099cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc69c8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7ac4cc") , white : :: std :: borrow :: Cow :: Borrowed ("#bccccc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff3030") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#559a70") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ccac00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0099cc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#cc69c8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7ac4cc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#bccccc") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#afc2c2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("LiquidCarbonTransparentInverse") , black : :: std :: borrow :: Cow :: Borrowed ("#bccccd") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3030") , green : :: std :: borrow :: Cow :: Borrowed ("#559a70") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ccac00") , blue : :: std :: borrow :: Cow :: Borrowed ("#0099cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc69c8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7ac4cc") , white : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff3030") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#559a70") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ccac00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0099cc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#cc69c8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7ac4cc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#000000") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#afc2c2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Man Page") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cc0000") , green : :: std :: borrow :: Cow :: Borrowed ("#00a600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#999900") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000b2") , purple : :: std :: borrow :: Cow :: Borrowed ("#b200b2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00a6b2") , white : :: std :: borrow :: Cow :: Borrowed ("#cccccc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e50000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00d900") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e5e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#fef49c") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Material") , black : :: std :: borrow :: Cow :: Borrowed ("#212121") , red : :: std :: borrow :: Cow :: Borrowed ("#b7141f") , green : :: std :: borrow :: Cow :: Borrowed ("#457b24") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f6981e") , blue : :: std :: borrow :: Cow :: Borrowed ("#134eb2") , purple : :: std :: borrow :: Cow :: Borrowed ("#560088") , cyan : :: std :: borrow :: Cow :: Borrowed ("#0e717c") , white : :: std :: borrow :: Cow :: Borrowed ("#efefef") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#424242") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e83b3f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#7aba3a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffea2e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#54a4f3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#aa4dbc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#26bbd1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , background : :: std :: borrow :: Cow :: Borrowed ("#eaeaea") , foreground : :: std :: borrow :: Cow :: Borrowed ("#232322") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("MaterialDark") , black : :: std :: borrow :: Cow :: Borrowed ("#212121") , red : :: std :: borrow :: Cow :: Borrowed ("#b7141f") , green : :: std :: borrow :: Cow :: Borrowed ("#457b24") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f6981e") , blue : :: std :: borrow :: Cow :: Borrowed ("#134eb2") , purple : :: std :: borrow :: Cow :: Borrowed ("#560088") , cyan : :: std :: borrow :: Cow :: Borrowed ("#0e717c") , white : :: std :: borrow :: Cow :: Borrowed ("#efefef") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#424242") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e83b3f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#7aba3a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffea2e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#54a4f3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#aa4dbc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#26bbd1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , background : :: std :: borrow :: Cow :: Borrowed ("#232322") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("MaterialOcean") , black : :: std :: borrow :: Cow :: Borrowed ("#546e7a") , red : :: std :: borrow :: Cow :: Borrowed ("#ff5370") , green : :: std :: borrow :: Cow :: Borrowed ("#c3e88d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcb6b") , blue : :: std :: borrow :: Cow :: Borrowed ("#82aaff") , purple : :: std :: borrow :: Cow :: Borrowed ("#c792ea") , cyan : :: std :: borrow :: Cow :: Borrowed ("#89ddff") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#546e7a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5370") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#c3e88d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcb6b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#82aaff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c792ea") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#89ddff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0f111a") , foreground : :: std :: borrow :: Cow :: Borrowed ("#8f93a2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Mathias") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#e52222") , green : :: std :: borrow :: Cow :: Borrowed ("#a6e32d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fc951e") , blue : :: std :: borrow :: Cow :: Borrowed ("#c48dff") , purple : :: std :: borrow :: Cow :: Borrowed ("#fa2573") , cyan : :: std :: borrow :: Cow :: Borrowed ("#67d9f0") , white : :: std :: borrow :: Cow :: Borrowed ("#f2f2f2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Medallion") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#b64c00") , green : :: std :: borrow :: Cow :: Borrowed ("#7c8b16") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d3bd26") , blue : :: std :: borrow :: Cow :: Borrowed ("#616bb0") , purple : :: std :: borrow :: Cow :: Borrowed ("#8c5a90") , cyan : :: std :: borrow :: Cow :: Borrowed ("#916c25") , white : :: std :: borrow :: Cow :: Borrowed ("#cac29a") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5e5219") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff9149") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b2ca3b") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe54a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#acb8ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffa0ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ffbc51") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fed698") , background : :: std :: borrow :: Cow :: Borrowed ("#1d1908") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cac296") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Mirage") , black : :: std :: borrow :: Cow :: Borrowed ("#011627") , red : :: std :: borrow :: Cow :: Borrowed ("#ff9999") , green : :: std :: borrow :: Cow :: Borrowed ("#85cc95") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd700") , blue : :: std :: borrow :: Cow :: Borrowed ("#7fb5ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#ddb3ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#21c7a8") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#575656") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff9999") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#85cc95") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd700") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7fb5ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ddb3ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#85cc95") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1b2738") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a6b2c0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Misterioso") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff4242") , green : :: std :: borrow :: Cow :: Borrowed ("#74af68") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffad29") , blue : :: std :: borrow :: Cow :: Borrowed ("#338f86") , purple : :: std :: borrow :: Cow :: Borrowed ("#9414e6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#23d7d7") , white : :: std :: borrow :: Cow :: Borrowed ("#e1e1e0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff3242") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#74cd68") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffb929") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#23d7d7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff37ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00ede1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#2d3743") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e1e1e0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Molokai") , black : :: std :: borrow :: Cow :: Borrowed ("#121212") , red : :: std :: borrow :: Cow :: Borrowed ("#fa2573") , green : :: std :: borrow :: Cow :: Borrowed ("#98e123") , yellow : :: std :: borrow :: Cow :: Borrowed ("#dfd460") , blue : :: std :: borrow :: Cow :: Borrowed ("#1080d0") , purple : :: std :: borrow :: Cow :: Borrowed ("#8700ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#43a8d0") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f6669d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b1e05f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff26d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#00afff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#af87ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#51ceff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#121212") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("MonaLisa") , black : :: std :: borrow :: Cow :: Borrowed ("#351b0e") , red : :: std :: borrow :: Cow :: Borrowed ("#9b291c") , green : :: std :: borrow :: Cow :: Borrowed ("#636232") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c36e28") , blue : :: std :: borrow :: Cow :: Borrowed ("#515c5d") , purple : :: std :: borrow :: Cow :: Borrowed ("#9b1d29") , cyan : :: std :: borrow :: Cow :: Borrowed ("#588056") , white : :: std :: borrow :: Cow :: Borrowed ("#f7d75c") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#874228") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff4331") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b4b264") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ff9566") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#9eb2b4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff5b6a") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8acd8f") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffe598") , background : :: std :: borrow :: Cow :: Borrowed ("#120b0d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f7d66a") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Monokai Remastered") , black : :: std :: borrow :: Cow :: Borrowed ("#1a1a1a") , red : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , green : :: std :: borrow :: Cow :: Borrowed ("#98e024") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fd971f") , blue : :: std :: borrow :: Cow :: Borrowed ("#9d65ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#58d1eb") , white : :: std :: borrow :: Cow :: Borrowed ("#c4c5b5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#625e4c") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98e024") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e0d561") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#9d65ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#58d1eb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f6f6ef") , background : :: std :: borrow :: Cow :: Borrowed ("#0c0c0c") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Monokai Soda") , black : :: std :: borrow :: Cow :: Borrowed ("#1a1a1a") , red : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , green : :: std :: borrow :: Cow :: Borrowed ("#98e024") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fa8419") , blue : :: std :: borrow :: Cow :: Borrowed ("#9d65ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#58d1eb") , white : :: std :: borrow :: Cow :: Borrowed ("#c4c5b5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#625e4c") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98e024") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e0d561") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#9d65ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f4005f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#58d1eb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f6f6ef") , background : :: std :: borrow :: Cow :: Borrowed ("#1a1a1a") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c4c5b5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Monokai Vivid") , black : :: std :: borrow :: Cow :: Borrowed ("#121212") , red : :: std :: borrow :: Cow :: Borrowed ("#fa2934") , green : :: std :: borrow :: Cow :: Borrowed ("#98e123") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fff30a") , blue : :: std :: borrow :: Cow :: Borrowed ("#0443ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#f800f8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#01b6ed") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#838383") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f6669d") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b1e05f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff26d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0443ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f200f6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#51ceff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#121212") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f9f9f9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("N0tch2k") , black : :: std :: borrow :: Cow :: Borrowed ("#383838") , red : :: std :: borrow :: Cow :: Borrowed ("#a95551") , green : :: std :: borrow :: Cow :: Borrowed ("#666666") , yellow : :: std :: borrow :: Cow :: Borrowed ("#a98051") , blue : :: std :: borrow :: Cow :: Borrowed ("#657d3e") , purple : :: std :: borrow :: Cow :: Borrowed ("#767676") , cyan : :: std :: borrow :: Cow :: Borrowed ("#c9c9c9") , white : :: std :: borrow :: Cow :: Borrowed ("#d0b8a3") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#474747") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#a97775") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8c8c8c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#a99175") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#98bd5e") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a3a3a3") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#dcdcdc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d8c8bb") , background : :: std :: borrow :: Cow :: Borrowed ("#222222") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a0a0a0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Neopolitan") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#800000") , green : :: std :: borrow :: Cow :: Borrowed ("#61ce3c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fbde2d") , blue : :: std :: borrow :: Cow :: Borrowed ("#253b76") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff0080") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8da6ce") , white : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#800000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#61ce3c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fbde2d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#253b76") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff0080") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8da6ce") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , background : :: std :: borrow :: Cow :: Borrowed ("#271f19") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Neutron") , black : :: std :: borrow :: Cow :: Borrowed ("#23252b") , red : :: std :: borrow :: Cow :: Borrowed ("#b54036") , green : :: std :: borrow :: Cow :: Borrowed ("#5ab977") , yellow : :: std :: borrow :: Cow :: Borrowed ("#deb566") , blue : :: std :: borrow :: Cow :: Borrowed ("#6a7c93") , purple : :: std :: borrow :: Cow :: Borrowed ("#a4799d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3f94a8") , white : :: std :: borrow :: Cow :: Borrowed ("#e6e8ef") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#23252b") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#b54036") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5ab977") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#deb566") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6a7c93") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a4799d") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3f94a8") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ebedf2") , background : :: std :: borrow :: Cow :: Borrowed ("#1c1e22") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e6e8ef") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Night Owlish Light") , black : :: std :: borrow :: Cow :: Borrowed ("#011627") , red : :: std :: borrow :: Cow :: Borrowed ("#d3423e") , green : :: std :: borrow :: Cow :: Borrowed ("#2aa298") , yellow : :: std :: borrow :: Cow :: Borrowed ("#daaa01") , blue : :: std :: borrow :: Cow :: Borrowed ("#4876d6") , purple : :: std :: borrow :: Cow :: Borrowed ("#403f53") , cyan : :: std :: borrow :: Cow :: Borrowed ("#08916a") , white : :: std :: borrow :: Cow :: Borrowed ("#7a8181") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7a8181") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f76e6e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#49d0c5") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#dac26b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5ca7e4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#697098") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00c990") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#989fb1") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#403f53") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("NightLion v1") , black : :: std :: borrow :: Cow :: Borrowed ("#4c4c4c") , red : :: std :: borrow :: Cow :: Borrowed ("#bb0000") , green : :: std :: borrow :: Cow :: Borrowed ("#5fde8f") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f3f167") , blue : :: std :: borrow :: Cow :: Borrowed ("#276bd8") , purple : :: std :: borrow :: Cow :: Borrowed ("#bb00bb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00dadf") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("NightLion v2") , black : :: std :: borrow :: Cow :: Borrowed ("#4c4c4c") , red : :: std :: borrow :: Cow :: Borrowed ("#bb0000") , green : :: std :: borrow :: Cow :: Borrowed ("#04f623") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f3f167") , blue : :: std :: borrow :: Cow :: Borrowed ("#64d0f0") , purple : :: std :: borrow :: Cow :: Borrowed ("#ce6fdb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00dadf") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#7df71d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#62cbe8") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff9bf5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00ccd8") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#171717") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Nocturnal Winter") , black : :: std :: borrow :: Cow :: Borrowed ("#4d4d4d") , red : :: std :: borrow :: Cow :: Borrowed ("#f12d52") , green : :: std :: borrow :: Cow :: Borrowed ("#09cd7e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f5f17a") , blue : :: std :: borrow :: Cow :: Borrowed ("#3182e0") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff2b6d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#09c87a") , white : :: std :: borrow :: Cow :: Borrowed ("#fcfcfc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#808080") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f16d86") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#0ae78d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffc67") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6096ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff78a2") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#0ae78d") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0d0d17") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e6e5e5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Novel") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cc0000") , green : :: std :: borrow :: Cow :: Borrowed ("#009600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d06b00") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc00cc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#0087cc") , white : :: std :: borrow :: Cow :: Borrowed ("#cccccc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#808080") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#cc0000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#009600") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d06b00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000cc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#cc00cc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#0087cc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#dfdbc3") , foreground : :: std :: borrow :: Cow :: Borrowed ("#3b2322") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Obsidian") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#a60001") , green : :: std :: borrow :: Cow :: Borrowed ("#00bb00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fecd22") , blue : :: std :: borrow :: Cow :: Borrowed ("#3a9bdb") , purple : :: std :: borrow :: Cow :: Borrowed ("#bb00bb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00bbbb") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0003") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#93c863") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fef874") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#a1d7ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#283033") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cdcdcd") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Ocean") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#990000") , green : :: std :: borrow :: Cow :: Borrowed ("#00a600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#999900") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000b2") , purple : :: std :: borrow :: Cow :: Borrowed ("#b200b2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00a6b2") , white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e50000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00d900") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e5e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#224fbc") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("OceanicMaterial") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ee2b2a") , green : :: std :: borrow :: Cow :: Borrowed ("#40a33f") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffea2e") , blue : :: std :: borrow :: Cow :: Borrowed ("#1e80f0") , purple : :: std :: borrow :: Cow :: Borrowed ("#8800a0") , cyan : :: std :: borrow :: Cow :: Borrowed ("#16afca") , white : :: std :: borrow :: Cow :: Borrowed ("#a4a4a4") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#777777") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dc5c60") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#70be71") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff163") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#54a4f3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#aa4dbc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#42c7da") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1c262b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c2c8d7") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Ollie") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ac2e31") , green : :: std :: borrow :: Cow :: Borrowed ("#31ac61") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ac4300") , blue : :: std :: borrow :: Cow :: Borrowed ("#2d57ac") , purple : :: std :: borrow :: Cow :: Borrowed ("#b08528") , cyan : :: std :: borrow :: Cow :: Borrowed ("#1fa6ac") , white : :: std :: borrow :: Cow :: Borrowed ("#8a8eac") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5b3725") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff3d48") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#3bff99") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ff5e1e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4488ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffc21d") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#1ffaff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#5b6ea7") , background : :: std :: borrow :: Cow :: Borrowed ("#222125") , foreground : :: std :: borrow :: Cow :: Borrowed ("#8a8dae") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("OneHalfDark") , black : :: std :: borrow :: Cow :: Borrowed ("#282c34") , red : :: std :: borrow :: Cow :: Borrowed ("#e06c75") , green : :: std :: borrow :: Cow :: Borrowed ("#98c379") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e5c07b") , blue : :: std :: borrow :: Cow :: Borrowed ("#61afef") , purple : :: std :: borrow :: Cow :: Borrowed ("#c678dd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#56b6c2") , white : :: std :: borrow :: Cow :: Borrowed ("#dcdfe4") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#282c34") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e06c75") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98c379") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5c07b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#61afef") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c678dd") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#56b6c2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#dcdfe4") , background : :: std :: borrow :: Cow :: Borrowed ("#282c34") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dcdfe4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("OneHalfLight") , black : :: std :: borrow :: Cow :: Borrowed ("#383a42") , red : :: std :: borrow :: Cow :: Borrowed ("#e45649") , green : :: std :: borrow :: Cow :: Borrowed ("#50a14f") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c18401") , blue : :: std :: borrow :: Cow :: Borrowed ("#0184bc") , purple : :: std :: borrow :: Cow :: Borrowed ("#a626a4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#0997b3") , white : :: std :: borrow :: Cow :: Borrowed ("#fafafa") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#4f525e") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e06c75") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98c379") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5c07b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#61afef") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c678dd") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#56b6c2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#fafafa") , foreground : :: std :: borrow :: Cow :: Borrowed ("#383a42") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Operator Mono Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#5a5a5a") , red : :: std :: borrow :: Cow :: Borrowed ("#ca372d") , green : :: std :: borrow :: Cow :: Borrowed ("#4d7b3a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d4d697") , blue : :: std :: borrow :: Cow :: Borrowed ("#4387cf") , purple : :: std :: borrow :: Cow :: Borrowed ("#b86cb4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#72d5c6") , white : :: std :: borrow :: Cow :: Borrowed ("#ced4cd") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#9a9b99") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c37d62") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#83d0a2") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdfdc5") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#89d3f6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff2c7a") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#82eada") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fdfdf6") , background : :: std :: borrow :: Cow :: Borrowed ("#191919") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c3cac2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Pandora") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff4242") , green : :: std :: borrow :: Cow :: Borrowed ("#74af68") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffad29") , blue : :: std :: borrow :: Cow :: Borrowed ("#338f86") , purple : :: std :: borrow :: Cow :: Borrowed ("#9414e6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#23d7d7") , white : :: std :: borrow :: Cow :: Borrowed ("#e2e2e2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#3f5648") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff3242") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#74cd68") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffb929") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#23d7d7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff37ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00ede1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#141e43") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e1e1e1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Paraiso Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#2f1e2e") , red : :: std :: borrow :: Cow :: Borrowed ("#ef6155") , green : :: std :: borrow :: Cow :: Borrowed ("#48b685") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fec418") , blue : :: std :: borrow :: Cow :: Borrowed ("#06b6ef") , purple : :: std :: borrow :: Cow :: Borrowed ("#815ba4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5bc4bf") , white : :: std :: borrow :: Cow :: Borrowed ("#a39e9b") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#776e71") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ef6155") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#48b685") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fec418") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#06b6ef") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#815ba4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5bc4bf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e7e9db") , background : :: std :: borrow :: Cow :: Borrowed ("#2f1e2e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a39e9b") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Parasio Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#2f1e2e") , red : :: std :: borrow :: Cow :: Borrowed ("#ef6155") , green : :: std :: borrow :: Cow :: Borrowed ("#48b685") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fec418") , blue : :: std :: borrow :: Cow :: Borrowed ("#06b6ef") , purple : :: std :: borrow :: Cow :: Borrowed ("#815ba4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5bc4bf") , white : :: std :: borrow :: Cow :: Borrowed ("#a39e9b") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#776e71") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ef6155") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#48b685") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fec418") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#06b6ef") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#815ba4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5bc4bf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e7e9db") , background : :: std :: borrow :: Cow :: Borrowed ("#2f1e2e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a39e9b") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("PaulMillr") , black : :: std :: borrow :: Cow :: Borrowed ("#2a2a2a") , red : :: std :: borrow :: Cow :: Borrowed ("#ff0000") , green : :: std :: borrow :: Cow :: Borrowed ("#79ff0f") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e7bf00") , blue : :: std :: borrow :: Cow :: Borrowed ("#396bd7") , purple : :: std :: borrow :: Cow :: Borrowed ("#b449be") , cyan : :: std :: borrow :: Cow :: Borrowed ("#66ccff") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0080") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#66ff66") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f3d64e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#709aed") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#db67e6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7adff2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f2f2f2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("PencilDark") , black : :: std :: borrow :: Cow :: Borrowed ("#212121") , red : :: std :: borrow :: Cow :: Borrowed ("#c30771") , green : :: std :: borrow :: Cow :: Borrowed ("#10a778") , yellow : :: std :: borrow :: Cow :: Borrowed ("#a89c14") , blue : :: std :: borrow :: Cow :: Borrowed ("#008ec4") , purple : :: std :: borrow :: Cow :: Borrowed ("#523c79") , cyan : :: std :: borrow :: Cow :: Borrowed ("#20a5ba") , white : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#424242") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb007a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5fd7af") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f3e430") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#20bbfc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#6855de") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#4fb8cc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , background : :: std :: borrow :: Cow :: Borrowed ("#212121") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("PencilLight") , black : :: std :: borrow :: Cow :: Borrowed ("#212121") , red : :: std :: borrow :: Cow :: Borrowed ("#c30771") , green : :: std :: borrow :: Cow :: Borrowed ("#10a778") , yellow : :: std :: borrow :: Cow :: Borrowed ("#a89c14") , blue : :: std :: borrow :: Cow :: Borrowed ("#008ec4") , purple : :: std :: borrow :: Cow :: Borrowed ("#523c79") , cyan : :: std :: borrow :: Cow :: Borrowed ("#20a5ba") , white : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#424242") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb007a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5fd7af") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f3e430") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#20bbfc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#6855de") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#4fb8cc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , background : :: std :: borrow :: Cow :: Borrowed ("#f1f1f1") , foreground : :: std :: borrow :: Cow :: Borrowed ("#424242") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Piatto Light") , black : :: std :: borrow :: Cow :: Borrowed ("#414141") , red : :: std :: borrow :: Cow :: Borrowed ("#b23771") , green : :: std :: borrow :: Cow :: Borrowed ("#66781e") , yellow : :: std :: borrow :: Cow :: Borrowed ("#cd6f34") , blue : :: std :: borrow :: Cow :: Borrowed ("#3c5ea8") , purple : :: std :: borrow :: Cow :: Borrowed ("#a454b2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#66781e") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#3f3f3f") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#db3365") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#829429") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#cd6f34") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3c5ea8") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a454b2") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#829429") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f2f2f2") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#414141") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Pnevma") , black : :: std :: borrow :: Cow :: Borrowed ("#2f2e2d") , red : :: std :: borrow :: Cow :: Borrowed ("#a36666") , green : :: std :: borrow :: Cow :: Borrowed ("#90a57d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d7af87") , blue : :: std :: borrow :: Cow :: Borrowed ("#7fa5bd") , purple : :: std :: borrow :: Cow :: Borrowed ("#c79ec4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8adbb4") , white : :: std :: borrow :: Cow :: Borrowed ("#d0d0d0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#4a4845") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d78787") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#afbea2") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e4c9af") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#a1bdce") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d7beda") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#b1e7dd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#efefef") , background : :: std :: borrow :: Cow :: Borrowed ("#1c1c1c") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d0d0d0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Pro Light") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#e5492b") , green : :: std :: borrow :: Cow :: Borrowed ("#50d148") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c6c440") , blue : :: std :: borrow :: Cow :: Borrowed ("#3b75ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#ed66e8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#4ed2de") , white : :: std :: borrow :: Cow :: Borrowed ("#dcdcdc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#9f9f9f") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6640") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#61ef57") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f2f156") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0082ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff7eff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#61f7f8") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f2f2f2") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#191919") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Pro") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#990000") , green : :: std :: borrow :: Cow :: Borrowed ("#00a600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#999900") , blue : :: std :: borrow :: Cow :: Borrowed ("#2009db") , purple : :: std :: borrow :: Cow :: Borrowed ("#b200b2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00a6b2") , white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e50000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00d900") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e5e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f2f2f2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Purple Rain") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff260e") , green : :: std :: borrow :: Cow :: Borrowed ("#9be205") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc400") , blue : :: std :: borrow :: Cow :: Borrowed ("#00a2fa") , purple : :: std :: borrow :: Cow :: Borrowed ("#815bb5") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00deef") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#565656") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff4250") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b8e36e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd852") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#00a6ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ac7bf0") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#74fdf3") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#21084a") , foreground : :: std :: borrow :: Cow :: Borrowed ("#fffbf6") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Red Alert") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#d62e4e") , green : :: std :: borrow :: Cow :: Borrowed ("#71be6b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#beb86b") , blue : :: std :: borrow :: Cow :: Borrowed ("#489bee") , purple : :: std :: borrow :: Cow :: Borrowed ("#e979d7") , cyan : :: std :: borrow :: Cow :: Borrowed ("#6bbeb8") , white : :: std :: borrow :: Cow :: Borrowed ("#d6d6d6") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#262626") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e02553") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#aff08c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#dfddb7") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#65aaf1") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ddb7df") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#b7dfdd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#762423") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Red Planet") , black : :: std :: borrow :: Cow :: Borrowed ("#202020") , red : :: std :: borrow :: Cow :: Borrowed ("#8c3432") , green : :: std :: borrow :: Cow :: Borrowed ("#728271") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e8bf6a") , blue : :: std :: borrow :: Cow :: Borrowed ("#69819e") , purple : :: std :: borrow :: Cow :: Borrowed ("#896492") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5b8390") , white : :: std :: borrow :: Cow :: Borrowed ("#b9aa99") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#676767") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#b55242") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#869985") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ebeb91") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#60827e") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#de4974") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#38add8") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d6bfb8") , background : :: std :: borrow :: Cow :: Borrowed ("#222222") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c2b790") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Red Sands") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3f00") , green : :: std :: borrow :: Cow :: Borrowed ("#00bb00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e7b000") , blue : :: std :: borrow :: Cow :: Borrowed ("#0072ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#bb00bb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00bbbb") , white : :: std :: borrow :: Cow :: Borrowed ("#bbbbbb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bb0000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00bb00") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e7b000") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0072ae") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#7a251e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d7c9a7") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Relaxed") , black : :: std :: borrow :: Cow :: Borrowed ("#151515") , red : :: std :: borrow :: Cow :: Borrowed ("#bc5653") , green : :: std :: borrow :: Cow :: Borrowed ("#909d63") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ebc17a") , blue : :: std :: borrow :: Cow :: Borrowed ("#6a8799") , purple : :: std :: borrow :: Cow :: Borrowed ("#b06698") , cyan : :: std :: borrow :: Cow :: Borrowed ("#c9dfff") , white : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#636363") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bc5653") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#a0ac77") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ebc17a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7eaac7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#b06698") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#acbbd0") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , background : :: std :: borrow :: Cow :: Borrowed ("#353a44") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d9d9d9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Rippedcasts") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cdaf95") , green : :: std :: borrow :: Cow :: Borrowed ("#a8ff60") , yellow : :: std :: borrow :: Cow :: Borrowed ("#bfbb1f") , blue : :: std :: borrow :: Cow :: Borrowed ("#75a5b0") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff73fd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5a647e") , white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#eecbad") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#bcee68") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#86bdc9") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8c9bc4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#2b2b2b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Rouge 2") , black : :: std :: borrow :: Cow :: Borrowed ("#5d5d6b") , red : :: std :: borrow :: Cow :: Borrowed ("#c6797e") , green : :: std :: borrow :: Cow :: Borrowed ("#969e92") , yellow : :: std :: borrow :: Cow :: Borrowed ("#dbcdab") , blue : :: std :: borrow :: Cow :: Borrowed ("#6e94b9") , purple : :: std :: borrow :: Cow :: Borrowed ("#4c4e78") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8ab6c1") , white : :: std :: borrow :: Cow :: Borrowed ("#e8e8ea") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#616274") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c6797e") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#e6dcc4") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e6dcc4") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#98b3cd") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8283a1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#abcbd3") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e8e8ea") , background : :: std :: borrow :: Cow :: Borrowed ("#17182b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a2a3aa") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Royal") , black : :: std :: borrow :: Cow :: Borrowed ("#241f2b") , red : :: std :: borrow :: Cow :: Borrowed ("#91284c") , green : :: std :: borrow :: Cow :: Borrowed ("#23801c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b49d27") , blue : :: std :: borrow :: Cow :: Borrowed ("#6580b0") , purple : :: std :: borrow :: Cow :: Borrowed ("#674d96") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8aaabe") , white : :: std :: borrow :: Cow :: Borrowed ("#524966") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#312d3d") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d5356c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#2cd946") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fde83b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#90baf9") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a479e3") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#acd4eb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#9e8cbd") , background : :: std :: borrow :: Cow :: Borrowed ("#100815") , foreground : :: std :: borrow :: Cow :: Borrowed ("#514968") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Ryuuko") , black : :: std :: borrow :: Cow :: Borrowed ("#2c3941") , red : :: std :: borrow :: Cow :: Borrowed ("#865f5b") , green : :: std :: borrow :: Cow :: Borrowed ("#66907d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b1a990") , blue : :: std :: borrow :: Cow :: Borrowed ("#6a8e95") , purple : :: std :: borrow :: Cow :: Borrowed ("#b18a73") , cyan : :: std :: borrow :: Cow :: Borrowed ("#88b2ac") , white : :: std :: borrow :: Cow :: Borrowed ("#ececec") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5d7079") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#865f5b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#66907d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#b1a990") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6a8e95") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#b18a73") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#88b2ac") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ececec") , background : :: std :: borrow :: Cow :: Borrowed ("#2c3941") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ececec") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Sakura") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#d52370") , green : :: std :: borrow :: Cow :: Borrowed ("#41af1a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#bc7053") , blue : :: std :: borrow :: Cow :: Borrowed ("#6964ab") , purple : :: std :: borrow :: Cow :: Borrowed ("#c71fbf") , cyan : :: std :: borrow :: Cow :: Borrowed ("#939393") , white : :: std :: borrow :: Cow :: Borrowed ("#998eac") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#786d69") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f41d99") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#22e529") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f59574") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#9892f1") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e90cdd") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#eeeeee") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#cbb6ff") , background : :: std :: borrow :: Cow :: Borrowed ("#18131e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dd7bdc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Scarlet Protocol") , black : :: std :: borrow :: Cow :: Borrowed ("#101116") , red : :: std :: borrow :: Cow :: Borrowed ("#ff0051") , green : :: std :: borrow :: Cow :: Borrowed ("#00dc84") , yellow : :: std :: borrow :: Cow :: Borrowed ("#faf945") , blue : :: std :: borrow :: Cow :: Borrowed ("#0271b6") , purple : :: std :: borrow :: Cow :: Borrowed ("#ca30c7") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00c5c7") , white : :: std :: borrow :: Cow :: Borrowed ("#c7c7c7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#686868") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6e67") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5ffa68") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffc67") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6871ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#bd35ec") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#60fdff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1c153d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e41951") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("SeaShells") , black : :: std :: borrow :: Cow :: Borrowed ("#17384c") , red : :: std :: borrow :: Cow :: Borrowed ("#d15123") , green : :: std :: borrow :: Cow :: Borrowed ("#027c9b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fca02f") , blue : :: std :: borrow :: Cow :: Borrowed ("#1e4950") , purple : :: std :: borrow :: Cow :: Borrowed ("#68d4f1") , cyan : :: std :: borrow :: Cow :: Borrowed ("#50a3b5") , white : :: std :: borrow :: Cow :: Borrowed ("#deb88d") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#434b53") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d48678") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#628d98") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdd39f") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1bbcdd") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#bbe3ee") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#87acb4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fee4ce") , background : :: std :: borrow :: Cow :: Borrowed ("#09141b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#deb88d") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Seafoam Pastel") , black : :: std :: borrow :: Cow :: Borrowed ("#757575") , red : :: std :: borrow :: Cow :: Borrowed ("#825d4d") , green : :: std :: borrow :: Cow :: Borrowed ("#728c62") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ada16d") , blue : :: std :: borrow :: Cow :: Borrowed ("#4d7b82") , purple : :: std :: borrow :: Cow :: Borrowed ("#8a7267") , cyan : :: std :: borrow :: Cow :: Borrowed ("#729494") , white : :: std :: borrow :: Cow :: Borrowed ("#e0e0e0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#8a8a8a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#cf937a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98d9aa") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fae79d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7ac3cf") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d6b2a1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ade0e0") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e0e0e0") , background : :: std :: borrow :: Cow :: Borrowed ("#243435") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d4e7d4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Seti") , black : :: std :: borrow :: Cow :: Borrowed ("#323232") , red : :: std :: borrow :: Cow :: Borrowed ("#c22832") , green : :: std :: borrow :: Cow :: Borrowed ("#8ec43d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e0c64f") , blue : :: std :: borrow :: Cow :: Borrowed ("#43a5d5") , purple : :: std :: borrow :: Cow :: Borrowed ("#8b57b5") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8ec43d") , white : :: std :: borrow :: Cow :: Borrowed ("#eeeeee") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c22832") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8ec43d") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e0c64f") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#43a5d5") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8b57b5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8ec43d") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#111213") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cacecd") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Shaman") , black : :: std :: borrow :: Cow :: Borrowed ("#012026") , red : :: std :: borrow :: Cow :: Borrowed ("#b2302d") , green : :: std :: borrow :: Cow :: Borrowed ("#00a941") , yellow : :: std :: borrow :: Cow :: Borrowed ("#5e8baa") , blue : :: std :: borrow :: Cow :: Borrowed ("#449a86") , purple : :: std :: borrow :: Cow :: Borrowed ("#00599d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5d7e19") , white : :: std :: borrow :: Cow :: Borrowed ("#405555") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#384451") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff4242") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#2aea5e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#8ed4fd") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#61d5ba") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#1298ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#98d028") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#58fbd6") , background : :: std :: borrow :: Cow :: Borrowed ("#001015") , foreground : :: std :: borrow :: Cow :: Borrowed ("#405555") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Slate") , black : :: std :: borrow :: Cow :: Borrowed ("#222222") , red : :: std :: borrow :: Cow :: Borrowed ("#e2a8bf") , green : :: std :: borrow :: Cow :: Borrowed ("#81d778") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c4c9c0") , blue : :: std :: borrow :: Cow :: Borrowed ("#264b49") , purple : :: std :: borrow :: Cow :: Borrowed ("#a481d3") , cyan : :: std :: borrow :: Cow :: Borrowed ("#15ab9c") , white : :: std :: borrow :: Cow :: Borrowed ("#02c5e0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ffcdd9") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#beffa8") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d0ccca") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7ab0d2") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c5a7d9") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8cdfe0") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e0e0e0") , background : :: std :: borrow :: Cow :: Borrowed ("#222222") , foreground : :: std :: borrow :: Cow :: Borrowed ("#35b1d2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("SleepyHollow") , black : :: std :: borrow :: Cow :: Borrowed ("#572100") , red : :: std :: borrow :: Cow :: Borrowed ("#ba3934") , green : :: std :: borrow :: Cow :: Borrowed ("#91773f") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b55600") , blue : :: std :: borrow :: Cow :: Borrowed ("#5f63b4") , purple : :: std :: borrow :: Cow :: Borrowed ("#a17c7b") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8faea9") , white : :: std :: borrow :: Cow :: Borrowed ("#af9a91") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#4e4b61") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d9443f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#d6b04e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f66813") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#8086ef") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e2c2bb") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#a4dce7") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d2c7a9") , background : :: std :: borrow :: Cow :: Borrowed ("#121214") , foreground : :: std :: borrow :: Cow :: Borrowed ("#af9a91") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Smyck") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#b84131") , green : :: std :: borrow :: Cow :: Borrowed ("#7da900") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c4a500") , blue : :: std :: borrow :: Cow :: Borrowed ("#62a3c4") , purple : :: std :: borrow :: Cow :: Borrowed ("#ba8acc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#207383") , white : :: std :: borrow :: Cow :: Borrowed ("#a1a1a1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7a7a7a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d6837c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#c4f137") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fee14d") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#8dcff0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f79aff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#6ad9cf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , background : :: std :: borrow :: Cow :: Borrowed ("#1b1b1b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f7f7f7") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Snazzy") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#fc4346") , green : :: std :: borrow :: Cow :: Borrowed ("#50fb7c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f0fb8c") , blue : :: std :: borrow :: Cow :: Borrowed ("#49baff") , purple : :: std :: borrow :: Cow :: Borrowed ("#fc4cb4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8be9fe") , white : :: std :: borrow :: Cow :: Borrowed ("#ededec") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fc4346") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#50fb7c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f0fb8c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#49baff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fc4cb4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8be9fe") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ededec") , background : :: std :: borrow :: Cow :: Borrowed ("#1e1f29") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ebece6") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("SoftServer") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#a2686a") , green : :: std :: borrow :: Cow :: Borrowed ("#9aa56a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#a3906a") , blue : :: std :: borrow :: Cow :: Borrowed ("#6b8fa3") , purple : :: std :: borrow :: Cow :: Borrowed ("#6a71a3") , cyan : :: std :: borrow :: Cow :: Borrowed ("#6ba58f") , white : :: std :: borrow :: Cow :: Borrowed ("#99a3a2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666c6c") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dd5c60") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#bfdf55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#deb360") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#62b1df") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#606edf") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#64e39c") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d2e0de") , background : :: std :: borrow :: Cow :: Borrowed ("#242626") , foreground : :: std :: borrow :: Cow :: Borrowed ("#99a3a2") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Solarized Darcula") , black : :: std :: borrow :: Cow :: Borrowed ("#25292a") , red : :: std :: borrow :: Cow :: Borrowed ("#f24840") , green : :: std :: borrow :: Cow :: Borrowed ("#629655") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b68800") , blue : :: std :: borrow :: Cow :: Borrowed ("#2075c7") , purple : :: std :: borrow :: Cow :: Borrowed ("#797fd4") , cyan : :: std :: borrow :: Cow :: Borrowed ("#15968d") , white : :: std :: borrow :: Cow :: Borrowed ("#d2d8d9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#25292a") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f24840") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#629655") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#b68800") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2075c7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#797fd4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#15968d") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d2d8d9") , background : :: std :: borrow :: Cow :: Borrowed ("#3d3f41") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d2d8d9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Solarized Dark - Patched") , black : :: std :: borrow :: Cow :: Borrowed ("#002831") , red : :: std :: borrow :: Cow :: Borrowed ("#d11c24") , green : :: std :: borrow :: Cow :: Borrowed ("#738a05") , yellow : :: std :: borrow :: Cow :: Borrowed ("#a57706") , blue : :: std :: borrow :: Cow :: Borrowed ("#2176c7") , purple : :: std :: borrow :: Cow :: Borrowed ("#c61c6f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#259286") , white : :: std :: borrow :: Cow :: Borrowed ("#eae3cb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#475b62") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bd3613") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#475b62") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#536870") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#708284") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#5956ba") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#819090") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fcf4dc") , background : :: std :: borrow :: Cow :: Borrowed ("#001e27") , foreground : :: std :: borrow :: Cow :: Borrowed ("#708284") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Solarized Dark Higher Contrast") , black : :: std :: borrow :: Cow :: Borrowed ("#002831") , red : :: std :: borrow :: Cow :: Borrowed ("#d11c24") , green : :: std :: borrow :: Cow :: Borrowed ("#6cbe6c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#a57706") , blue : :: std :: borrow :: Cow :: Borrowed ("#2176c7") , purple : :: std :: borrow :: Cow :: Borrowed ("#c61c6f") , cyan : :: std :: borrow :: Cow :: Borrowed ("#259286") , white : :: std :: borrow :: Cow :: Borrowed ("#eae3cb") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#006488") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f5163b") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#51ef84") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#b27e28") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#178ec8") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e24d8e") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00b39e") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fcf4dc") , background : :: std :: borrow :: Cow :: Borrowed ("#001e27") , foreground : :: std :: borrow :: Cow :: Borrowed ("#9cc2c3") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("SpaceGray Eighties Dull") , black : :: std :: borrow :: Cow :: Borrowed ("#15171c") , red : :: std :: borrow :: Cow :: Borrowed ("#b24a56") , green : :: std :: borrow :: Cow :: Borrowed ("#92b477") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c6735a") , blue : :: std :: borrow :: Cow :: Borrowed ("#7c8fa5") , purple : :: std :: borrow :: Cow :: Borrowed ("#a5789e") , cyan : :: std :: borrow :: Cow :: Borrowed ("#80cdcb") , white : :: std :: borrow :: Cow :: Borrowed ("#b3b8c3") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ec5f67") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#89e986") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fec254") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5486c0") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#bf83c1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#58c2c1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#222222") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c9c6bc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("SpaceGray Eighties") , black : :: std :: borrow :: Cow :: Borrowed ("#15171c") , red : :: std :: borrow :: Cow :: Borrowed ("#ec5f67") , green : :: std :: borrow :: Cow :: Borrowed ("#81a764") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fec254") , blue : :: std :: borrow :: Cow :: Borrowed ("#5486c0") , purple : :: std :: borrow :: Cow :: Borrowed ("#bf83c1") , cyan : :: std :: borrow :: Cow :: Borrowed ("#57c2c1") , white : :: std :: borrow :: Cow :: Borrowed ("#efece7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6973") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#93d493") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd256") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4d84d1") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#83e9e4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#222222") , foreground : :: std :: borrow :: Cow :: Borrowed ("#bdbaae") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("SpaceGray") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#b04b57") , green : :: std :: borrow :: Cow :: Borrowed ("#87b379") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e5c179") , blue : :: std :: borrow :: Cow :: Borrowed ("#7d8fa4") , purple : :: std :: borrow :: Cow :: Borrowed ("#a47996") , cyan : :: std :: borrow :: Cow :: Borrowed ("#85a7a5") , white : :: std :: borrow :: Cow :: Borrowed ("#b3b8c3") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#b04b57") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#87b379") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5c179") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7d8fa4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a47996") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#85a7a5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#20242d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b3b8c3") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Spacedust") , black : :: std :: borrow :: Cow :: Borrowed ("#6e5346") , red : :: std :: borrow :: Cow :: Borrowed ("#e35b00") , green : :: std :: borrow :: Cow :: Borrowed ("#5cab96") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e3cd7b") , blue : :: std :: borrow :: Cow :: Borrowed ("#0f548b") , purple : :: std :: borrow :: Cow :: Borrowed ("#e35b00") , cyan : :: std :: borrow :: Cow :: Borrowed ("#06afc7") , white : :: std :: borrow :: Cow :: Borrowed ("#f0f1ce") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#684c31") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff8a3a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#aecab8") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc878") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#67a0ce") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff8a3a") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#83a7b4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fefff1") , background : :: std :: borrow :: Cow :: Borrowed ("#0a1e24") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ecf0c1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Spiderman") , black : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , red : :: std :: borrow :: Cow :: Borrowed ("#e60813") , green : :: std :: borrow :: Cow :: Borrowed ("#e22928") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e24756") , blue : :: std :: borrow :: Cow :: Borrowed ("#2c3fff") , purple : :: std :: borrow :: Cow :: Borrowed ("#2435db") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3256ff") , white : :: std :: borrow :: Cow :: Borrowed ("#fffef6") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#505354") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0325") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#ff3338") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fe3a35") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1d50ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#747cff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#6184ff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fffff9") , background : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e3e3e3") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Spring") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff4d83") , green : :: std :: borrow :: Cow :: Borrowed ("#1f8c3b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#1fc95b") , blue : :: std :: borrow :: Cow :: Borrowed ("#1dd3ee") , purple : :: std :: borrow :: Cow :: Borrowed ("#8959a8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3e999f") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0021") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#1fc231") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d5b807") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#15a9fd") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8959a8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3e999f") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#4d4d4c") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Square") , black : :: std :: borrow :: Cow :: Borrowed ("#050505") , red : :: std :: borrow :: Cow :: Borrowed ("#e9897c") , green : :: std :: borrow :: Cow :: Borrowed ("#b6377d") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ecebbe") , blue : :: std :: borrow :: Cow :: Borrowed ("#a9cdeb") , purple : :: std :: borrow :: Cow :: Borrowed ("#75507b") , cyan : :: std :: borrow :: Cow :: Borrowed ("#c9caec") , white : :: std :: borrow :: Cow :: Borrowed ("#f2f2f2") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#141414") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f99286") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#c3f786") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fcfbcc") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#b6defb") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ad7fa8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#d7d9fc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e2e2e2") , background : :: std :: borrow :: Cow :: Borrowed ("#1a1a1a") , foreground : :: std :: borrow :: Cow :: Borrowed ("#acacab") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Subliminal") , black : :: std :: borrow :: Cow :: Borrowed ("#7f7f7f") , red : :: std :: borrow :: Cow :: Borrowed ("#e15a60") , green : :: std :: borrow :: Cow :: Borrowed ("#a9cfa4") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe2a9") , blue : :: std :: borrow :: Cow :: Borrowed ("#6699cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#f1a5ab") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5fb3b3") , white : :: std :: borrow :: Cow :: Borrowed ("#d4d4d4") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#7f7f7f") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e15a60") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#a9cfa4") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe2a9") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6699cc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f1a5ab") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5fb3b3") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d4d4d4") , background : :: std :: borrow :: Cow :: Borrowed ("#282c35") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d4d4d4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Sundried") , black : :: std :: borrow :: Cow :: Borrowed ("#302b2a") , red : :: std :: borrow :: Cow :: Borrowed ("#a7463d") , green : :: std :: borrow :: Cow :: Borrowed ("#587744") , yellow : :: std :: borrow :: Cow :: Borrowed ("#9d602a") , blue : :: std :: borrow :: Cow :: Borrowed ("#485b98") , purple : :: std :: borrow :: Cow :: Borrowed ("#864651") , cyan : :: std :: borrow :: Cow :: Borrowed ("#9c814f") , white : :: std :: borrow :: Cow :: Borrowed ("#c9c9c9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#4d4e48") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#aa000c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#128c21") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fc6a21") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7999f7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fd8aa1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#fad484") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1a1818") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c9c9c9") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Symfonic") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#dc322f") , green : :: std :: borrow :: Cow :: Borrowed ("#56db3a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ff8400") , blue : :: std :: borrow :: Cow :: Borrowed ("#0084d4") , purple : :: std :: borrow :: Cow :: Borrowed ("#b729d9") , cyan : :: std :: borrow :: Cow :: Borrowed ("#ccccff") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#1b1d21") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dc322f") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#56db3a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ff8400") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0084d4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#b729d9") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ccccff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tango Adapted") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff0000") , green : :: std :: borrow :: Cow :: Borrowed ("#59d600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f0cb00") , blue : :: std :: borrow :: Cow :: Borrowed ("#00a2ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#c17ecc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00d0d6") , white : :: std :: borrow :: Cow :: Borrowed ("#e6ebe1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#8f928b") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0013") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#93ff00") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff121") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#88c9ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e9a7e1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00feff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f6f6f4") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tango Half Adapted") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff0000") , green : :: std :: borrow :: Cow :: Borrowed ("#4cc300") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e2c000") , blue : :: std :: borrow :: Cow :: Borrowed ("#008ef6") , purple : :: std :: borrow :: Cow :: Borrowed ("#a96cb3") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00bdc3") , white : :: std :: borrow :: Cow :: Borrowed ("#e0e5db") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#797d76") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0013") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8af600") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffec00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#76bfff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d898d1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00f6fa") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f4f4f2") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Teerb") , black : :: std :: borrow :: Cow :: Borrowed ("#1c1c1c") , red : :: std :: borrow :: Cow :: Borrowed ("#d68686") , green : :: std :: borrow :: Cow :: Borrowed ("#aed686") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d7af87") , blue : :: std :: borrow :: Cow :: Borrowed ("#86aed6") , purple : :: std :: borrow :: Cow :: Borrowed ("#d6aed6") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8adbb4") , white : :: std :: borrow :: Cow :: Borrowed ("#d0d0d0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#1c1c1c") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d68686") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#aed686") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e4c9af") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#86aed6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d6aed6") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#b1e7dd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#efefef") , background : :: std :: borrow :: Cow :: Borrowed ("#262626") , foreground : :: std :: borrow :: Cow :: Borrowed ("#d0d0d0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Terminal Basic") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#990000") , green : :: std :: borrow :: Cow :: Borrowed ("#00a600") , yellow : :: std :: borrow :: Cow :: Borrowed ("#999900") , blue : :: std :: borrow :: Cow :: Borrowed ("#0000b2") , purple : :: std :: borrow :: Cow :: Borrowed ("#b200b2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00a6b2") , white : :: std :: borrow :: Cow :: Borrowed ("#bfbfbf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666666") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e50000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00d900") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e5e500") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e500e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00e5e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Thayer Bright") , black : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , red : :: std :: borrow :: Cow :: Borrowed ("#f92672") , green : :: std :: borrow :: Cow :: Borrowed ("#4df840") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f4fd22") , blue : :: std :: borrow :: Cow :: Borrowed ("#2757d6") , purple : :: std :: borrow :: Cow :: Borrowed ("#8c54fe") , cyan : :: std :: borrow :: Cow :: Borrowed ("#38c8b5") , white : :: std :: borrow :: Cow :: Borrowed ("#ccccc6") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#505354") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5995") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b6e354") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#feed6c") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#3f78ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#9e6ffe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#23cfd5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f8f8f2") , background : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f8f8f8") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("The Hulk") , black : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , red : :: std :: borrow :: Cow :: Borrowed ("#269d1b") , green : :: std :: borrow :: Cow :: Borrowed ("#13ce30") , yellow : :: std :: borrow :: Cow :: Borrowed ("#63e457") , blue : :: std :: borrow :: Cow :: Borrowed ("#2525f5") , purple : :: std :: borrow :: Cow :: Borrowed ("#641f74") , cyan : :: std :: borrow :: Cow :: Borrowed ("#378ca9") , white : :: std :: borrow :: Cow :: Borrowed ("#d9d8d1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#505354") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#8dff2a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#48ff77") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#3afe16") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#506b95") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#72589d") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#4085a6") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e6e1") , background : :: std :: borrow :: Cow :: Borrowed ("#1b1d1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b5b5b5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tinacious Design (Dark)") , black : :: std :: borrow :: Cow :: Borrowed ("#1d1d26") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3399") , green : :: std :: borrow :: Cow :: Borrowed ("#00d364") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcc66") , blue : :: std :: borrow :: Cow :: Borrowed ("#00cbff") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc66ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00ceca") , white : :: std :: borrow :: Cow :: Borrowed ("#cbcbf0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#636667") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff2f92") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00d364") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd479") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#00cbff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d783ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00d5d4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d5d6f3") , background : :: std :: borrow :: Cow :: Borrowed ("#1d1d26") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cbcbf0") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tinacious Design (Light)") , black : :: std :: borrow :: Cow :: Borrowed ("#1d1d26") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3399") , green : :: std :: borrow :: Cow :: Borrowed ("#00d364") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcc66") , blue : :: std :: borrow :: Cow :: Borrowed ("#00cbff") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc66ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00ceca") , white : :: std :: borrow :: Cow :: Borrowed ("#cbcbf0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#636667") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff2f92") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00d364") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd479") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#00cbff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d783ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00d5d4") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d5d6f3") , background : :: std :: borrow :: Cow :: Borrowed ("#f8f8ff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#1d1d26") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tomorrow Night Blue") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff9da4") , green : :: std :: borrow :: Cow :: Borrowed ("#d1f1a9") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffeead") , blue : :: std :: borrow :: Cow :: Borrowed ("#bbdaff") , purple : :: std :: borrow :: Cow :: Borrowed ("#ebbbff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#99ffff") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff9da4") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#d1f1a9") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffeead") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#bbdaff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ebbbff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#99ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#002451") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tomorrow Night Bright") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#d54e53") , green : :: std :: borrow :: Cow :: Borrowed ("#b9ca4a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e7c547") , blue : :: std :: borrow :: Cow :: Borrowed ("#7aa6da") , purple : :: std :: borrow :: Cow :: Borrowed ("#c397d8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#70c0b1") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d54e53") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b9ca4a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e7c547") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7aa6da") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c397d8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#70c0b1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#eaeaea") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tomorrow Night Burns") , black : :: std :: borrow :: Cow :: Borrowed ("#252525") , red : :: std :: borrow :: Cow :: Borrowed ("#832e31") , green : :: std :: borrow :: Cow :: Borrowed ("#a63c40") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d3494e") , blue : :: std :: borrow :: Cow :: Borrowed ("#fc595f") , purple : :: std :: borrow :: Cow :: Borrowed ("#df9395") , cyan : :: std :: borrow :: Cow :: Borrowed ("#ba8586") , white : :: std :: borrow :: Cow :: Borrowed ("#f5f5f5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5d6f71") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#832e31") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#a63c40") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#d2494e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#fc595f") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#df9395") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ba8586") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f5f5f5") , background : :: std :: borrow :: Cow :: Borrowed ("#151515") , foreground : :: std :: borrow :: Cow :: Borrowed ("#a1b0b8") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tomorrow Night Eighties") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#f2777a") , green : :: std :: borrow :: Cow :: Borrowed ("#99cc99") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcc66") , blue : :: std :: borrow :: Cow :: Borrowed ("#6699cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc99cc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#66cccc") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f2777a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#99cc99") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcc66") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6699cc") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#cc99cc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#66cccc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#2d2d2d") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cccccc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tomorrow Night") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cc6666") , green : :: std :: borrow :: Cow :: Borrowed ("#b5bd68") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f0c674") , blue : :: std :: borrow :: Cow :: Borrowed ("#81a2be") , purple : :: std :: borrow :: Cow :: Borrowed ("#b294bb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8abeb7") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#cc6666") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b5bd68") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f0c674") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#81a2be") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#b294bb") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8abeb7") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1d1f21") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c5c8c6") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Tomorrow") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#c82829") , green : :: std :: borrow :: Cow :: Borrowed ("#718c00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#eab700") , blue : :: std :: borrow :: Cow :: Borrowed ("#4271ae") , purple : :: std :: borrow :: Cow :: Borrowed ("#8959a8") , cyan : :: std :: borrow :: Cow :: Borrowed ("#3e999f") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#c82829") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#718c00") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#eab700") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4271ae") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#8959a8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3e999f") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#4d4d4c") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("ToyChest") , black : :: std :: borrow :: Cow :: Borrowed ("#2c3f58") , red : :: std :: borrow :: Cow :: Borrowed ("#be2d26") , green : :: std :: borrow :: Cow :: Borrowed ("#1a9172") , yellow : :: std :: borrow :: Cow :: Borrowed ("#db8e27") , blue : :: std :: borrow :: Cow :: Borrowed ("#325d96") , purple : :: std :: borrow :: Cow :: Borrowed ("#8a5edc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#35a08f") , white : :: std :: borrow :: Cow :: Borrowed ("#23d183") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#336889") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dd5944") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#31d07b") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e7d84b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#34a6da") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ae6bdc") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#42c3ae") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d5d5d5") , background : :: std :: borrow :: Cow :: Borrowed ("#24364b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#31d07b") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Treehouse") , black : :: std :: borrow :: Cow :: Borrowed ("#321300") , red : :: std :: borrow :: Cow :: Borrowed ("#b2270e") , green : :: std :: borrow :: Cow :: Borrowed ("#44a900") , yellow : :: std :: borrow :: Cow :: Borrowed ("#aa820c") , blue : :: std :: borrow :: Cow :: Borrowed ("#58859a") , purple : :: std :: borrow :: Cow :: Borrowed ("#97363d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#b25a1e") , white : :: std :: borrow :: Cow :: Borrowed ("#786b53") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#433626") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ed5d20") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55f238") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f2b732") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#85cfed") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e14c5a") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#f07d14") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffc800") , background : :: std :: borrow :: Cow :: Borrowed ("#191919") , foreground : :: std :: borrow :: Cow :: Borrowed ("#786b53") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Twilight") , black : :: std :: borrow :: Cow :: Borrowed ("#141414") , red : :: std :: borrow :: Cow :: Borrowed ("#c06d44") , green : :: std :: borrow :: Cow :: Borrowed ("#afb97a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c2a86c") , blue : :: std :: borrow :: Cow :: Borrowed ("#44474a") , purple : :: std :: borrow :: Cow :: Borrowed ("#b4be7c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#778385") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffd4") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#262626") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#de7c4c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#ccd88c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e2c47e") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5a5e62") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#d0dc8e") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8a989b") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffd4") , background : :: std :: borrow :: Cow :: Borrowed ("#141414") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffd4") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Ubuntu") , black : :: std :: borrow :: Cow :: Borrowed ("#2e3436") , red : :: std :: borrow :: Cow :: Borrowed ("#cc0000") , green : :: std :: borrow :: Cow :: Borrowed ("#4e9a06") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c4a000") , blue : :: std :: borrow :: Cow :: Borrowed ("#3465a4") , purple : :: std :: borrow :: Cow :: Borrowed ("#75507b") , cyan : :: std :: borrow :: Cow :: Borrowed ("#06989a") , white : :: std :: borrow :: Cow :: Borrowed ("#d3d7cf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555753") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ef2929") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#8ae234") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fce94f") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#729fcf") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ad7fa8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#34e2e2") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , background : :: std :: borrow :: Cow :: Borrowed ("#300a24") , foreground : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("UltraViolent") , black : :: std :: borrow :: Cow :: Borrowed ("#242728") , red : :: std :: borrow :: Cow :: Borrowed ("#ff0090") , green : :: std :: borrow :: Cow :: Borrowed ("#b6ff00") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fff727") , blue : :: std :: borrow :: Cow :: Borrowed ("#47e0fb") , purple : :: std :: borrow :: Cow :: Borrowed ("#d731ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#0effbb") , white : :: std :: borrow :: Cow :: Borrowed ("#e1e1e1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#636667") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb58b4") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#deff8c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ebe087") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7fecff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e681ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#69fcd3") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f9f9f5") , background : :: std :: borrow :: Cow :: Borrowed ("#242728") , foreground : :: std :: borrow :: Cow :: Borrowed ("#c1c1c1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("UnderTheSea") , black : :: std :: borrow :: Cow :: Borrowed ("#022026") , red : :: std :: borrow :: Cow :: Borrowed ("#b2302d") , green : :: std :: borrow :: Cow :: Borrowed ("#00a941") , yellow : :: std :: borrow :: Cow :: Borrowed ("#59819c") , blue : :: std :: borrow :: Cow :: Borrowed ("#459a86") , purple : :: std :: borrow :: Cow :: Borrowed ("#00599d") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5d7e19") , white : :: std :: borrow :: Cow :: Borrowed ("#405555") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#384451") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff4242") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#2aea5e") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#8ed4fd") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#61d5ba") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#1298ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#98d028") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#58fbd6") , background : :: std :: borrow :: Cow :: Borrowed ("#011116") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Unikitty") , black : :: std :: borrow :: Cow :: Borrowed ("#0c0c0c") , red : :: std :: borrow :: Cow :: Borrowed ("#a80f20") , green : :: std :: borrow :: Cow :: Borrowed ("#bafc8b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#eedf4b") , blue : :: std :: borrow :: Cow :: Borrowed ("#145fcd") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff36a2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#6bd1bc") , white : :: std :: borrow :: Cow :: Borrowed ("#e2d7e1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#434343") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#d91329") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#d3ffaf") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffef50") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0075ea") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fdd5e5") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#79ecd5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fff3fe") , background : :: std :: borrow :: Cow :: Borrowed ("#ff8cd9") , foreground : :: std :: borrow :: Cow :: Borrowed ("#0b0b0b") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Urple") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#b0425b") , green : :: std :: borrow :: Cow :: Borrowed ("#37a415") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ad5c42") , blue : :: std :: borrow :: Cow :: Borrowed ("#564d9b") , purple : :: std :: borrow :: Cow :: Borrowed ("#6c3ca1") , cyan : :: std :: borrow :: Cow :: Borrowed ("#808080") , white : :: std :: borrow :: Cow :: Borrowed ("#87799c") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#5d3225") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6388") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#29e620") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f08161") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#867aed") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#a05eee") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#eaeaea") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#bfa3ff") , background : :: std :: borrow :: Cow :: Borrowed ("#1b1b23") , foreground : :: std :: borrow :: Cow :: Borrowed ("#877a9b") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Vaughn") , black : :: std :: borrow :: Cow :: Borrowed ("#25234f") , red : :: std :: borrow :: Cow :: Borrowed ("#705050") , green : :: std :: borrow :: Cow :: Borrowed ("#60b48a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#dfaf8f") , blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#f08cc3") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8cd0d3") , white : :: std :: borrow :: Cow :: Borrowed ("#709080") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#709080") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dca3a3") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#60b48a") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f0dfaf") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ec93d3") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#93e0e3") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#25234f") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dcdccc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("VibrantInk") , black : :: std :: borrow :: Cow :: Borrowed ("#878787") , red : :: std :: borrow :: Cow :: Borrowed ("#ff6600") , green : :: std :: borrow :: Cow :: Borrowed ("#ccff04") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffcc00") , blue : :: std :: borrow :: Cow :: Borrowed ("#44b4cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#9933cc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#44b4cc") , white : :: std :: borrow :: Cow :: Borrowed ("#f5f5f5") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff0000") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#00ff00") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff00") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0000ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff00ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#00ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Violet Dark") , black : :: std :: borrow :: Cow :: Borrowed ("#56595c") , red : :: std :: borrow :: Cow :: Borrowed ("#c94c22") , green : :: std :: borrow :: Cow :: Borrowed ("#85981c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b4881d") , blue : :: std :: borrow :: Cow :: Borrowed ("#2e8bce") , purple : :: std :: borrow :: Cow :: Borrowed ("#d13a82") , cyan : :: std :: borrow :: Cow :: Borrowed ("#32a198") , white : :: std :: borrow :: Cow :: Borrowed ("#c9c6bd") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#45484b") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bd3613") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#738a04") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#a57705") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2176c7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c61c6f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#259286") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#c9c6bd") , background : :: std :: borrow :: Cow :: Borrowed ("#1c1d1f") , foreground : :: std :: borrow :: Cow :: Borrowed ("#708284") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Violet Light") , black : :: std :: borrow :: Cow :: Borrowed ("#56595c") , red : :: std :: borrow :: Cow :: Borrowed ("#c94c22") , green : :: std :: borrow :: Cow :: Borrowed ("#85981c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#b4881d") , blue : :: std :: borrow :: Cow :: Borrowed ("#2e8bce") , purple : :: std :: borrow :: Cow :: Borrowed ("#d13a82") , cyan : :: std :: borrow :: Cow :: Borrowed ("#32a198") , white : :: std :: borrow :: Cow :: Borrowed ("#d3d0c9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#45484b") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bd3613") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#738a04") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#a57705") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2176c7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c61c6f") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#259286") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#c9c6bd") , background : :: std :: borrow :: Cow :: Borrowed ("#fcf4dc") , foreground : :: std :: borrow :: Cow :: Borrowed ("#536870") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("WarmNeon") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#e24346") , green : :: std :: borrow :: Cow :: Borrowed ("#39b13a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#dae145") , blue : :: std :: borrow :: Cow :: Borrowed ("#4261c5") , purple : :: std :: borrow :: Cow :: Borrowed ("#f920fb") , cyan : :: std :: borrow :: Cow :: Borrowed ("#2abbd4") , white : :: std :: borrow :: Cow :: Borrowed ("#d0b8a3") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#fefcfc") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#e97071") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#9cc090") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ddda7a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#7b91d6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f674ba") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5ed1e5") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#d8c8bb") , background : :: std :: borrow :: Cow :: Borrowed ("#404040") , foreground : :: std :: borrow :: Cow :: Borrowed ("#afdab6") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Wez") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#cc5555") , green : :: std :: borrow :: Cow :: Borrowed ("#55cc55") , yellow : :: std :: borrow :: Cow :: Borrowed ("#cdcd55") , blue : :: std :: borrow :: Cow :: Borrowed ("#5555cc") , purple : :: std :: borrow :: Cow :: Borrowed ("#cc55cc") , cyan : :: std :: borrow :: Cow :: Borrowed ("#7acaca") , white : :: std :: borrow :: Cow :: Borrowed ("#cccccc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#555555") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff5555") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#55ff55") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff55") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5555ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff55ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#55ffff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b3b3b3") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Whimsy") , black : :: std :: borrow :: Cow :: Borrowed ("#535178") , red : :: std :: borrow :: Cow :: Borrowed ("#ef6487") , green : :: std :: borrow :: Cow :: Borrowed ("#5eca89") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fdd877") , blue : :: std :: borrow :: Cow :: Borrowed ("#65aef7") , purple : :: std :: borrow :: Cow :: Borrowed ("#aa7ff0") , cyan : :: std :: borrow :: Cow :: Borrowed ("#43c1be") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#535178") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ef6487") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5eca89") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdd877") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#65aef7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#aa7ff0") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#43c1be") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#29283b") , foreground : :: std :: borrow :: Cow :: Borrowed ("#b3b0d6") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("WildCherry") , black : :: std :: borrow :: Cow :: Borrowed ("#000507") , red : :: std :: borrow :: Cow :: Borrowed ("#d94085") , green : :: std :: borrow :: Cow :: Borrowed ("#2ab250") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd16f") , blue : :: std :: borrow :: Cow :: Borrowed ("#883cdc") , purple : :: std :: borrow :: Cow :: Borrowed ("#ececec") , cyan : :: std :: borrow :: Cow :: Borrowed ("#c1b8b7") , white : :: std :: borrow :: Cow :: Borrowed ("#fff8de") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#009cc9") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#da6bac") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#f4dca5") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#eac066") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#308cba") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ae636b") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#ff919d") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#e4838d") , background : :: std :: borrow :: Cow :: Borrowed ("#1f1726") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dafaff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Wombat") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff615a") , green : :: std :: borrow :: Cow :: Borrowed ("#b1e969") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ebd99c") , blue : :: std :: borrow :: Cow :: Borrowed ("#5da9f6") , purple : :: std :: borrow :: Cow :: Borrowed ("#e86aff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#82fff7") , white : :: std :: borrow :: Cow :: Borrowed ("#dedacf") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#313131") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f58c80") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#ddf88f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#eee5b2") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#a5c7ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ddaaff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#b7fff9") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#171717") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dedacf") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Wryan") , black : :: std :: borrow :: Cow :: Borrowed ("#333333") , red : :: std :: borrow :: Cow :: Borrowed ("#8c4665") , green : :: std :: borrow :: Cow :: Borrowed ("#287373") , yellow : :: std :: borrow :: Cow :: Borrowed ("#7c7c99") , blue : :: std :: borrow :: Cow :: Borrowed ("#395573") , purple : :: std :: borrow :: Cow :: Borrowed ("#5e468c") , cyan : :: std :: borrow :: Cow :: Borrowed ("#31658c") , white : :: std :: borrow :: Cow :: Borrowed ("#899ca1") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#3d3d3d") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#bf4d80") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#53a6a6") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#9e9ecb") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#477ab3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#7e62b3") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#6096bf") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#c0c0c0") , background : :: std :: borrow :: Cow :: Borrowed ("#101010") , foreground : :: std :: borrow :: Cow :: Borrowed ("#999993") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("Zenburn") , black : :: std :: borrow :: Cow :: Borrowed ("#4d4d4d") , red : :: std :: borrow :: Cow :: Borrowed ("#705050") , green : :: std :: borrow :: Cow :: Borrowed ("#60b48a") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f0dfaf") , blue : :: std :: borrow :: Cow :: Borrowed ("#506070") , purple : :: std :: borrow :: Cow :: Borrowed ("#dc8cc3") , cyan : :: std :: borrow :: Cow :: Borrowed ("#8cd0d3") , white : :: std :: borrow :: Cow :: Borrowed ("#dcdccc") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#709080") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#dca3a3") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#c3bf9f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#e0cf9f") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#94bff3") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ec93d3") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#93e0e3") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#3f3f3f") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dcdccc") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("ayu") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3333") , green : :: std :: borrow :: Cow :: Borrowed ("#b8cc52") , yellow : :: std :: borrow :: Cow :: Borrowed ("#e7c547") , blue : :: std :: borrow :: Cow :: Borrowed ("#36a3d9") , purple : :: std :: borrow :: Cow :: Borrowed ("#f07178") , cyan : :: std :: borrow :: Cow :: Borrowed ("#95e6cb") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6565") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#eafe84") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff779") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#68d5ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffa3aa") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#c7fffd") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#0f1419") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e6e1cf") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("ayu_light") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff3333") , green : :: std :: borrow :: Cow :: Borrowed ("#86b300") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f29718") , blue : :: std :: borrow :: Cow :: Borrowed ("#41a6d9") , purple : :: std :: borrow :: Cow :: Borrowed ("#f07178") , cyan : :: std :: borrow :: Cow :: Borrowed ("#4dbf99") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#323232") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6565") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b8e532") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc94a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#73d8ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ffa3aa") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#7ff1cb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#fafafa") , foreground : :: std :: borrow :: Cow :: Borrowed ("#5c6773") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("coffee_theme") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#c91b00") , green : :: std :: borrow :: Cow :: Borrowed ("#00c200") , yellow : :: std :: borrow :: Cow :: Borrowed ("#c7c400") , blue : :: std :: borrow :: Cow :: Borrowed ("#0225c7") , purple : :: std :: borrow :: Cow :: Borrowed ("#ca30c7") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00c5c7") , white : :: std :: borrow :: Cow :: Borrowed ("#c7c7c7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#686868") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff6e67") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#5ffa68") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffc67") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6871ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff77ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#60fdff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#f5deb3") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("cyberpunk") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#ff7092") , green : :: std :: borrow :: Cow :: Borrowed ("#00fbac") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fffa6a") , blue : :: std :: borrow :: Cow :: Borrowed ("#00bfff") , purple : :: std :: borrow :: Cow :: Borrowed ("#df95ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#86cbfe") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff8aa4") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#21f6bc") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff787") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#1bccfd") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e6aefe") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#99d6fc") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#332a57") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e5e5e5") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("deep") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#d70005") , green : :: std :: borrow :: Cow :: Borrowed ("#1cd915") , yellow : :: std :: borrow :: Cow :: Borrowed ("#d9bd26") , blue : :: std :: borrow :: Cow :: Borrowed ("#5665ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#b052da") , cyan : :: std :: borrow :: Cow :: Borrowed ("#50d2da") , white : :: std :: borrow :: Cow :: Borrowed ("#e0e0e0") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#535353") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fb0007") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#22ff18") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fedc2b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#9fa9ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e09aff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8df9ff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#090909") , foreground : :: std :: borrow :: Cow :: Borrowed ("#cdcdcd") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("idea") , black : :: std :: borrow :: Cow :: Borrowed ("#adadad") , red : :: std :: borrow :: Cow :: Borrowed ("#fc5256") , green : :: std :: borrow :: Cow :: Borrowed ("#98b61c") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ccb444") , blue : :: std :: borrow :: Cow :: Borrowed ("#437ee7") , purple : :: std :: borrow :: Cow :: Borrowed ("#9d74b0") , cyan : :: std :: borrow :: Cow :: Borrowed ("#248887") , white : :: std :: borrow :: Cow :: Borrowed ("#181818") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#fc7072") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#98b61c") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffff0b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6c9ced") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#fc7eff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#248887") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#181818") , background : :: std :: borrow :: Cow :: Borrowed ("#202020") , foreground : :: std :: borrow :: Cow :: Borrowed ("#adadad") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("idleToes") , black : :: std :: borrow :: Cow :: Borrowed ("#323232") , red : :: std :: borrow :: Cow :: Borrowed ("#d25252") , green : :: std :: borrow :: Cow :: Borrowed ("#7fe173") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffc66d") , blue : :: std :: borrow :: Cow :: Borrowed ("#4099ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#f680ff") , cyan : :: std :: borrow :: Cow :: Borrowed ("#bed6ff") , white : :: std :: borrow :: Cow :: Borrowed ("#eeeeec") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#535353") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f07070") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#9dff91") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe48b") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#5eb7f7") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff9dff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#dcf4ff") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#323232") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("lovelace") , black : :: std :: borrow :: Cow :: Borrowed ("#282a36") , red : :: std :: borrow :: Cow :: Borrowed ("#f37f97") , green : :: std :: borrow :: Cow :: Borrowed ("#5adecd") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f2a272") , blue : :: std :: borrow :: Cow :: Borrowed ("#8897f4") , purple : :: std :: borrow :: Cow :: Borrowed ("#c574dd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#79e6f3") , white : :: std :: borrow :: Cow :: Borrowed ("#fdfdfd") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#414458") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff4971") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#18e3c8") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ff8037") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#556fff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#b043d1") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#3fdcee") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#bebec1") , background : :: std :: borrow :: Cow :: Borrowed ("#1d1f28") , foreground : :: std :: borrow :: Cow :: Borrowed ("#fdfdfd") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("midnight-in-mojave") , black : :: std :: borrow :: Cow :: Borrowed ("#1e1e1e") , red : :: std :: borrow :: Cow :: Borrowed ("#ff453a") , green : :: std :: borrow :: Cow :: Borrowed ("#32d74b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd60a") , blue : :: std :: borrow :: Cow :: Borrowed ("#0a84ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#bf5af2") , cyan : :: std :: borrow :: Cow :: Borrowed ("#5ac8fa") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#1e1e1e") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff453a") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#32d74b") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#ffd60a") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#0a84ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#bf5af2") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#5ac8fa") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1e1e1e") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("primary") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#db4437") , green : :: std :: borrow :: Cow :: Borrowed ("#0f9d58") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f4b400") , blue : :: std :: borrow :: Cow :: Borrowed ("#4285f4") , purple : :: std :: borrow :: Cow :: Borrowed ("#db4437") , cyan : :: std :: borrow :: Cow :: Borrowed ("#4285f4") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#db4437") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#0f9d58") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f4b400") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#4285f4") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#4285f4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#0f9d58") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , foreground : :: std :: borrow :: Cow :: Borrowed ("#000000") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("purplepeter") , black : :: std :: borrow :: Cow :: Borrowed ("#0a0520") , red : :: std :: borrow :: Cow :: Borrowed ("#ff796d") , green : :: std :: borrow :: Cow :: Borrowed ("#99b481") , yellow : :: std :: borrow :: Cow :: Borrowed ("#efdfac") , blue : :: std :: borrow :: Cow :: Borrowed ("#66d9ef") , purple : :: std :: borrow :: Cow :: Borrowed ("#e78fcd") , cyan : :: std :: borrow :: Cow :: Borrowed ("#ba8cff") , white : :: std :: borrow :: Cow :: Borrowed ("#ffba81") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#100b23") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f99f92") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#b4be8f") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f2e9bf") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#79daed") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ba91d4") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#a0a0d6") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#b9aed3") , background : :: std :: borrow :: Cow :: Borrowed ("#2a1a4a") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ece7fa") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("rebecca") , black : :: std :: borrow :: Cow :: Borrowed ("#12131e") , red : :: std :: borrow :: Cow :: Borrowed ("#dd7755") , green : :: std :: borrow :: Cow :: Borrowed ("#04dbb5") , yellow : :: std :: borrow :: Cow :: Borrowed ("#f2e7b7") , blue : :: std :: borrow :: Cow :: Borrowed ("#7aa5ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#bf9cf9") , cyan : :: std :: borrow :: Cow :: Borrowed ("#56d3c2") , white : :: std :: borrow :: Cow :: Borrowed ("#e4e3e9") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#666699") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#ff92cd") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#01eac0") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fffca8") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#69c0fa") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#c17ff8") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#8bfde1") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#f4f2f9") , background : :: std :: borrow :: Cow :: Borrowed ("#292a44") , foreground : :: std :: borrow :: Cow :: Borrowed ("#e8e6ed") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("shades-of-purple") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#d90429") , green : :: std :: borrow :: Cow :: Borrowed ("#3ad900") , yellow : :: std :: borrow :: Cow :: Borrowed ("#ffe700") , blue : :: std :: borrow :: Cow :: Borrowed ("#6943ff") , purple : :: std :: borrow :: Cow :: Borrowed ("#ff2c70") , cyan : :: std :: borrow :: Cow :: Borrowed ("#00c5c7") , white : :: std :: borrow :: Cow :: Borrowed ("#c7c7c7") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#686868") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f92a1c") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#43d426") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#f1d000") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#6871ff") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#ff77ff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#79e8fb") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#1e1d40") , foreground : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("synthwave-everything") , black : :: std :: borrow :: Cow :: Borrowed ("#fefefe") , red : :: std :: borrow :: Cow :: Borrowed ("#f97e72") , green : :: std :: borrow :: Cow :: Borrowed ("#72f1b8") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fede5d") , blue : :: std :: borrow :: Cow :: Borrowed ("#6d77b3") , purple : :: std :: borrow :: Cow :: Borrowed ("#c792ea") , cyan : :: std :: borrow :: Cow :: Borrowed ("#f772e0") , white : :: std :: borrow :: Cow :: Borrowed ("#fefefe") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#fefefe") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f88414") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#72f1b8") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fff951") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#36f9f6") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#e1acff") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#f92aad") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#fefefe") , background : :: std :: borrow :: Cow :: Borrowed ("#2a2139") , foreground : :: std :: borrow :: Cow :: Borrowed ("#f0eff1") , } , HtmlTheme { name : :: std :: borrow :: Cow :: Borrowed ("synthwave") , black : :: std :: borrow :: Cow :: Borrowed ("#000000") , red : :: std :: borrow :: Cow :: Borrowed ("#f6188f") , green : :: std :: borrow :: Cow :: Borrowed ("#1ebb2b") , yellow : :: std :: borrow :: Cow :: Borrowed ("#fdf834") , blue : :: std :: borrow :: Cow :: Borrowed ("#2186ec") , purple : :: std :: borrow :: Cow :: Borrowed ("#f85a21") , cyan : :: std :: borrow :: Cow :: Borrowed ("#12c3e2") , white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , bright_black : :: std :: borrow :: Cow :: Borrowed ("#000000") , bright_red : :: std :: borrow :: Cow :: Borrowed ("#f841a0") , bright_green : :: std :: borrow :: Cow :: Borrowed ("#25c141") , bright_yellow : :: std :: borrow :: Cow :: Borrowed ("#fdf454") , bright_blue : :: std :: borrow :: Cow :: Borrowed ("#2f9ded") , bright_purple : :: std :: borrow :: Cow :: Borrowed ("#f97137") , bright_cyan : :: std :: borrow :: Cow :: Borrowed ("#19cde6") , bright_white : :: std :: borrow :: Cow :: Borrowed ("#ffffff") , background : :: std :: borrow :: Cow :: Borrowed ("#000000") , foreground : :: std :: borrow :: Cow :: Borrowed ("#dad9c7") , }] } }
\ No newline at end of file
@@ -0,0 +1,841 @@
+// Code automatically generated by `shadow-rs` (https://github.com/baoyachi/shadow-rs), do not edit.
+// Author: https://www.github.com/baoyachi
+// Generation time: Sun, 18 Oct 2026 12:51:29 +0000
+
+
+
+#[doc=r#"
+The name of the Git branch that this project was built from.
+This constant will be empty if the branch cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BRANCH :&str = r#""#;
+
+#[doc=r#"
+Operating system and architecture on which the project was build.
+The format of this variable is always `os-arch`,
+where `os` is the operating system name as returned by [`std::env::consts::OS`],
+and `arch` is the computer architecture as returned by [`std::env::consts::ARCH`]."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_OS :&str = r#"linux-x86_64"#;
+
+#[doc=r#"
+The debug configuration with which the project was built.
+Note that this is not the Rust channel, but either `debug` or `release`, depending on whether debug assertions were enabled in the build or not. "#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_RUST_CHANNEL :&str = r#"debug"#;
+
+#[doc=r#"
+The [target](https://doc.rust-lang.org/rustc/targets/index.html) for this build.
+This is possibly distinct from the host target during build, in which case this project build was created via cross-compilation."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TARGET :&str = r#"x86_64-unknown-linux-gnu"#;
+
+#[doc=r#"
+The architecture of the target for this build. This is the "architecture" part of the [`BUILD_TARGET`] constant."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TARGET_ARCH :&str = r#"x86_64"#;
+
+#[doc=r#"
+The project build time, formatted in modified ISO 8601 format (`YYYY-MM-DD HH-MM ±hh-mm` where hh-mm is the offset from UTC)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIME :&str = r#"2026-10-18 12:51:29 +00:00"#;
+
+#[doc=r#"
+The project build time as a Unix timestamp (seconds since Unix epoch)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIMESTAMP :i64 = 1792327889;
+
+#[doc=r#"
+The project build time, formatted according to [RFC 2822](https://datatracker.ietf.org/doc/html/rfc2822#section-3.3) (e.g. HTTP Headers)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIME_2822 :&str = r#"Sun, 18 Oct 2026 12:51:29 +0000"#;
+
+#[doc=r#"
+The project build time, formatted according to [RFC 3339 and ISO 8601](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const BUILD_TIME_3339 :&str = r#"2026-10-18T12:51:29Z"#;
+
+#[doc=r#"
+List of top-level crate features that are enabled for the build."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_FEATURES :&str = r#"default,os"#;
+
+#[doc=r#"
+The directory of the Cargo.toml manifest file of the project during build.
+Note that this variable will contain a full local file system path, and will therefore contain sensitive information and not be reproducible."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_MANIFEST_DIR :&str = r#"/root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/nu-cmd-lang-0.112.2"#;
+
+#[doc=r#"
+The dependency tree of the project, as output by `cargo tree`.
+Note that this variable may contain local file system paths for path dependencies, and may therefore contain sensitive information and not be reproducible."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_TREE :&str = r#"
+├── itertools v0.14.0
+│   └── either v1.15.0
+├── nu-cmd-base v0.112.2
+│   ├── indexmap v2.13.0
+│   │   ├── equivalent v1.0.2
+│   │   └── hashbrown v0.16.1
+│   │       ├── allocator-api2 v0.2.21
+│   │       ├── equivalent v1.0.2
+│   │       └── foldhash v0.2.0
+│   ├── miette v7.6.0
+│   │   ├── cfg-if v1.0.4
+│   │   ├── miette-derive v7.6.0 (proc-macro)
+│   │   │   ├── proc-macro2 v1.0.106
+│   │   │   │   └── unicode-ident v1.0.24
+│   │   │   ├── quote v1.0.45
+│   │   │   │   └── proc-macro2 v1.0.106 (*)
+│   │   │   └── syn v2.0.117
+│   │   │       ├── proc-macro2 v1.0.106 (*)
+│   │   │       ├── quote v1.0.45 (*)
+│   │   │       └── unicode-ident v1.0.24
+│   │   ├── owo-colors v4.3.0
+│   │   ├── supports-color v3.0.2
+│   │   │   └── is_ci v1.2.0
+│   │   ├── supports-hyperlinks v3.2.0
+│   │   ├── supports-unicode v3.0.0
+│   │   ├── terminal_size v0.4.3
+│   │   │   └── rustix v1.1.4
+│   │   │       ├── bitflags v2.11.0
+│   │   │       └── linux-raw-sys v0.12.1
+│   │   ├── textwrap v0.16.2
+│   │   │   ├── unicode-linebreak v0.1.5
+│   │   │   └── unicode-width v0.2.2
+│   │   └── unicode-width v0.1.14
+│   ├── nu-engine v0.112.2
+│   │   ├── fancy-regex v0.17.0
+│   │   │   ├── bit-set v0.8.0
+│   │   │   │   └── bit-vec v0.8.0
+│   │   │   ├── regex-automata v0.4.14
+│   │   │   │   ├── aho-corasick v1.1.4
+│   │   │   │   │   └── memchr v2.8.0
+│   │   │   │   ├── memchr v2.8.0
+│   │   │   │   └── regex-syntax v0.8.10
+│   │   │   └── regex-syntax v0.8.10
+│   │   ├── log v0.4.29
+│   │   ├── nu-experimental v0.112.2
+│   │   │   ├── itertools v0.14.0 (*)
+│   │   │   └── thiserror v2.0.18
+│   │   │       └── thiserror-impl v2.0.18 (proc-macro)
+│   │   │           ├── proc-macro2 v1.0.106 (*)
+│   │   │           ├── quote v1.0.45 (*)
+│   │   │           └── syn v2.0.117 (*)
+│   │   ├── nu-glob v0.112.2
+│   │   ├── nu-path v0.112.2
+│   │   │   ├── dirs v6.0.0
+│   │   │   │   └── dirs-sys v0.5.0
+│   │   │   │       ├── libc v0.2.184
+│   │   │   │       └── option-ext v0.2.0
+│   │   │   ├── pwd v1.4.0
+│   │   │   │   ├── libc v0.2.184
+│   │   │   │   └── thiserror v1.0.69
+│   │   │   │       └── thiserror-impl v1.0.69 (proc-macro)
+│   │   │   │           ├── proc-macro2 v1.0.106 (*)
+│   │   │   │           ├── quote v1.0.45 (*)
+│   │   │   │           └── syn v2.0.117 (*)
+│   │   │   └── ref-cast v1.0.25
+│   │   │       └── ref-cast-impl v1.0.25 (proc-macro)
+│   │   │           ├── proc-macro2 v1.0.106 (*)
+│   │   │           ├── quote v1.0.45 (*)
+│   │   │           └── syn v2.0.117 (*)
+│   │   ├── nu-protocol v0.112.2
+│   │   │   ├── bytes v1.11.1
+│   │   │   ├── chrono v0.4.41
+│   │   │   │   ├── iana-time-zone v0.1.65
+│   │   │   │   ├── num-traits v0.2.19
+│   │   │   │   │   [build-dependencies]
+│   │   │   │   │   └── autocfg v1.5.0
+│   │   │   │   ├── pure-rust-locales v0.8.2
+│   │   │   │   └── serde v1.0.228
+│   │   │   │       ├── serde_core v1.0.228
+│   │   │   │       └── serde_derive v1.0.228 (proc-macro)
+│   │   │   │           ├── proc-macro2 v1.0.106 (*)
+│   │   │   │           ├── quote v1.0.45 (*)
+│   │   │   │           └── syn v2.0.117 (*)
+│   │   │   ├── chrono-humanize v0.2.3
+│   │   │   │   └── chrono v0.4.41 (*)
+│   │   │   ├── dirs v6.0.0 (*)
+│   │   │   ├── fancy-regex v0.17.0 (*)
+│   │   │   ├── heck v0.5.0
+│   │   │   ├── indexmap v2.13.0 (*)
+│   │   │   ├── log v0.4.29
+│   │   │   ├── lru v0.16.3
+│   │   │   │   └── hashbrown v0.16.1 (*)
+│   │   │   ├── memchr v2.8.0
+│   │   │   ├── miette v7.6.0 (*)
+│   │   │   ├── nix v0.31.2
+│   │   │   │   ├── bitflags v2.11.0
+│   │   │   │   ├── cfg-if v1.0.4
+│   │   │   │   └── libc v0.2.184
+│   │   │   │   [build-dependencies]
+│   │   │   │   └── cfg_aliases v0.2.1
+│   │   │   ├── nu-derive-value v0.112.2 (proc-macro)
+│   │   │   │   ├── heck v0.5.0
+│   │   │   │   ├── proc-macro-error2 v2.0.1
+│   │   │   │   │   ├── proc-macro-error-attr2 v2.0.0 (proc-macro)
+│   │   │   │   │   │   ├── proc-macro2 v1.0.106 (*)
+│   │   │   │   │   │   └── quote v1.0.45 (*)
+│   │   │   │   │   ├── proc-macro2 v1.0.106 (*)
+│   │   │   │   │   ├── quote v1.0.45 (*)
+│   │   │   │   │   └── syn v2.0.117 (*)
+│   │   │   │   ├── proc-macro2 v1.0.106 (*)
+│   │   │   │   ├── quote v1.0.45 (*)
+│   │   │   │   └── syn v2.0.117 (*)
+│   │   │   ├── nu-experimental v0.112.2 (*)
+│   │   │   ├── nu-glob v0.112.2
+│   │   │   ├── nu-path v0.112.2 (*)
+│   │   │   ├── nu-system v0.112.2
+│   │   │   │   ├── itertools v0.14.0 (*)
+│   │   │   │   ├── libc v0.2.184
+│   │   │   │   ├── log v0.4.29
+│   │   │   │   ├── nix v0.31.2 (*)
+│   │   │   │   ├── nu-utils v0.112.2
+│   │   │   │   │   ├── byteyarn v0.5.1
+│   │   │   │   │   │   └── buf-trait v0.4.1
+│   │   │   │   │   │       └── zerocopy v0.7.35
+│   │   │   │   │   │           └── byteorder v1.5.0
+│   │   │   │   │   ├── crossterm v0.29.0
+│   │   │   │   │   │   ├── bitflags v2.11.0
+│   │   │   │   │   │   ├── derive_more v2.1.1
+│   │   │   │   │   │   │   └── derive_more-impl v2.1.1 (proc-macro)
+│   │   │   │   │   │   │       ├── convert_case v0.10.0
+│   │   │   │   │   │   │       │   └── unicode-segmentation v1.13.2
+│   │   │   │   │   │   │       ├── proc-macro2 v1.0.106 (*)
+│   │   │   │   │   │   │       ├── quote v1.0.45 (*)
+│   │   │   │   │   │   │       └── syn v2.0.117 (*)
+│   │   │   │   │   │   │       [build-dependencies]
+│   │   │   │   │   │   │       └── rustc_version v0.4.1
+│   │   │   │   │   │   │           └── semver v1.0.27
+│   │   │   │   │   │   ├── document-features v0.2.12 (proc-macro)
+│   │   │   │   │   │   │   └── litrs v1.0.0
+│   │   │   │   │   │   ├── mio v1.2.0
+│   │   │   │   │   │   │   ├── libc v0.2.184
+│   │   │   │   │   │   │   └── log v0.4.29
+│   │   │   │   │   │   ├── parking_lot v0.12.5
+│   │   │   │   │   │   │   ├── lock_api v0.4.14
+│   │   │   │   │   │   │   │   └── scopeguard v1.2.0
+│   │   │   │   │   │   │   └── parking_lot_core v0.9.12
+│   │   │   │   │   │   │       ├── cfg-if v1.0.4
+│   │   │   │   │   │   │       ├── libc v0.2.184
+│   │   │   │   │   │   │       └── smallvec v1.15.1
+│   │   │   │   │   │   ├── rustix v1.1.4 (*)
+│   │   │   │   │   │   ├── signal-hook v0.3.18
+│   │   │   │   │   │   │   ├── libc v0.2.184
+│   │   │   │   │   │   │   └── signal-hook-registry v1.4.8
+│   │   │   │   │   │   │       ├── errno v0.3.14
+│   │   │   │   │   │   │       │   └── libc v0.2.184
+│   │   │   │   │   │   │       └── libc v0.2.184
+│   │   │   │   │   │   └── signal-hook-mio v0.2.5
+│   │   │   │   │   │       ├── libc v0.2.184
+│   │   │   │   │   │       ├── mio v1.2.0 (*)
+│   │   │   │   │   │       └── signal-hook v0.3.18 (*)
+│   │   │   │   │   ├── fancy-regex v0.17.0 (*)
+│   │   │   │   │   ├── lean_string v0.5.1
+│   │   │   │   │   │   ├── castaway v0.2.4
+│   │   │   │   │   │   │   └── rustversion v1.0.22 (proc-macro)
+│   │   │   │   │   │   ├── itoa v1.0.17
+│   │   │   │   │   │   ├── ryu v1.0.23
+│   │   │   │   │   │   └── serde v1.0.228 (*)
+│   │   │   │   │   ├── log v0.4.29
+│   │   │   │   │   ├── lscolors v0.21.0
+│   │   │   │   │   │   ├── aho-corasick v1.1.4 (*)
+│   │   │   │   │   │   └── nu-ansi-term v0.50.3
+│   │   │   │   │   ├── memchr v2.8.0
+│   │   │   │   │   ├── nix v0.31.2 (*)
+│   │   │   │   │   ├── num-format v0.4.4
+│   │   │   │   │   │   ├── arrayvec v0.7.6
+│   │   │   │   │   │   └── itoa v1.0.17
+│   │   │   │   │   ├── parking_lot v0.12.5 (*)
+│   │   │   │   │   ├── serde v1.0.228 (*)
+│   │   │   │   │   ├── serde_json v1.0.149
+│   │   │   │   │   │   ├── itoa v1.0.17
+│   │   │   │   │   │   ├── memchr v2.8.0
+│   │   │   │   │   │   ├── serde_core v1.0.228
+│   │   │   │   │   │   └── zmij v1.0.21
+│   │   │   │   │   ├── strip-ansi-escapes v0.2.1
+│   │   │   │   │   │   └── vte v0.14.1
+│   │   │   │   │   │       └── memchr v2.8.0
+│   │   │   │   │   ├── sys-locale v0.3.2
+│   │   │   │   │   └── unicase v2.9.0
+│   │   │   │   ├── procfs v0.18.0
+│   │   │   │   │   ├── bitflags v2.11.0
+│   │   │   │   │   ├── chrono v0.4.41 (*)
+│   │   │   │   │   ├── flate2 v1.1.9
+│   │   │   │   │   │   ├── crc32fast v1.5.0
+│   │   │   │   │   │   │   └── cfg-if v1.0.4
+│   │   │   │   │   │   └── miniz_oxide v0.8.9
+│   │   │   │   │   │       ├── adler2 v2.0.1
+│   │   │   │   │   │       └── simd-adler32 v0.3.8
+│   │   │   │   │   ├── procfs-core v0.18.0
+│   │   │   │   │   │   ├── bitflags v2.11.0
+│   │   │   │   │   │   ├── chrono v0.4.41 (*)
+│   │   │   │   │   │   └── hex v0.4.3
+│   │   │   │   │   └── rustix v1.1.4 (*)
+│   │   │   │   ├── sysinfo v0.38.4
+│   │   │   │   │   ├── libc v0.2.184
+│   │   │   │   │   └── memchr v2.8.0
+│   │   │   │   ├── uucore v0.8.0
+│   │   │   │   │   ├── clap v4.5.60
+│   │   │   │   │   │   └── clap_builder v4.5.60
+│   │   │   │   │   │       ├── anstream v0.6.21
+│   │   │   │   │   │       │   ├── anstyle v1.0.13
+│   │   │   │   │   │       │   ├── anstyle-parse v0.2.7
+│   │   │   │   │   │       │   │   └── utf8parse v0.2.2
+│   │   │   │   │   │       │   ├── anstyle-query v1.1.5
+│   │   │   │   │   │       │   ├── colorchoice v1.0.4
+│   │   │   │   │   │       │   ├── is_terminal_polyfill v1.70.2
+│   │   │   │   │   │       │   └── utf8parse v0.2.2
+│   │   │   │   │   │       ├── anstyle v1.0.13
+│   │   │   │   │   │       ├── clap_lex v1.0.0
+│   │   │   │   │   │       ├── strsim v0.11.1
+│   │   │   │   │   │       └── terminal_size v0.4.3 (*)
+│   │   │   │   │   ├── fluent v0.17.0
+│   │   │   │   │   │   ├── fluent-bundle v0.16.0
+│   │   │   │   │   │   │   ├── fluent-langneg v0.13.1
+│   │   │   │   │   │   │   │   └── unic-langid v0.9.6
+│   │   │   │   │   │   │   │       └── unic-langid-impl v0.9.6
+│   │   │   │   │   │   │   │           └── tinystr v0.8.2
+│   │   │   │   │   │   │   │               └── displaydoc v0.2.5 (proc-macro)
+│   │   │   │   │   │   │   │                   ├── proc-macro2 v1.0.106 (*)
+│   │   │   │   │   │   │   │                   ├── quote v1.0.45 (*)
+│   │   │   │   │   │   │   │                   └── syn v2.0.117 (*)
+│   │   │   │   │   │   │   ├── fluent-syntax v0.12.0
+│   │   │   │   │   │   │   │   ├── memchr v2.8.0
+│   │   │   │   │   │   │   │   └── thiserror v2.0.18 (*)
+│   │   │   │   │   │   │   ├── intl-memoizer v0.5.3
+│   │   │   │   │   │   │   │   ├── type-map v0.5.1
+│   │   │   │   │   │   │   │   │   └── rustc-hash v2.1.1
+│   │   │   │   │   │   │   │   └── unic-langid v0.9.6 (*)
+│   │   │   │   │   │   │   ├── intl_pluralrules v7.0.2
+│   │   │   │   │   │   │   │   └── unic-langid v0.9.6 (*)
+│   │   │   │   │   │   │   ├── rustc-hash v2.1.1
+│   │   │   │   │   │   │   ├── self_cell v1.2.2
+│   │   │   │   │   │   │   ├── smallvec v1.15.1
+│   │   │   │   │   │   │   └── unic-langid v0.9.6 (*)
+│   │   │   │   │   │   └── unic-langid v0.9.6 (*)
+│   │   │   │   │   ├── fluent-bundle v0.16.0 (*)
+│   │   │   │   │   ├── fluent-syntax v0.12.0 (*)
+│   │   │   │   │   ├── libc v0.2.184
+│   │   │   │   │   ├── nix v0.31.2 (*)
+│   │   │   │   │   ├── os_display v0.1.4
+│   │   │   │   │   │   └── unicode-width v0.2.2
+│   │   │   │   │   ├── rustc-hash v2.1.1
+│   │   │   │   │   ├── rustix v1.1.4 (*)
+│   │   │   │   │   ├── thiserror v2.0.18 (*)
+│   │   │   │   │   ├── unic-langid v0.9.6 (*)
+│   │   │   │   │   └── uucore_procs v0.8.0 (proc-macro)
+│   │   │   │   │       ├── proc-macro2 v1.0.106 (*)
+│   │   │   │   │       └── quote v1.0.45 (*)
+│   │   │   │   └── web-time v1.1.0
+│   │   │   ├── nu-utils v0.112.2 (*)
+│   │   │   ├── num-format v0.4.4 (*)
+│   │   │   ├── os_pipe v1.2.3
+│   │   │   │   └── libc v0.2.184
+│   │   │   ├── serde v1.0.228 (*)
+│   │   │   ├── serde_json v1.0.149 (*)
+│   │   │   ├── strum v0.27.2
+│   │   │   ├── strum_macros v0.27.2 (proc-macro)
+│   │   │   │   ├── heck v0.5.0
+│   │   │   │   ├── proc-macro2 v1.0.106 (*)
+│   │   │   │   ├── quote v1.0.45 (*)
+│   │   │   │   └── syn v2.0.117 (*)
+│   │   │   ├── thiserror v2.0.18 (*)
+│   │   │   ├── typetag v0.2.21
+│   │   │   │   ├── erased-serde v0.4.10
+│   │   │   │   │   ├── serde_core v1.0.228
+│   │   │   │   │   └── typeid v1.0.3
+│   │   │   │   ├── inventory v0.3.22
+│   │   │   │   ├── once_cell v1.21.3
+│   │   │   │   ├── serde v1.0.228 (*)
+│   │   │   │   └── typetag-impl v0.2.21 (proc-macro)
+│   │   │   │       ├── proc-macro2 v1.0.106 (*)
+│   │   │   │       ├── quote v1.0.45 (*)
+│   │   │   │       └── syn v2.0.117 (*)
+│   │   │   └── web-time v1.1.0
+│   │   └── nu-utils v0.112.2 (*)
+│   ├── nu-parser v0.112.2
+│   │   ├── bytesize v2.3.1
+│   │   ├── chrono v0.4.41 (*)
+│   │   ├── itertools v0.14.0 (*)
+│   │   ├── log v0.4.29
+│   │   ├── nu-engine v0.112.2 (*)
+│   │   ├── nu-experimental v0.112.2 (*)
+│   │   ├── nu-path v0.112.2 (*)
+│   │   ├── nu-protocol v0.112.2 (*)
+│   │   ├── nu-utils v0.112.2 (*)
+│   │   └── serde_json v1.0.149 (*)
+│   ├── nu-path v0.112.2 (*)
+│   └── nu-protocol v0.112.2 (*)
+├── nu-engine v0.112.2 (*)
+├── nu-experimental v0.112.2 (*)
+├── nu-parser v0.112.2 (*)
+├── nu-protocol v0.112.2 (*)
+├── nu-utils v0.112.2 (*)
+└── shadow-rs v1.7.1
+    ├── const_format v0.2.35
+    │   └── const_format_proc_macros v0.2.34 (proc-macro)
+    │       ├── proc-macro2 v1.0.106 (*)
+    │       ├── quote v1.0.45 (*)
+    │       └── unicode-xid v0.2.6
+    └── is_debug v1.1.0
+[build-dependencies]
+└── shadow-rs v1.7.1
+    ├── const_format v0.2.35 (*)
+    ├── is_debug v1.1.0
+    ├── time v0.3.47
+    │   ├── deranged v0.5.8
+    │   │   └── powerfmt v0.2.0
+    │   ├── itoa v1.0.17
+    │   ├── libc v0.2.184
+    │   ├── num-conv v0.2.0
+    │   ├── num_threads v0.1.7
+    │   ├── powerfmt v0.2.0
+    │   └── time-core v0.1.8
+    └── tzdb v0.7.3
+        ├── iana-time-zone v0.1.65
+        ├── tz-rs v0.7.3
+        └── tzdb_data v0.2.3
+            └── tz-rs v0.7.3
+[dev-dependencies]
+├── miette v7.6.0 (*)
+├── quickcheck v1.1.0
+│   ├── env_logger v0.11.9
+│   │   ├── env_filter v1.0.0
+│   │   │   ├── log v0.4.29
+│   │   │   └── regex v1.12.3
+│   │   │       ├── aho-corasick v1.1.4 (*)
+│   │   │       ├── memchr v2.8.0
+│   │   │       ├── regex-automata v0.4.14 (*)
+│   │   │       └── regex-syntax v0.8.10
+│   │   └── log v0.4.29
+│   ├── log v0.4.29
+│   └── rand v0.10.0
+│       ├── getrandom v0.4.2
+│       │   ├── cfg-if v1.0.4
+│       │   ├── libc v0.2.184
+│       │   └── rand_core v0.10.0
+│       └── rand_core v0.10.0
+└── quickcheck_macros v1.2.0 (proc-macro)
+    ├── proc-macro2 v1.0.106 (*)
+    ├── quote v1.0.45 (*)
+    └── syn v2.0.117 (*)
+"#;
+
+#[doc=r#"
+The cargo version which which the project was built, as output by `cargo --version`."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CARGO_VERSION :&str = r#"cargo 1.95.0 (f2d3ce0bd 2026-03-21)"#;
+
+#[doc=r#"
+The number of commits since the last Git tag on the branch that this project was built from.
+This value indicates how many commits have been made after the last tag and before the current commit.
+
+If there are no additional commits after the last tag (i.e., the current commit is exactly at a tag),
+this value will be `0`.
+
+This constant will be empty or `0` if the last tag cannot be determined or if there are no commits after it.
+"#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMITS_SINCE_TAG :usize = 0;
+
+#[doc=r#"
+The author of the Git commit that this project was built from.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_AUTHOR :&str = r#""#;
+
+#[doc=r#"The time of the Git commit that this project was built from.
+The time is formatted in modified ISO 8601 format (`YYYY-MM-DD HH-MM ±hh-mm` where hh-mm is the offset from UTC).
+The timezone information from the original commit is preserved.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_DATE :&str = r#""#;
+
+#[doc=r#"
+The time of the Git commit that this project was built from.
+The time is formatted according to [RFC 2822](https://datatracker.ietf.org/doc/html/rfc2822#section-3.3) (e.g. HTTP Headers).
+The timezone information from the original commit is preserved.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_DATE_2822 :&str = r#""#;
+
+#[doc=r#"
+The time of the Git commit that this project was built from.
+The time is formatted according to [RFC 3339 and ISO 8601](https://datatracker.ietf.org/doc/html/rfc3339#section-5.6).
+The timezone information from the original commit is preserved.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_DATE_3339 :&str = r#""#;
+
+#[doc=r#"
+The e-mail address of the author of the Git commit that this project was built from.
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_EMAIL :&str = r#""#;
+
+#[doc=r#"
+The full commit hash of the Git commit that this project was built from.
+An abbreviated, but not necessarily unique, version of this is [`SHORT_COMMIT`].
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_HASH :&str = r#""#;
+
+#[doc=r#"
+The time of the Git commit as a Unix timestamp (seconds since Unix epoch).
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const COMMIT_TIMESTAMP :&str = r#""#;
+
+#[doc=r#"
+Whether the Git working tree was clean at the time of project build (`true`), or not (`false`).
+
+This constant will be `false` if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const GIT_CLEAN :bool = true;
+
+#[doc=r#"
+The Git working tree status as a list of files with their status, similar to `git status`.
+Each line of the list is preceded with `  * `, followed by the file name.
+Files marked `(dirty)` have unstaged changes.
+Files marked `(staged)` have staged changes.
+
+This constant will be empty if the working tree status cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const GIT_STATUS_FILE :&str = r#""#;
+
+#[doc=r#"
+The name of the last Git tag on the branch that this project was built from.
+As opposed to [`TAG`], this does not require the current commit to be tagged, just one of its parents.
+
+This constant will be empty if the last tag cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const LAST_TAG :&str = r#""#;
+
+#[doc=r#"
+The project's description, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_DESCRIPTION :&str = r#"Nushell's core language commands"#;
+
+#[doc=r#"
+The project's full version string, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION :&str = r#"0.112.2"#;
+
+#[doc=r#"
+The project's semver major version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_MAJOR :&str = r#"0"#;
+
+#[doc=r#"
+The project's semver minor version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_MINOR :&str = r#"112"#;
+
+#[doc=r#"
+The project's semver patch version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_PATCH :&str = r#"2"#;
+
+#[doc=r#"
+The project's semver pre-release version, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PKG_VERSION_PRE :&str = r#""#;
+
+#[doc=r#"
+The project name, as determined by the Cargo.toml manifest."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const PROJECT_NAME :&str = r#"nu-cmd-lang"#;
+
+#[doc=r#"
+The [Rustup toolchain](https://rust-lang.github.io/rustup/concepts/toolchains.html) with which the project was built.
+Note that as per Rustup toolchain format, this variable may or may not contain host and date information,
+but it will always contain [channel](https://rust-lang.github.io/rustup/concepts/channels.html) information (stable, beta or nightly)."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const RUST_CHANNEL :&str = r#"stable-x86_64-unknown-linux-gnu"#;
+
+#[doc=r#"
+Rust version with which the project was built.
+The version always uses the canonical Rust version format,
+and is therefore identical to the output of the build toolchain's `rustc --version`."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const RUST_VERSION :&str = r#"rustc 1.95.0 (59807616e 2026-04-14)"#;
+
+#[doc=r#"
+The short hash of the Git commit that this project was built from.
+Note that this will always truncate [`COMMIT_HASH`] to 8 characters if necessary.
+Depending on the amount of commits in your project, this may not yield a unique Git identifier
+([see here for more details on hash abbreviation](https://git-scm.com/docs/git-describe#_examples)).
+
+This constant will be empty if the last commit cannot be determined."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const SHORT_COMMIT :&str = r#""#;
+
+#[doc=r#"
+The name of the Git tag that this project was built from.
+Note that this will be empty if there is no tag for the HEAD at the time of build."#]
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const TAG :&str = r#""#;
+
+/// A long version string describing the project.
+/// The version string contains the package version, branch, commit hash, build time, and build environment on separate lines.
+/// This constant is suitable for printing to the user.
+#[allow(dead_code,missing_docs)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const VERSION:&str = shadow_rs::formatcp!(r#"
+pkg_version:{}
+branch:{}
+commit_hash:{}
+build_time:{}
+build_env:{},{}"#,PKG_VERSION, BRANCH, SHORT_COMMIT, BUILD_TIME, RUST_VERSION, RUST_CHANNEL
+);
+
+
+/// A long version string describing the project.
+/// The version string contains the package version, branch, commit hash, build time, and build environment on separate lines.
+/// This constant is intended to be used by clap or other CLI tools as a long version string.
+#[allow(dead_code,missing_docs)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub const CLAP_LONG_VERSION:&str = shadow_rs::formatcp!(r#"{}
+branch:{}
+commit_hash:{}
+build_time:{}
+build_env:{},{}"#,PKG_VERSION, BRANCH, SHORT_COMMIT, BUILD_TIME, RUST_VERSION, RUST_CHANNEL
+);
+
+
+/// A struct that implements [`core::fmt::Display`] which
+/// writes consts generated by `shadow-rs` to it's formatter
+#[allow(non_snake_case)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub struct BuildInfoDisplay {
+	pub BRANCH: bool,
+	pub BUILD_OS: bool,
+	pub BUILD_RUST_CHANNEL: bool,
+	pub BUILD_TARGET: bool,
+	pub BUILD_TARGET_ARCH: bool,
+	pub BUILD_TIME: bool,
+	pub BUILD_TIMESTAMP: bool,
+	pub BUILD_TIME_2822: bool,
+	pub BUILD_TIME_3339: bool,
+	pub CARGO_FEATURES: bool,
+	pub CARGO_MANIFEST_DIR: bool,
+	pub CARGO_TREE: bool,
+	pub CARGO_VERSION: bool,
+	pub COMMITS_SINCE_TAG: bool,
+	pub COMMIT_AUTHOR: bool,
+	pub COMMIT_DATE: bool,
+	pub COMMIT_DATE_2822: bool,
+	pub COMMIT_DATE_3339: bool,
+	pub COMMIT_EMAIL: bool,
+	pub COMMIT_HASH: bool,
+	pub COMMIT_TIMESTAMP: bool,
+	pub GIT_CLEAN: bool,
+	pub GIT_STATUS_FILE: bool,
+	pub LAST_TAG: bool,
+	pub PKG_DESCRIPTION: bool,
+	pub PKG_VERSION: bool,
+	pub PKG_VERSION_MAJOR: bool,
+	pub PKG_VERSION_MINOR: bool,
+	pub PKG_VERSION_PATCH: bool,
+	pub PKG_VERSION_PRE: bool,
+	pub PROJECT_NAME: bool,
+	pub RUST_CHANNEL: bool,
+	pub RUST_VERSION: bool,
+	pub SHORT_COMMIT: bool,
+	pub TAG: bool,
+	pub VERSION: bool,
+	pub CLAP_LONG_VERSION: bool,
+}
+
+impl Default for BuildInfoDisplay {
+	#[allow(dead_code)]
+	#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+	/// Every constant that `shadow-rs` tracks will be printed
+	/// except for slices (CARGO_METADATA for example)
+	fn default() -> Self {
+		Self {
+			BRANCH: true,
+			BUILD_OS: true,
+			BUILD_RUST_CHANNEL: true,
+			BUILD_TARGET: true,
+			BUILD_TARGET_ARCH: true,
+			BUILD_TIME: true,
+			BUILD_TIMESTAMP: true,
+			BUILD_TIME_2822: true,
+			BUILD_TIME_3339: true,
+			CARGO_FEATURES: true,
+			CARGO_MANIFEST_DIR: true,
+			CARGO_TREE: true,
+			CARGO_VERSION: true,
+			COMMITS_SINCE_TAG: true,
+			COMMIT_AUTHOR: true,
+			COMMIT_DATE: true,
+			COMMIT_DATE_2822: true,
+			COMMIT_DATE_3339: true,
+			COMMIT_EMAIL: true,
+			COMMIT_HASH: true,
+			COMMIT_TIMESTAMP: true,
+			GIT_CLEAN: true,
+			GIT_STATUS_FILE: true,
+			LAST_TAG: true,
+			PKG_DESCRIPTION: true,
+			PKG_VERSION: true,
+			PKG_VERSION_MAJOR: true,
+			PKG_VERSION_MINOR: true,
+			PKG_VERSION_PATCH: true,
+			PKG_VERSION_PRE: true,
+			PROJECT_NAME: true,
+			RUST_CHANNEL: true,
+			RUST_VERSION: true,
+			SHORT_COMMIT: true,
+			TAG: true,
+			VERSION: true,
+			CLAP_LONG_VERSION: true,
+		}
+	}
+}
+
+impl BuildInfoDisplay {
+	#[allow(dead_code)]
+	#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+	/// Every constant that `shadow-rs` tracks will be printed
+	pub fn all() -> Self {
+		Self {
+			BRANCH: true,
+			BUILD_OS: true,
+			BUILD_RUST_CHANNEL: true,
+			BUILD_TARGET: true,
+			BUILD_TARGET_ARCH: true,
+			BUILD_TIME: true,
+			BUILD_TIMESTAMP: true,
+			BUILD_TIME_2822: true,
+			BUILD_TIME_3339: true,
+			CARGO_FEATURES: true,
+			CARGO_MANIFEST_DIR: true,
+			CARGO_TREE: true,
+			CARGO_VERSION: true,
+			COMMITS_SINCE_TAG: true,
+			COMMIT_AUTHOR: true,
+			COMMIT_DATE: true,
+			COMMIT_DATE_2822: true,
+			COMMIT_DATE_3339: true,
+			COMMIT_EMAIL: true,
+			COMMIT_HASH: true,
+			COMMIT_TIMESTAMP: true,
+			GIT_CLEAN: true,
+			GIT_STATUS_FILE: true,
+			LAST_TAG: true,
+			PKG_DESCRIPTION: true,
+			PKG_VERSION: true,
+			PKG_VERSION_MAJOR: true,
+			PKG_VERSION_MINOR: true,
+			PKG_VERSION_PATCH: true,
+			PKG_VERSION_PRE: true,
+			PROJECT_NAME: true,
+			RUST_CHANNEL: true,
+			RUST_VERSION: true,
+			SHORT_COMMIT: true,
+			TAG: true,
+			VERSION: true,
+			CLAP_LONG_VERSION: true,
+		}
+	}
+}
+
+impl core::fmt::Display for BuildInfoDisplay {
+	#[allow(dead_code)]
+	#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		if self.BRANCH { writeln!(f, "BRANCH:{BRANCH}\n")?; }
+		if self.BUILD_OS { writeln!(f, "BUILD_OS:{BUILD_OS}\n")?; }
+		if self.BUILD_RUST_CHANNEL { writeln!(f, "BUILD_RUST_CHANNEL:{BUILD_RUST_CHANNEL}\n")?; }
+		if self.BUILD_TARGET { writeln!(f, "BUILD_TARGET:{BUILD_TARGET}\n")?; }
+		if self.BUILD_TARGET_ARCH { writeln!(f, "BUILD_TARGET_ARCH:{BUILD_TARGET_ARCH}\n")?; }
+		if self.BUILD_TIME { writeln!(f, "BUILD_TIME:{BUILD_TIME}\n")?; }
+		if self.BUILD_TIMESTAMP { writeln!(f, "BUILD_TIMESTAMP:{BUILD_TIMESTAMP}\n")?; }
+		if self.BUILD_TIME_2822 { writeln!(f, "BUILD_TIME_2822:{BUILD_TIME_2822}\n")?; }
+		if self.BUILD_TIME_3339 { writeln!(f, "BUILD_TIME_3339:{BUILD_TIME_3339}\n")?; }
+		if self.CARGO_FEATURES { writeln!(f, "CARGO_FEATURES:{CARGO_FEATURES}\n")?; }
+		if self.CARGO_MANIFEST_DIR { writeln!(f, "CARGO_MANIFEST_DIR:{CARGO_MANIFEST_DIR}\n")?; }
+		if self.CARGO_TREE { writeln!(f, "CARGO_TREE:{CARGO_TREE}\n")?; }
+		if self.CARGO_VERSION { writeln!(f, "CARGO_VERSION:{CARGO_VERSION}\n")?; }
+		if self.COMMITS_SINCE_TAG { writeln!(f, "COMMITS_SINCE_TAG:{COMMITS_SINCE_TAG}\n")?; }
+		if self.COMMIT_AUTHOR { writeln!(f, "COMMIT_AUTHOR:{COMMIT_AUTHOR}\n")?; }
+		if self.COMMIT_DATE { writeln!(f, "COMMIT_DATE:{COMMIT_DATE}\n")?; }
+		if self.COMMIT_DATE_2822 { writeln!(f, "COMMIT_DATE_2822:{COMMIT_DATE_2822}\n")?; }
+		if self.COMMIT_DATE_3339 { writeln!(f, "COMMIT_DATE_3339:{COMMIT_DATE_3339}\n")?; }
+		if self.COMMIT_EMAIL { writeln!(f, "COMMIT_EMAIL:{COMMIT_EMAIL}\n")?; }
+		if self.COMMIT_HASH { writeln!(f, "COMMIT_HASH:{COMMIT_HASH}\n")?; }
+		if self.COMMIT_TIMESTAMP { writeln!(f, "COMMIT_TIMESTAMP:{COMMIT_TIMESTAMP}\n")?; }
+		if self.GIT_CLEAN { writeln!(f, "GIT_CLEAN:{GIT_CLEAN}\n")?; }
+		if self.GIT_STATUS_FILE { writeln!(f, "GIT_STATUS_FILE:{GIT_STATUS_FILE}\n")?; }
+		if self.LAST_TAG { writeln!(f, "LAST_TAG:{LAST_TAG}\n")?; }
+		if self.PKG_DESCRIPTION { writeln!(f, "PKG_DESCRIPTION:{PKG_DESCRIPTION}\n")?; }
+		if self.PKG_VERSION { writeln!(f, "PKG_VERSION:{PKG_VERSION}\n")?; }
+		if self.PKG_VERSION_MAJOR { writeln!(f, "PKG_VERSION_MAJOR:{PKG_VERSION_MAJOR}\n")?; }
+		if self.PKG_VERSION_MINOR { writeln!(f, "PKG_VERSION_MINOR:{PKG_VERSION_MINOR}\n")?; }
+		if self.PKG_VERSION_PATCH { writeln!(f, "PKG_VERSION_PATCH:{PKG_VERSION_PATCH}\n")?; }
+		if self.PKG_VERSION_PRE { writeln!(f, "PKG_VERSION_PRE:{PKG_VERSION_PRE}\n")?; }
+		if self.PROJECT_NAME { writeln!(f, "PROJECT_NAME:{PROJECT_NAME}\n")?; }
+		if self.RUST_CHANNEL { writeln!(f, "RUST_CHANNEL:{RUST_CHANNEL}\n")?; }
+		if self.RUST_VERSION { writeln!(f, "RUST_VERSION:{RUST_VERSION}\n")?; }
+		if self.SHORT_COMMIT { writeln!(f, "SHORT_COMMIT:{SHORT_COMMIT}\n")?; }
+		if self.TAG { writeln!(f, "TAG:{TAG}\n")?; }
+		if self.VERSION { writeln!(f, "VERSION:{VERSION}\n")?; }
+		if self.CLAP_LONG_VERSION { writeln!(f, "CLAP_LONG_VERSION:{CLAP_LONG_VERSION}\n")?; }
+		Ok(())
+	}
+}
+
+/// Prints all built-in `shadow-rs` build constants
+/// (except for slices) to standard output.
+#[allow(dead_code)]
+#[allow(clippy::all, clippy::pedantic, clippy::restriction, clippy::nursery)]
+pub fn print_build_in() {
+	println!("{}", BuildInfoDisplay::default());
+}
+
@@ -0,0 +1,9 @@
+pub (crate) const RUSTC_COMMIT: &str = "59807616e1fa2540724bfbac14d7976d7e4a3860";
+pub (crate) const RUSTC_MAJOR: u16 = 1;
+pub (crate) const RUSTC_MINOR: u16 = 95;
+pub (crate) const RUSTC_PATCH: u16 = 0;
+pub (crate) const OPT_LEVEL: &str = "0";
+pub (crate) const DEBUG: &str = "false";
+pub (crate) const NUM_JOBS: &str = "1";
+pub (crate) const TARGET: &str = "x86_64-unknown-linux-gnu";
+pub (crate) const HOST: &str = "x86_64-unknown-linux-gnu";
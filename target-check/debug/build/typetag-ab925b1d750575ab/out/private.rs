@@ -0,0 +1,5 @@
+#[doc(hidden)]
+pub mod __private23 {
+    #[doc(hidden)]
+    pub use crate::private::*;
+}
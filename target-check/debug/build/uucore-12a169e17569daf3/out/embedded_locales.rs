@@ -0,0 +1,397 @@
+// Generated at compile time - do not edit
+// This file contains embedded English locale files
+
+
+pub fn get_embedded_locale(key: &str) -> Option<&'static str> {
+    match key {
+        // Static utility locales for crates.io builds
+        // Locale for uucore (en-US)
+        "uucore/en-US.ftl" => Some(r"# Common strings shared across all uutils commands
+# Mostly clap
+
+# Generic words
+common-error = error
+common-tip = tip
+common-usage = Usage
+common-help = help
+common-version = version
+
+# Common clap error messages
+clap-error-unexpected-argument = { $error_word }: unexpected argument '{ $arg }' found
+clap-error-unexpected-argument-simple = unexpected argument
+clap-error-similar-argument = { $tip_word }: a similar argument exists: '{ $suggestion }'
+clap-error-pass-as-value = { $tip_word }: to pass '{ $arg }' as a value, use '{ $tip_command }'
+clap-error-invalid-value = { $error_word }: invalid value '{ $value }' for '{ $option }'
+clap-error-value-required = { $error_word }: a value is required for '{ $option }' but none was supplied
+clap-error-missing-required-arguments = { $error_word }: the following required arguments were not provided:
+clap-error-possible-values = possible values
+clap-error-help-suggestion = For more information, try '{ $command } --help'.
+common-help-suggestion = For more information, try '--help'.
+
+# Common help text patterns
+help-flag-help = Print help information
+help-flag-version = Print version information
+
+# Common error contexts
+error-io = I/O error
+error-permission-denied = Permission denied
+error-file-not-found = No such file or directory
+error-invalid-argument = Invalid argument
+error-is-a-directory = { $file }: Is a directory
+
+# Common actions
+action-copying = copying
+action-moving = moving
+action-removing = removing
+action-creating = creating
+action-reading = reading
+action-writing = writing
+
+# SELinux error messages
+selinux-error-not-enabled = SELinux is not enabled on this system
+selinux-error-file-open-failure = failed to open the file: { $error }
+selinux-error-context-retrieval-failure = failed to retrieve the security context: { $error }
+selinux-error-context-set-failure = failed to set default file creation context to '{ $context }': { $error }
+selinux-error-context-conversion-failure = failed to set default file creation context to '{ $context }': { $error }
+selinux-error-operation-not-supported = operation not supported
+
+# SMACK error messages
+smack-error-not-enabled = SMACK is not enabled on this system
+smack-error-label-retrieval-failure = failed to get security context: { $error }
+smack-error-label-set-failure = failed to set default file creation context to '{ $context }': { $error }
+smack-error-no-label-set = no security context set
+
+# Safe traversal error messages
+safe-traversal-error-path-contains-null = path contains null byte
+safe-traversal-error-open-failed = failed to open { $path }: { $source }
+safe-traversal-error-stat-failed = failed to stat { $path }: { $source }
+safe-traversal-error-read-dir-failed = failed to read directory { $path }: { $source }
+safe-traversal-error-unlink-failed = failed to unlink { $path }: { $source }
+safe-traversal-error-invalid-fd = invalid file descriptor
+safe-traversal-current-directory = <current directory>
+safe-traversal-directory = <directory>
+
+# checksum-related messages
+checksum-no-properly-formatted = { $checksum_file }: no properly formatted checksum lines found
+checksum-no-file-verified = { $checksum_file }: no file was verified
+checksum-error-failed-to-read-input = failed to read input
+checksum-bad-format = { $count ->
+    [1] { $count } line is improperly formatted
+   *[other] { $count } lines are improperly formatted
+}
+checksum-failed-cksum = { $count ->
+    [1] { $count } computed checksum did NOT match
+   *[other] { $count } computed checksums did NOT match
+}
+checksum-failed-open-file = { $count ->
+    [1] { $count } listed file could not be read
+   *[other] { $count } listed files could not be read
+}
+checksum-error-algo-bad-format = { $file }: { $line }: improperly formatted { $algo } checksum line
+
+# uudoc tldr examples messages
+uudoc-tldr-attribution = The examples are provided by the [tldr-pages project](https://tldr.sh) under the [CC BY 4.0 License](https://github.com/tldr-pages/tldr/blob/main/LICENSE.md).
+uudoc-tldr-disclaimer = Please note that, as uutils is a work in progress, some examples might fail.
+"),
+        // Locale for cp (en-US)
+        "cp/en-US.ftl" => Some(r"cp-about = Copy SOURCE to DEST, or multiple SOURCE(s) to DIRECTORY.
+cp-usage = cp [OPTION]... [-T] SOURCE DEST
+  cp [OPTION]... SOURCE... DIRECTORY
+  cp [OPTION]... -t DIRECTORY SOURCE...
+cp-after-help = Do not copy a non-directory that has an existing destination with the same or newer modification timestamp;
+  instead, silently skip the file without failing. If timestamps are being preserved, the comparison is to the
+  source timestamp truncated to the resolutions of the destination file system and of the system calls used to
+  update timestamps; this avoids duplicate work if several cp -pu commands are executed with the same source
+  and destination. This option is ignored if the -n or --no-clobber option is also specified. Also, if
+  --preserve=links is also specified (like with cp -au for example), that will take precedence; consequently,
+  depending on the order that files are processed from the source, newer files in the destination may be replaced,
+  to mirror hard links in the source. which gives more control over which existing files in the destination are
+  replaced, and its value can be one of the following:
+
+  - all This is the default operation when an --update option is not specified, and results in all existing files in the destination being replaced.
+  - none This is similar to the --no-clobber option, in that no files in the destination are replaced, but also skipping a file does not induce a failure.
+  - older This is the default operation when --update is specified, and results in files being replaced if they're older than the corresponding source file.
+
+# Help messages
+cp-help-target-directory = copy all SOURCE arguments into target-directory
+cp-help-no-target-directory = Treat DEST as a regular file and not a directory
+cp-help-interactive = ask before overwriting files
+cp-help-link = hard-link files instead of copying
+cp-help-no-clobber = don't overwrite a file that already exists
+cp-help-recursive = copy directories recursively
+cp-help-strip-trailing-slashes = remove any trailing slashes from each SOURCE argument
+cp-help-debug = explain how a file is copied. Implies -v
+cp-help-verbose = explicitly state what is being done
+cp-help-symbolic-link = make symbolic links instead of copying
+cp-help-force = if an existing destination file cannot be opened, remove it and try again (this option is ignored when the -n option is also used). Currently not implemented for Windows.
+cp-help-remove-destination = remove each existing destination file before attempting to open it (contrast with --force). On Windows, currently only works for writeable files.
+cp-help-reflink = control clone/CoW copies. See below
+cp-help-attributes-only = Don't copy the file data, just the attributes
+cp-help-preserve = Preserve the specified attributes (default: mode, ownership (unix only), timestamps), if possible additional attributes: context, links, xattr, all
+cp-help-preserve-default = same as --preserve=mode,ownership(unix only),timestamps
+cp-help-no-preserve = don't preserve the specified attributes
+cp-help-parents = use full source file name under DIRECTORY
+cp-help-no-dereference = never follow symbolic links in SOURCE
+cp-help-dereference = always follow symbolic links in SOURCE
+cp-help-cli-symbolic-links = follow command-line symbolic links in SOURCE
+cp-help-archive = Same as -dR --preserve=all
+cp-help-no-dereference-preserve-links = same as --no-dereference --preserve=links
+cp-help-one-file-system = stay on this file system
+cp-help-sparse = control creation of sparse files. See below
+cp-help-selinux = set SELinux security context of destination file to default type
+cp-help-context = like -Z, or if CTX is specified then set the SELinux or SMACK security context to CTX
+cp-help-progress = Display a progress bar. Note: this feature is not supported by GNU coreutils.
+cp-help-copy-contents = NotImplemented: copy contents of special files when recursive
+
+# Error messages
+cp-error-missing-file-operand = missing file operand
+cp-error-missing-destination-operand = missing destination file operand after { $source }
+cp-error-extra-operand = extra operand { $operand }
+cp-error-same-file = { $source } and { $dest } are the same file
+cp-error-backing-up-destroy-source = backing up { $dest } might destroy source;  { $source } not copied
+cp-error-cannot-open-for-reading = cannot open { $source } for reading
+cp-error-not-writing-dangling-symlink = not writing through dangling symlink { $dest }
+cp-error-failed-to-clone = failed to clone { $source } from { $dest }: { $error }
+cp-error-cannot-change-attribute = cannot change attribute { $dest }: Source file is a non regular file
+cp-error-cannot-stat = cannot stat { $source }: No such file or directory
+cp-error-cannot-create-symlink = cannot create symlink { $dest } to { $source }
+cp-error-cannot-create-hard-link = cannot create hard link { $dest } to { $source }
+cp-error-omitting-directory = -r not specified; omitting directory { $dir }
+cp-error-cannot-copy-directory-into-itself = cannot copy a directory, { $source }, into itself, { $dest }
+cp-error-will-not-copy-through-symlink = will not copy { $source } through just-created symlink { $dest }
+cp-error-will-not-overwrite-just-created = will not overwrite just-created { $dest } with { $source }
+cp-error-target-not-directory = target: { $target } is not a directory
+cp-error-cannot-overwrite-directory-with-non-directory = cannot overwrite directory { $dir } with non-directory
+cp-error-cannot-overwrite-non-directory-with-directory = cannot overwrite non-directory with directory
+cp-error-with-parents-dest-must-be-dir = with --parents, the destination must be a directory
+cp-error-not-replacing = not replacing { $file }
+cp-error-failed-get-current-dir = failed to get current directory { $error }
+cp-error-failed-set-permissions = cannot set permissions { $path }
+cp-error-backup-mutually-exclusive = options --backup and --no-clobber are mutually exclusive
+cp-error-invalid-argument = invalid argument { $arg } for '{ $option }'
+cp-error-option-not-implemented = Option '{ $option }' not yet implemented.
+cp-error-not-all-files-copied = Not all files were copied
+cp-error-reflink-always-sparse-auto = `--reflink=always` can be used only with --sparse=auto
+cp-error-file-exists = { $path }: File exists
+cp-error-invalid-backup-argument = --backup is mutually exclusive with -n or --update=none-fail
+cp-error-reflink-not-supported = --reflink is only supported on linux and macOS
+cp-error-sparse-not-supported = --sparse is only supported on linux
+cp-error-not-a-directory = { $path } is not a directory
+cp-error-selinux-not-enabled = SELinux was not enabled during the compile time!
+cp-error-selinux-set-context = failed to set the security context of { $path }: { $error }
+cp-error-selinux-get-context = failed to get security context of { $path }
+cp-error-selinux-error = SELinux error: { $error }
+cp-error-selinux-context-conflict = cannot combine --context (-Z) with --preserve=context
+cp-error-cannot-create-fifo = cannot create fifo { $path }: File exists
+cp-error-cannot-create-special-file = cannot create special file { $path }: { $error }
+cp-error-invalid-attribute = invalid attribute { $value }
+cp-error-failed-to-create-whole-tree = failed to create whole tree
+cp-error-failed-to-create-directory = Failed to create directory: { $error }
+cp-error-backup-format = cp: { $error }
+  Try '{ $exec } --help' for more information.
+cp-error-setting-attributes = setting attributes for { $path }
+
+# Debug enum strings
+cp-debug-enum-no = no
+cp-debug-enum-yes = yes
+cp-debug-enum-avoided = avoided
+cp-debug-enum-unsupported = unsupported
+cp-debug-enum-unknown = unknown
+cp-debug-enum-zeros = zeros
+cp-debug-enum-seek-hole = SEEK_HOLE
+cp-debug-enum-seek-hole-zeros = SEEK_HOLE + zeros
+
+# Warning messages
+cp-warning-source-specified-more-than-once = source { $file_type } { $source } specified more than once
+
+# Verbose and debug messages
+cp-debug-skipped = skipped { $path }
+cp-verbose-removed = removed { $path }
+cp-debug-copy-offload = copy offload: { $offload }, reflink: { $reflink }, sparse detection: { $sparse }
+
+# Prompts
+cp-prompt-overwrite = overwrite { $path }?
+cp-prompt-overwrite-with-mode = replace { $path }, overriding mode
+"),
+        // Locale for mkdir (en-US)
+        "mkdir/en-US.ftl" => Some(r"mkdir-about = Create the given DIRECTORY(ies) if they do not exist
+mkdir-usage = mkdir [OPTION]... DIRECTORY...
+mkdir-after-help = Each MODE is of the form [ugoa]*([-+=]([rwxXst]*|[ugo]))+|[-+=]?[0-7]+.
+
+# Help messages
+mkdir-help-mode = set file mode (not implemented on windows)
+mkdir-help-parents = make parent directories as needed
+mkdir-help-verbose = print a message for each printed directory
+mkdir-help-selinux = set SELinux security context of each created directory to the default type
+mkdir-help-context = like -Z, or if CTX is specified then set the SELinux or SMACK security context to CTX
+
+# Error messages
+mkdir-error-empty-directory-name = cannot create directory '': No such file or directory
+mkdir-error-file-exists = { $path }: File exists
+mkdir-error-failed-to-create-tree = failed to create whole tree
+mkdir-error-cannot-set-permissions = cannot set permissions { $path }
+
+# Verbose output
+mkdir-verbose-created-directory = { $util_name }: created directory { $path }
+"),
+        // Locale for mktemp (en-US)
+        "mktemp/en-US.ftl" => Some(r"mktemp-about = Create a temporary file or directory.
+mktemp-usage = mktemp [OPTION]... [TEMPLATE]
+
+# Help messages
+mktemp-help-directory = Make a directory instead of a file
+mktemp-help-dry-run = do not create anything; merely print a name (unsafe)
+mktemp-help-quiet = Fail silently if an error occurs.
+mktemp-help-suffix = append SUFFIX to TEMPLATE; SUFFIX must not contain a path separator. This option is implied if TEMPLATE does not end with X.
+mktemp-help-p = short form of --tmpdir
+mktemp-help-tmpdir = interpret TEMPLATE relative to DIR; if DIR is not specified, use $TMPDIR ($TMP on windows) if set, else /tmp. With this option, TEMPLATE must not be an absolute name; unlike with -t, TEMPLATE may contain slashes, but mktemp creates only the final component
+mktemp-help-t = Generate a template (using the supplied prefix and TMPDIR (TMP on windows) if set) to create a filename template [deprecated]
+
+# Error messages
+mktemp-error-persist-file = could not persist file { $path }
+mktemp-error-must-end-in-x = with --suffix, template { $template } must end in X
+mktemp-error-too-few-xs = too few X's in template { $template }
+mktemp-error-prefix-contains-separator = invalid template, { $template }, contains directory separator
+mktemp-error-suffix-contains-separator = invalid suffix { $suffix }, contains directory separator
+mktemp-error-invalid-template = invalid template, { $template }; with --tmpdir, it may not be absolute
+mktemp-error-too-many-templates = too many templates
+mktemp-error-not-found = failed to create { $template_type } via template { $template }: No such file or directory
+mktemp-error-failed-print = failed to print directory name
+
+# Template types
+mktemp-template-type-directory = directory
+mktemp-template-type-file = file
+"),
+        // Locale for mv (en-US)
+        "mv/en-US.ftl" => Some(r"mv-about = Move SOURCE to DEST, or multiple SOURCE(s) to DIRECTORY.
+mv-usage = mv [OPTION]... [-T] SOURCE DEST
+  mv [OPTION]... SOURCE... DIRECTORY
+  mv [OPTION]... -t DIRECTORY SOURCE...
+mv-after-help = When specifying more than one of -i, -f, -n, only the final one will take effect.
+
+  Do not move a non-directory that has an existing destination with the same or newer modification timestamp;
+  instead, silently skip the file without failing. If the move is across file system boundaries, the comparison is
+  to the source timestamp truncated to the resolutions of the destination file system and of the system calls used
+  to update timestamps; this avoids duplicate work if several mv -u commands are executed with the same source
+  and destination. This option is ignored if the -n or --no-clobber option is also specified. which gives more control
+  over which existing files in the destination are replaced, and its value can be one of the following:
+
+  - all This is the default operation when an --update option is not specified, and results in all existing files in the destination being replaced.
+  - none This is similar to the --no-clobber option, in that no files in the destination are replaced, but also skipping a file does not induce a failure.
+  - older This is the default operation when --update is specified, and results in files being replaced if they’re older than the corresponding source file.
+
+# Error messages
+mv-error-insufficient-arguments = The argument '<{$arg_files}>...' requires at least 2 values, but only 1 was provided
+mv-error-no-such-file = cannot stat {$path}: No such file or directory
+mv-error-cannot-stat-not-directory = cannot stat {$path}: Not a directory
+mv-error-same-file = {$source} and {$target} are the same file
+mv-error-self-target-subdirectory = cannot move {$source} to a subdirectory of itself, {$target}
+mv-error-directory-to-non-directory = cannot overwrite directory {$path} with non-directory
+mv-error-non-directory-to-directory = cannot overwrite non-directory {$target} with directory {$source}
+mv-error-not-directory = target {$path}: Not a directory
+mv-error-target-not-directory = target directory {$path}: Not a directory
+mv-error-failed-access-not-directory = failed to access {$path}: Not a directory
+mv-error-backup-with-no-clobber = cannot combine --backup with -n/--no-clobber or --update=none-fail
+mv-error-extra-operand = mv: extra operand {$operand}
+mv-error-backup-might-destroy-source = backing up {$target} might destroy source;  {$source} not moved
+mv-error-will-not-overwrite-just-created = will not overwrite just-created {$target} with {$source}
+mv-error-not-replacing = not replacing {$target}
+mv-error-cannot-move = cannot move {$source} to {$target}
+mv-error-directory-not-empty = Directory not empty
+mv-error-dangling-symlink = can't determine symlink type, since it is dangling
+mv-error-no-symlink-support = your operating system does not support symlinks
+mv-error-permission-denied = Permission denied
+mv-error-inter-device-move-failed = inter-device move failed: {$from} to {$to}; unable to remove target: {$err}
+
+# Help messages
+mv-help-force = do not prompt before overwriting
+mv-help-interactive = prompt before override
+mv-help-no-clobber = do not overwrite an existing file
+mv-help-strip-trailing-slashes = remove any trailing slashes from each SOURCE argument
+mv-help-target-directory = move all SOURCE arguments into DIRECTORY
+mv-help-no-target-directory = treat DEST as a normal file
+mv-help-verbose = explain what is being done
+mv-help-progress = Display a progress bar.
+  Note: this feature is not supported by GNU coreutils.
+mv-help-debug = explain how a file is copied. Implies -v
+mv-help-selinux = set SELinux security context of destination file to default type
+mv-help-context = like -Z, or if CTX is specified then set the SELinux security context to CTX
+
+# Verbose messages
+mv-verbose-renamed = renamed {$from} -> {$to}
+mv-verbose-renamed-with-backup = renamed {$from} -> {$to} (backup: {$backup})
+
+# Debug messages
+mv-debug-skipped = skipped {$target}
+
+# Prompt messages
+mv-prompt-overwrite = overwrite {$target}?
+mv-prompt-overwrite-mode = replace {$target}, overriding mode {$mode_info}?
+
+# Progress messages
+mv-progress-moving = moving
+"),
+        // Locale for touch (en-US)
+        "touch/en-US.ftl" => Some(r#"touch-about = Update the access and modification times of each FILE to the current time.
+touch-usage = touch [OPTION]... [FILE]...
+
+# Help messages
+touch-help-help = Print help information.
+touch-help-access = change only the access time
+touch-help-timestamp = use [[CC]YY]MMDDhhmm[.ss] instead of the current time
+touch-help-date = parse argument and use it instead of current time
+touch-help-modification = change only the modification time
+touch-help-no-create = do not create any files
+touch-help-no-deref = affect each symbolic link instead of any referenced file (only for systems that can change the timestamps of a symlink)
+touch-help-reference = use this file's times instead of the current time
+touch-help-time = change only the specified time: "access", "atime", or "use" are equivalent to -a; "modify" or "mtime" are equivalent to -m
+
+# Error messages
+touch-error-missing-file-operand = missing file operand
+  Try '{ $help_command } --help' for more information.
+touch-error-setting-times-of = setting times of { $filename }
+touch-error-setting-times-no-such-file = setting times of { $filename }: No such file or directory
+touch-error-cannot-touch = cannot touch { $filename }
+touch-error-no-such-file-or-directory = No such file or directory
+touch-error-failed-to-get-attributes = failed to get attributes of { $path }
+touch-error-setting-times-of-path = setting times of { $path }
+touch-error-invalid-date-ts-format = invalid date ts format { $date }
+touch-error-invalid-date-format = invalid date format { $date }
+touch-error-unable-to-parse-date = Unable to parse date: { $date }
+touch-error-windows-stdout-path-failed = GetFinalPathNameByHandleW failed with code { $code }
+touch-error-invalid-filetime = Source has invalid access or modification time: { $time }
+touch-error-reference-file-inaccessible = failed to get attributes of { $path }: { $error }
+"#),
+        // Locale for uname (en-US)
+        "uname/en-US.ftl" => Some(r"uname-about = Print certain system information.
+  With no OPTION, same as -s.
+uname-usage = uname [OPTION]...
+
+# Error messages
+uname-error-cannot-get-system-name = cannot get system name
+
+# Default values
+uname-unknown = unknown
+
+# Help text for command-line arguments
+uname-help-all = Behave as though all of the options -mnrsvo were specified.
+uname-help-kernel-name = print the kernel name.
+uname-help-nodename = print the nodename (the nodename may be a name that the system is known by to a communications network).
+uname-help-kernel-release = print the operating system release.
+uname-help-kernel-version = print the operating system version.
+uname-help-machine = print the machine hardware name.
+uname-help-os = print the operating system name.
+uname-help-processor = print the processor type (non-portable)
+uname-help-hardware-platform = print the hardware platform (non-portable)
+"),
+        // Locale for whoami (en-US)
+        "whoami/en-US.ftl" => Some(r"whoami-about = Print the current username.
+whoami-usage = whoami
+
+# Error messages
+whoami-error-failed-to-print = failed to print username
+whoami-error-failed-to-get = failed to get username
+"),
+        _ => None,
+    }
+}